@@ -0,0 +1,65 @@
+// Scripting example of a large building.
+// A 40 storey frame with 10 bays and X-bracing in the outer bays is generated, and solved for the
+// storey loads and self-weight. Run from the repository root, as the sections, materials and
+// load cases are read from the inputs folder:
+//
+// cargo run --release --example large_building
+
+use std::time::Instant;
+use rust_fe_calculator::input::section::{parse_section, assign_section_properties};
+use rust_fe_calculator::input::material::{parse_material, assign_material_properties};
+use rust_fe_calculator::input::load_case::parse_load_case;
+use rust_fe_calculator::generators::building::{generate_building_frame, generate_building_supports, generate_storey_loads, building_keypoint_name};
+use rust_fe_calculator::fe_engine::linear_static::{Model, solve_linear_static};
+use rust_fe_calculator::fe_engine::utils::{dofs_per_keypoint, global_stiffness_matrix_keypoint_hashmap};
+
+const BAYS: usize = 10;
+const STOREYS: usize = 40;
+const BAY_WIDTH: f64 = 6.0;
+const STOREY_HEIGHT: f64 = 3.5;
+const FLOOR_LOAD: f64 = 30.0; // kN/m
+const STOREY_LOAD: f64 = 50.0; // kN
+
+fn main() {
+    let bay_widths = [BAY_WIDTH; BAYS];
+    let storey_heights = [STOREY_HEIGHT; STOREYS];
+
+    let (kp_list, mut conn_list) = generate_building_frame(&bay_widths, &storey_heights, "x", &[0, BAYS-1], "mat1", "mat1", "mat1");
+    let bc_list = generate_building_supports(&bay_widths);
+    let pl_list = generate_storey_loads(&bay_widths, &storey_heights, FLOOR_LOAD, STOREY_LOAD);
+
+    for conn in conn_list.iter_mut() {
+        conn.section = if conn.name.starts_with("col") { "HEA200" }
+                       else if conn.name.starts_with("beam") { "IPE300" }
+                       else { "SHS120x6" }.to_string();
+    }
+    let section_list = parse_section("inputs/sections.csv");
+    assign_section_properties(&mut conn_list, &section_list, 0.0, 0.0);
+
+    let mat_list = parse_material("inputs/materials.txt");
+    assign_material_properties(&mut conn_list, &mat_list);
+    let lc_list = parse_load_case("inputs/loadcases.txt");
+
+    let dofs = dofs_per_keypoint(&conn_list);
+    println!("Solving {} keypoints, {} connections and {} dofs.", kp_list.len(), conn_list.len(), dofs * kp_list.len());
+
+    let start = Instant::now();
+    let model = Model {
+        kp_list: &kp_list,
+        conn_list: &conn_list,
+        bc_list: &bc_list,
+        pl_list: &pl_list,
+        dl_list: &[],
+        mat_list: &mat_list,
+        load_case: &lc_list[0],
+    };
+    let (displacement_vector, force_vector) = solve_linear_static(&model);
+    println!("Solved in {:.2} s.", start.elapsed().as_secs_f64());
+
+    let kp_map = global_stiffness_matrix_keypoint_hashmap(&kp_list, dofs);
+    let roof = kp_map[&building_keypoint_name(STOREYS, 0)];
+    println!("Roof displacement: ux = {:.4} m, uy = {:.4} m", displacement_vector[roof], displacement_vector[roof+1]);
+
+    let base_shear:f64 = bc_list.iter().map(|bc| force_vector[kp_map[&bc.keypoint]]).sum();
+    println!("Base shear: {:.2} kN", base_shear);
+}
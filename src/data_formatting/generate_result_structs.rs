@@ -1,12 +1,336 @@
 use crate::input::keypoint::Keypoint;
-use nalgebra::DVector;
-use crate::fe_engine::utils::global_stiffness_matrix_keypoint_hashmap;
+use crate::input::connection::Connection;
+use crate::input::distributed_load::DistributedLoad;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::input::pointload::Pointload;
+use nalgebra::{ComplexField, DVector};
+use crate::fe_engine::utils::{global_stiffness_matrix_keypoint_hashmap, dofs_per_keypoint};
+use crate::fe_engine::global_stiffness_matrix::{create_global_stiffness_matrix, apply_boundary_conditions};
+use crate::fe_engine::dof_filter_vector::create_dof_filter_vector;
+use crate::fe_engine::member_forces::{calculate_local_end_forces, sample_member_diagram};
+use crate::fe_engine::modal_analysis::Mode;
+use crate::fe_engine::buckling_analysis::BucklingMode;
+use crate::fe_engine::transient_analysis::TimeStep;
+use crate::fe_engine::harmonic_analysis::FrequencyResponse;
+use crate::fe_engine::response_spectrum::combine_modal_responses;
+use crate::fe_engine::influence_lines::{UnitLoadSolution, create_deck_positions, solve_unit_loads, interpolate_unit_load_solution, evaluate_quantity};
+use crate::input::deck_path::DeckPath;
+use crate::input::influence_line::InfluenceLine;
+use crate::input::vehicle::VehicleAxle;
+use crate::input::steel_member::SteelMember;
+use crate::design::steel_check::{SteelCheck, check_steel_member};
+use crate::input::timber_class::TimberClass;
+use crate::input::timber_member::TimberMember;
+use crate::design::timber_check::{TimberCheck, check_timber_member};
+use crate::design::member_sizing::{MemberDesign, select_lightest_section, calculate_total_weight};
+use crate::input::section::{Section, assign_section};
+use crate::input::sizing_group::{SizingGroup, SizingCandidates};
+use crate::fe_engine::linear_static::{Model, solve_linear_static};
+use crate::design::topology_optimisation::{create_ground_structure, optimise_topology, extract_optimised_truss};
+use crate::material_formulation::local_stiffness_matrix_frame::element_geometry;
+use crate::fe_engine::moving_loads::{vehicle_names, create_vehicle_positions, solve_vehicle_position};
+use crate::input::sweep::{SweepParameter, SweepOutput};
+use crate::fe_engine::parameter_sweep::{create_sweep_variants, apply_model_parameter, evaluate_model_output};
+use crate::input::random_variable::{RandomVariable, ReliabilityOutput};
+use crate::fe_engine::monte_carlo::{RandomNumberGenerator, sample_random_variable, mean_and_standard_deviation};
+use crate::input::sensitivity_response::SensitivityResponse;
+use crate::fe_engine::sensitivity::{create_design_variables, design_variable_value, create_design_derivatives,
+	factorise_reduced_stiffness_matrix, calculate_pseudo_load, solve_direct_sensitivity, solve_adjoint_vector};
+use crate::input::space_keypoint::SpaceKeypoint;
+use crate::fe_engine::space_analysis::{space_keypoint_hashmap, calculate_space_local_end_forces};
+use crate::input::surface_element::SurfaceElement;
+use crate::fe_engine::plane_analysis::{calculate_surface_element_stress, surface_element_natural_coordinates, surface_element_centroid};
+use crate::material_formulation::plane_elasticity::von_mises_stress;
+use serde::Serialize;
+use std::collections::HashMap;
+
+// Mode shape of a keypoint.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct ModeShapeKeypoint {
+	pub name: String,
+	pub ux: f64,
+	pub uy: f64,
+	pub rz: f64,
+}
+
+// Frequencies and mass-normalized mode shape of a mode.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct ModeResult {
+	pub mode: usize,
+	pub angular_frequency: f64,
+	pub frequency: f64,
+	pub period: f64,
+	pub keypoints: Vec<ModeShapeKeypoint>,
+}
+
+// Displacement and force history of a keypoint.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct KeypointHistory {
+	pub name: String,
+	pub time: Vec<f64>,
+	pub ux: Vec<f64>,
+	pub uy: Vec<f64>,
+	pub rz: Vec<f64>,
+	pub fx: Vec<f64>,
+	pub fy: Vec<f64>,
+	pub mz: Vec<f64>,
+}
+
+// Steady-state amplitude and phase (degrees) of a keypoint versus frequency.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct KeypointFrequencyResponse {
+	pub name: String,
+	pub frequency: Vec<f64>,
+	pub ux_amplitude: Vec<f64>,
+	pub ux_phase: Vec<f64>,
+	pub uy_amplitude: Vec<f64>,
+	pub uy_phase: Vec<f64>,
+	pub rz_amplitude: Vec<f64>,
+	pub rz_phase: Vec<f64>,
+}
+
+// Peak normal force of a connection.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct PeakConnectionForce {
+	pub name: String,
+	pub n: f64,
+}
+
+// Combined peak responses of a response spectrum analysis.
+// Keypoint forces are the peak reactions, and are zero in keypoints without boundary conditions.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct ResponseSpectrumResult {
+	pub method: String,
+	pub keypoints: Vec<Keypoint>,
+	pub connections: Vec<PeakConnectionForce>,
+}
+
+// Influence line values versus distance along the deck path.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct InfluenceLineResult {
+	pub name: String,
+	pub quantity: String,
+	pub target: String,
+	pub direction: String,
+	pub distance: Vec<f64>,
+	pub value: Vec<f64>,
+}
+
+// Maximum and minimum value of a quantity, and the position of the leading axle producing them.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct Envelope {
+	pub max: f64,
+	pub max_position: f64,
+	pub min: f64,
+	pub min_position: f64,
+}
+
+// Envelopes of keypoint displacements and reactions.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct KeypointEnvelope {
+	pub name: String,
+	pub ux: Envelope,
+	pub uy: Envelope,
+	pub rz: Envelope,
+	pub fx: Envelope,
+	pub fy: Envelope,
+	pub mz: Envelope,
+}
+
+// Envelope of the connection normal force.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct ConnectionEnvelope {
+	pub name: String,
+	pub n: Envelope,
+}
+
+// Envelope results of a vehicle moving along a deck path.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct MovingLoadResult {
+	pub vehicle: String,
+	pub deck_path: String,
+	pub keypoints: Vec<KeypointEnvelope>,
+	pub connections: Vec<ConnectionEnvelope>,
+}
+
+// Steel checks of all checked connections, with a pass/fail summary.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct SteelCheckResult {
+	pub checked_members: usize,
+	pub failed_members: Vec<String>,
+	pub max_utilization: f64,
+	pub governing_connection: String,
+	pub passed: bool,
+	pub members: Vec<SteelCheck>,
+}
+
+// Timber checks of all checked connections, with a pass/fail summary.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct TimberCheckResult {
+	pub checked_members: usize,
+	pub failed_members: Vec<String>,
+	pub max_utilization: f64,
+	pub governing_connection: String,
+	pub passed: bool,
+	pub members: Vec<TimberCheck>,
+}
+
+// Section of a sizing group.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Serialize)]
+pub struct GroupSection {
+	pub group: String,
+	pub section: String,
+}
+
+// Sections, weight and utilization of a member sizing iteration.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct SizingIteration {
+	pub iteration: usize,
+	pub sections: Vec<GroupSection>,
+	pub total_weight: f64,
+	pub max_utilization: f64,
+}
+
+// Final sections of the member sizing, with the iteration history.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct SizingResult {
+	pub converged: bool,
+	pub sections: Vec<GroupSection>,
+	pub total_weight: f64,
+	pub max_utilization: f64,
+	pub history: Vec<SizingIteration>,
+}
+
+// Bar of the optimised truss, with the required area and the smallest library section covering it.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct TopologyMember {
+	pub name: String,
+	pub kp_1: String,
+	pub kp_2: String,
+	pub material: String,
+	pub area: f64,
+	pub normal_force: f64,
+	pub section: String,
+}
+
+// Optimised truss layout from the ground structure.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct TopologyResult {
+	pub ground_structure_bars: usize,
+	pub feasible: bool,
+	pub volume: f64,
+	pub members: Vec<TopologyMember>,
+}
+
+// Parameter values and outputs of a variant of the parameter sweep.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct SweepVariant {
+	pub variant: usize,
+	pub parameter_values: Vec<f64>,
+	pub output_values: Vec<f64>,
+}
+
+// Summary table of the parameter sweep. The values of each variant follow the order of the names.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct ParameterSweepResult {
+	pub parameters: Vec<String>,
+	pub outputs: Vec<String>,
+	pub variants: Vec<SweepVariant>,
+}
+
+// Bin of a histogram, with the number of samples in [lower, upper).
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct HistogramBin {
+	pub lower: f64,
+	pub upper: f64,
+	pub count: usize,
+}
+
+// Statistics of a response from the Monte Carlo samples.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct ReliabilityOutputResult {
+	pub name: String,
+	pub quantity: String,
+	pub target: String,
+	pub mean: f64,
+	pub standard_deviation: f64,
+	pub min: f64,
+	pub max: f64,
+	pub threshold: f64,
+	pub exceedance_probability: f64,
+	pub histogram: Vec<HistogramBin>,
+	pub samples: Vec<f64>,
+}
+
+// Monte Carlo reliability analysis result. The seed reproduces the samples.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct ReliabilityResult {
+	pub number_of_samples: usize,
+	pub seed: u64,
+	pub outputs: Vec<ReliabilityOutputResult>,
+}
+
+// Derivative of a response with respect to a design variable, from the direct and adjoint methods.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct SensitivityEntry {
+	pub parameter: String,
+	pub target: String,
+	pub value: f64,
+	pub direct: f64,
+	pub adjoint: f64,
+}
+
+// Sensitivity table of a keypoint displacement.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct SensitivityResult {
+	pub name: String,
+	pub keypoint: String,
+	pub direction: String,
+	pub displacement: f64,
+	pub sensitivities: Vec<SensitivityEntry>,
+}
+
+// Load factor and normalized mode shape of a buckling mode.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct BucklingModeResult {
+	pub mode: usize,
+	pub load_factor: f64,
+	pub keypoints: Vec<ModeShapeKeypoint>,
+}
 
 pub fn generate_result_keypoint(kp_list:&mut [Keypoint], 
 								force_vector:&DVector<f64>,
-								displacement_vector:&DVector<f64>) {
+								displacement_vector:&DVector<f64>,
+								dofs_per_keypoint:usize) {
 
-	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(&kp_list);
+	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
 
 	for kp in kp_list.iter_mut() {
 		let number = kp_hashmap[&kp.name];
@@ -14,5 +338,872 @@ pub fn generate_result_keypoint(kp_list:&mut [Keypoint],
 		kp.fy = force_vector[number+1];
 		kp.ux = displacement_vector[number];
 		kp.uy = displacement_vector[number+1];
+
+		// Moments and rotations only exist in models with frame elements.
+		if dofs_per_keypoint == 3 {
+			kp.mz = force_vector[number+2];
+			kp.rz = displacement_vector[number+2];
+		}
+	}
+}
+
+// Converting the local member end forces into normal force, shear force and bending moment at each end.
+// Normal force is positive in tension. Bending moment is positive when sagging.
+// The member force diagrams are sampled along each connection.
+pub fn generate_result_connection(conn_list:&mut [Connection],
+								  kp_list:&[Keypoint],
+								  displacement_vector:&DVector<f64>,
+								  dofs_per_keypoint:usize,
+								  dl_list:&[DistributedLoad],
+								  diagram_samples:usize) {
+
+	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+
+	for conn in conn_list.iter_mut() {
+		let f = calculate_local_end_forces(conn, kp_list, &kp_hashmap, displacement_vector, 
+										   dofs_per_keypoint, dl_list);
+		conn.n_1 = -f[0];
+		conn.v_1 = f[1];
+		conn.m_1 = -f[2];
+		conn.n_2 = f[3];
+		conn.v_2 = -f[4];
+		conn.m_2 = f[5];
+		conn.diagram = sample_member_diagram(conn, kp_list, dl_list, diagram_samples);
+	}
+}
+
+// Returning a copy of the keypoints, displaced by a global displacement-like vector (e.g. a mode shape).
+// Used for plotting mode shapes with the displaced-shape plotting routines.
+pub fn generate_displaced_keypoints(kp_list:&[Keypoint],
+									displacement_vector:&DVector<f64>,
+									dofs_per_keypoint:usize) -> Vec<Keypoint> {
+
+	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+	let mut displaced_kp_list:Vec<Keypoint> = kp_list.to_vec();
+
+	for kp in displaced_kp_list.iter_mut() {
+		let number = kp_hashmap[&kp.name];
+		kp.fx = 0.0;
+		kp.fy = 0.0;
+		kp.mz = 0.0;
+		kp.ux = displacement_vector[number];
+		kp.uy = displacement_vector[number+1];
+		kp.rz = if dofs_per_keypoint == 3 { displacement_vector[number+2] } else { 0.0 };
+	}
+
+	displaced_kp_list
+}
+
+pub fn generate_result_modes(kp_list:&[Keypoint],
+							 modes:&[Mode],
+							 dofs_per_keypoint:usize) -> Vec<ModeResult> {
+
+	let mut mode_results:Vec<ModeResult> = Vec::new();
+
+	for mode in modes {
+		let keypoints:Vec<ModeShapeKeypoint> = generate_displaced_keypoints(kp_list, &mode.shape, dofs_per_keypoint)
+			.into_iter()
+			.map(|kp| ModeShapeKeypoint {name: kp.name, ux: kp.ux, uy: kp.uy, rz: kp.rz})
+			.collect();
+
+		mode_results.push(ModeResult {
+			mode: mode.number,
+			angular_frequency: mode.angular_frequency,
+			frequency: mode.frequency,
+			period: mode.period,
+			keypoints,
+		});
 	}
-}
\ No newline at end of file
+
+	mode_results
+}
+
+pub fn generate_result_buckling_modes(kp_list:&[Keypoint],
+									  buckling_modes:&[BucklingMode],
+									  dofs_per_keypoint:usize) -> Vec<BucklingModeResult> {
+
+	let mut buckling_mode_results:Vec<BucklingModeResult> = Vec::new();
+
+	for mode in buckling_modes {
+		let keypoints:Vec<ModeShapeKeypoint> = generate_displaced_keypoints(kp_list, &mode.shape, dofs_per_keypoint)
+			.into_iter()
+			.map(|kp| ModeShapeKeypoint {name: kp.name, ux: kp.ux, uy: kp.uy, rz: kp.rz})
+			.collect();
+
+		buckling_mode_results.push(BucklingModeResult {
+			mode: mode.number,
+			load_factor: mode.load_factor,
+			keypoints,
+		});
+	}
+
+	buckling_mode_results
+}
+
+pub fn generate_result_keypoint_histories(kp_list:&[Keypoint],
+										  time_steps:&[TimeStep],
+										  dofs_per_keypoint:usize) -> Vec<KeypointHistory> {
+
+	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+	let mut histories:Vec<KeypointHistory> = Vec::new();
+
+	for kp in kp_list {
+		let number = kp_hashmap[&kp.name];
+
+		// Moments and rotations only exist in models with frame elements.
+		let rotational = |vector:&DVector<f64>| if dofs_per_keypoint == 3 { vector[number+2] } else { 0.0 };
+
+		histories.push(KeypointHistory {
+			name: kp.name.clone(),
+			time: time_steps.iter().map(|step| step.time).collect(),
+			ux: time_steps.iter().map(|step| step.displacement[number]).collect(),
+			uy: time_steps.iter().map(|step| step.displacement[number+1]).collect(),
+			rz: time_steps.iter().map(|step| rotational(&step.displacement)).collect(),
+			fx: time_steps.iter().map(|step| step.force[number]).collect(),
+			fy: time_steps.iter().map(|step| step.force[number+1]).collect(),
+			mz: time_steps.iter().map(|step| rotational(&step.force)).collect(),
+		});
+	}
+
+	histories
+}
+
+pub fn generate_result_frequency_responses(kp_list:&[Keypoint],
+										   responses:&[FrequencyResponse],
+										   dofs_per_keypoint:usize) -> Vec<KeypointFrequencyResponse> {
+
+	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+	let mut keypoint_responses:Vec<KeypointFrequencyResponse> = Vec::new();
+
+	for kp in kp_list {
+		let number = kp_hashmap[&kp.name];
+
+		// Rotations only exist in models with frame elements.
+		let amplitude = |response:&FrequencyResponse, offset:usize| {
+			if offset < dofs_per_keypoint { response.displacement[number+offset].modulus() } else { 0.0 }
+		};
+		let phase = |response:&FrequencyResponse, offset:usize| {
+			if offset < dofs_per_keypoint { response.displacement[number+offset].argument().to_degrees() } else { 0.0 }
+		};
+
+		keypoint_responses.push(KeypointFrequencyResponse {
+			name: kp.name.clone(),
+			frequency: responses.iter().map(|response| response.frequency).collect(),
+			ux_amplitude: responses.iter().map(|response| amplitude(response, 0)).collect(),
+			ux_phase: responses.iter().map(|response| phase(response, 0)).collect(),
+			uy_amplitude: responses.iter().map(|response| amplitude(response, 1)).collect(),
+			uy_phase: responses.iter().map(|response| phase(response, 1)).collect(),
+			rz_amplitude: responses.iter().map(|response| amplitude(response, 2)).collect(),
+			rz_phase: responses.iter().map(|response| phase(response, 2)).collect(),
+		});
+	}
+
+	keypoint_responses
+}
+
+pub fn generate_result_response_spectrum(kp_list:&[Keypoint],
+										 conn_list:&[Connection],
+										 bc_list:&[BoundaryCondition],
+										 modes:&[Mode],
+										 modal_displacements:&[DVector<f64>],
+										 damping_ratio:f64,
+										 method:&str) -> ResponseSpectrumResult {
+
+	let dofs_per_keypoint:usize = dofs_per_keypoint(conn_list);
+	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+	let global_stiffness_matrix = create_global_stiffness_matrix(kp_list, conn_list);
+	let dof_filter_vector = create_dof_filter_vector(kp_list, conn_list, bc_list);
+
+	// Modal reactions. Only dofs with boundary conditions are kept.
+	let modal_reactions:Vec<DVector<f64>> = modal_displacements.iter().map(|u| {
+		let mut reaction:DVector<f64> = &global_stiffness_matrix * u;
+		for i in 0..reaction.nrows() {
+			if dof_filter_vector[i] == -1.0 {
+				reaction[i] = 0.0;
+			}
+		}
+		reaction
+	}).collect();
+
+	// Modal normal forces, one value per connection.
+	let modal_normal_forces:Vec<DVector<f64>> = modal_displacements.iter().map(|u| {
+		DVector::from_iterator(conn_list.len(), conn_list.iter().map(|conn| {
+			let f = calculate_local_end_forces(conn, kp_list, &kp_hashmap, u, dofs_per_keypoint, &[]);
+			(f[3] - f[0]) / 2.0
+		}))
+	}).collect();
+
+	let displacement = combine_modal_responses(modal_displacements, modes, damping_ratio, method);
+	let reaction = combine_modal_responses(&modal_reactions, modes, damping_ratio, method);
+	let normal_force = combine_modal_responses(&modal_normal_forces, modes, damping_ratio, method);
+
+	let mut peak_kp_list:Vec<Keypoint> = kp_list.to_vec();
+	generate_result_keypoint(&mut peak_kp_list, &reaction, &displacement, dofs_per_keypoint);
+
+	let connections:Vec<PeakConnectionForce> = conn_list.iter().enumerate()
+		.map(|(i, conn)| PeakConnectionForce {name: conn.name.clone(), n: normal_force[i]})
+		.collect();
+
+	ResponseSpectrumResult {method: method.to_string(), keypoints: peak_kp_list, connections}
+}
+
+pub fn generate_result_influence_lines(il_list:&[InfluenceLine],
+									   deck_paths:&[DeckPath],
+									   kp_list:&[Keypoint],
+									   conn_list:&[Connection],
+									   bc_list:&[BoundaryCondition],
+									   steps_per_segment:usize) -> Vec<InfluenceLineResult> {
+
+	let dofs_per_keypoint:usize = dofs_per_keypoint(conn_list);
+	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+	let global_stiffness_matrix = create_global_stiffness_matrix(kp_list, conn_list);
+	let dof_filter_vector = create_dof_filter_vector(kp_list, conn_list, bc_list);
+	let modified_global_stiffness_matrix = apply_boundary_conditions(&global_stiffness_matrix, &dof_filter_vector);
+	let mut influence_line_results:Vec<InfluenceLineResult> = Vec::new();
+
+	for il in il_list {
+		let deck_path = deck_paths.iter().find(|path| path.name == il.deck_path).unwrap();
+		let positions = create_deck_positions(deck_path, kp_list, steps_per_segment);
+		let solutions = solve_unit_loads(&global_stiffness_matrix, &modified_global_stiffness_matrix, &dof_filter_vector,
+										 kp_list, &deck_path.keypoints, dofs_per_keypoint);
+
+		let value:Vec<f64> = positions.iter().map(|position| {
+			let solution = interpolate_unit_load_solution(position, &solutions, 1.0);
+			evaluate_quantity(il, &solution, kp_list, conn_list, &kp_hashmap, dofs_per_keypoint)
+		}).collect();
+
+		influence_line_results.push(InfluenceLineResult {
+			name: il.name.clone(),
+			quantity: il.quantity.clone(),
+			target: il.target.clone(),
+			direction: il.direction.clone(),
+			distance: positions.iter().map(|position| position.distance).collect(),
+			value,
+		});
+	}
+
+	influence_line_results
+}
+
+// Finding the extremes of a quantity over all vehicle positions.
+fn create_envelope(values:&[f64], positions:&[f64]) -> Envelope {
+	let mut envelope = Envelope {max: values[0], max_position: positions[0], min: values[0], min_position: positions[0]};
+
+	for (value, position) in values.iter().zip(positions.iter()) {
+		if *value > envelope.max {
+			envelope.max = *value;
+			envelope.max_position = *position;
+		}
+		if *value < envelope.min {
+			envelope.min = *value;
+			envelope.min_position = *position;
+		}
+	}
+
+	envelope
+}
+
+pub fn generate_result_moving_loads(vehicle_axles:&[VehicleAxle],
+									deck_paths:&[DeckPath],
+									kp_list:&[Keypoint],
+									conn_list:&[Connection],
+									bc_list:&[BoundaryCondition],
+									step:f64) -> Vec<MovingLoadResult> {
+
+	let dofs_per_keypoint:usize = dofs_per_keypoint(conn_list);
+	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+	let global_stiffness_matrix = create_global_stiffness_matrix(kp_list, conn_list);
+	let dof_filter_vector = create_dof_filter_vector(kp_list, conn_list, bc_list);
+	let modified_global_stiffness_matrix = apply_boundary_conditions(&global_stiffness_matrix, &dof_filter_vector);
+	let mut moving_load_results:Vec<MovingLoadResult> = Vec::new();
+
+	for vehicle in vehicle_names(vehicle_axles) {
+		let axles:Vec<&VehicleAxle> = vehicle_axles.iter().filter(|axle| axle.name == vehicle).collect();
+		let deck_path = deck_paths.iter().find(|path| path.name == axles[0].deck_path).unwrap();
+
+		let solutions = solve_unit_loads(&global_stiffness_matrix, &modified_global_stiffness_matrix, &dof_filter_vector,
+										 kp_list, &deck_path.keypoints, dofs_per_keypoint);
+		let positions = create_vehicle_positions(&axles, deck_path, kp_list, step);
+		let vehicle_solutions:Vec<_> = positions.iter()
+			.map(|position| solve_vehicle_position(&axles, deck_path, kp_list, &solutions, *position))
+			.collect();
+
+		let keypoints:Vec<KeypointEnvelope> = kp_list.iter().map(|kp| {
+			let number = kp_hashmap[&kp.name];
+
+			// Moments and rotations only exist in models with frame elements.
+			let envelope = |vector:fn(&UnitLoadSolution) -> &DVector<f64>, offset:usize| {
+				let values:Vec<f64> = vehicle_solutions.iter()
+					.map(|solution| if offset < dofs_per_keypoint { vector(solution)[number+offset] } else { 0.0 })
+					.collect();
+				create_envelope(&values, &positions)
+			};
+			let displacement:fn(&UnitLoadSolution) -> &DVector<f64> = |solution| &solution.displacement;
+			let reaction:fn(&UnitLoadSolution) -> &DVector<f64> = |solution| &solution.reaction;
+
+			KeypointEnvelope {
+				name: kp.name.clone(),
+				ux: envelope(displacement, 0),
+				uy: envelope(displacement, 1),
+				rz: envelope(displacement, 2),
+				fx: envelope(reaction, 0),
+				fy: envelope(reaction, 1),
+				mz: envelope(reaction, 2),
+			}
+		}).collect();
+
+		let connections:Vec<ConnectionEnvelope> = conn_list.iter().map(|conn| {
+			let values:Vec<f64> = vehicle_solutions.iter().map(|solution| {
+				let f = calculate_local_end_forces(conn, kp_list, &kp_hashmap, &solution.displacement, dofs_per_keypoint, &[]);
+				(f[3] - f[0]) / 2.0
+			}).collect();
+			ConnectionEnvelope {name: conn.name.clone(), n: create_envelope(&values, &positions)}
+		}).collect();
+
+		moving_load_results.push(MovingLoadResult {
+			vehicle,
+			deck_path: deck_path.name.clone(),
+			keypoints,
+			connections,
+		});
+	}
+
+	moving_load_results
+}
+
+pub fn generate_result_steel_checks(conn_list:&mut [Connection],
+									steel_members:&[SteelMember],
+									kp_list:&[Keypoint],
+									gamma_m0:f64,
+									gamma_m1:f64) -> SteelCheckResult {
+
+	let mut members:Vec<SteelCheck> = Vec::new();
+
+	// Connections without steel design parameters are not checked.
+	for conn in conn_list.iter_mut() {
+		if let Some(member) = steel_members.iter().find(|member| member.connection == conn.name) {
+			let check = check_steel_member(conn, member, kp_list, gamma_m0, gamma_m1);
+			conn.utilization = conn.utilization.max(check.utilization);
+			members.push(check);
+		}
+	}
+
+	let governing = members.iter().max_by(|a, b| a.utilization.total_cmp(&b.utilization));
+
+	SteelCheckResult {
+		checked_members: members.len(),
+		failed_members: members.iter().filter(|check| !check.passed).map(|check| check.connection.clone()).collect(),
+		max_utilization: governing.map_or(0.0, |check| check.utilization),
+		governing_connection: governing.map_or(String::new(), |check| check.connection.clone()),
+		passed: members.iter().all(|check| check.passed),
+		members,
+	}
+}
+
+pub fn generate_result_timber_checks(conn_list:&mut [Connection],
+									 timber_members:&[TimberMember],
+									 timber_classes:&[TimberClass],
+									 kp_list:&[Keypoint]) -> TimberCheckResult {
+
+	let mut members:Vec<TimberCheck> = Vec::new();
+
+	// Connections without timber design parameters are not checked.
+	for conn in conn_list.iter_mut() {
+		if let Some(member) = timber_members.iter().find(|member| member.connection == conn.name) {
+			let timber_class = timber_classes.iter().find(|class| class.name == member.timber_class).unwrap();
+			let check = check_timber_member(conn, member, timber_class, kp_list);
+			conn.utilization = conn.utilization.max(check.utilization);
+			members.push(check);
+		}
+	}
+
+	let governing = members.iter().max_by(|a, b| a.utilization.total_cmp(&b.utilization));
+
+	TimberCheckResult {
+		checked_members: members.len(),
+		failed_members: members.iter().filter(|check| !check.passed).map(|check| check.connection.clone()).collect(),
+		max_utilization: governing.map_or(0.0, |check| check.utilization),
+		governing_connection: governing.map_or(String::new(), |check| check.connection.clone()),
+		passed: members.iter().all(|check| check.passed),
+		members,
+	}
+}
+
+// Iterating the member sizing until no group changes section and all utilizations are <= 1.
+// Each iteration solves the model with the current sections and runs the steel and timber checks.
+pub fn generate_result_member_sizing(model:&Model,
+									 section_list:&[Section],
+									 sizing_groups:&[SizingGroup],
+									 sizing_candidates:&[SizingCandidates],
+									 design:&MemberDesign,
+									 max_iterations:usize,
+									 diagram_samples:usize) -> SizingResult {
+
+	let Model {kp_list, conn_list, dl_list, mat_list, ..} = *model;
+	let mut sized_conn_list:Vec<Connection> = conn_list.to_vec();
+	let dofs:usize = dofs_per_keypoint(conn_list);
+
+	// Candidate sections of each group, and the lightest candidate as the starting section.
+	let group_candidates:Vec<Vec<&Section>> = sizing_groups.iter().map(|group| {
+		let candidates = sizing_candidates.iter().find(|candidates| candidates.group == group.name).unwrap();
+		candidates.sections.iter()
+			.map(|name| section_list.iter().find(|section| &section.name == name).unwrap())
+			.collect()
+	}).collect();
+	let mut sections:Vec<GroupSection> = sizing_groups.iter().zip(group_candidates.iter()).map(|(group, candidates)| {
+		let lightest = candidates.iter().min_by(|a, b| a.area.total_cmp(&b.area)).unwrap();
+		GroupSection {group: group.name.clone(), section: lightest.name.clone()}
+	}).collect();
+
+	// Assigning the group sections to the connections.
+	let assign_group_sections = |sized_conn_list:&mut [Connection], sections:&[GroupSection]| {
+		for ((group, group_section), candidates) in sizing_groups.iter().zip(sections.iter()).zip(group_candidates.iter()) {
+			let section = candidates.iter().find(|section| section.name == group_section.section).unwrap();
+			for conn in sized_conn_list.iter_mut().filter(|conn| group.connections.contains(&conn.name)) {
+				assign_section(conn, section);
+			}
+		}
+	};
+
+	let mut history:Vec<SizingIteration> = Vec::new();
+	let mut converged:bool = false;
+
+	for iteration in 1..=max_iterations {
+		// Assigning the group sections and solving the model.
+		assign_group_sections(&mut sized_conn_list, &sections);
+
+		let (displacement_vector, _) = solve_linear_static(&Model {conn_list: &sized_conn_list, ..*model});
+		// The checks take the largest normal forces from the sampled diagrams.
+		generate_result_connection(&mut sized_conn_list, kp_list, &displacement_vector, dofs, dl_list, diagram_samples);
+		// The checks keep the largest utilization on each connection, so the previous iteration is cleared first.
+		for conn in sized_conn_list.iter_mut() {
+			conn.utilization = 0.0;
+		}
+		let steel_check_result = generate_result_steel_checks(&mut sized_conn_list, design.steel_members, kp_list,
+															  design.gamma_m0, design.gamma_m1);
+		let timber_check_result = generate_result_timber_checks(&mut sized_conn_list, design.timber_members, design.timber_classes, kp_list);
+
+		history.push(SizingIteration {
+			iteration,
+			sections: sections.clone(),
+			total_weight: calculate_total_weight(&sized_conn_list, kp_list, mat_list),
+			max_utilization: steel_check_result.max_utilization.max(timber_check_result.max_utilization),
+		});
+
+		// Selecting the lightest passing section of each group from the new member forces.
+		let new_sections:Vec<GroupSection> = sizing_groups.iter().zip(group_candidates.iter()).map(|(group, candidates)| {
+			let group_connections:Vec<&Connection> = sized_conn_list.iter()
+				.filter(|conn| group.connections.contains(&conn.name))
+				.collect();
+			let section = select_lightest_section(&group_connections, candidates, design, kp_list);
+			GroupSection {group: group.name.clone(), section: section.name.clone()}
+		}).collect();
+
+		// Stopping when no group changes section. Converged if all checks pass.
+		let unchanged:bool = new_sections.iter().zip(sections.iter()).all(|(new, old)| new.section == old.section);
+		if unchanged {
+			converged = steel_check_result.passed && timber_check_result.passed;
+			break;
+		}
+
+		sections = new_sections;
+	}
+
+	// Without iterations, the starting sections are returned unchecked.
+	let Some(last_iteration) = history.last() else {
+		assign_group_sections(&mut sized_conn_list, &sections);
+		return SizingResult {
+			converged,
+			total_weight: calculate_total_weight(&sized_conn_list, kp_list, mat_list),
+			max_utilization: 0.0,
+			sections,
+			history,
+		};
+	};
+
+	SizingResult {
+		converged,
+		sections: last_iteration.sections.clone(),
+		total_weight: last_iteration.total_weight,
+		max_utilization: last_iteration.max_utilization,
+		history,
+	}
+}
+
+pub fn generate_result_topology_optimisation(kp_list:&[Keypoint],
+											 bc_list:&[BoundaryCondition],
+											 pl_list:&[Pointload],
+											 section_list:&[Section],
+											 material:&str,
+											 allowable_stress:f64,
+											 removal_ratio:f64) -> TopologyResult {
+
+	let mut ground_structure = create_ground_structure(kp_list, material, 0.0);
+	let feasible:bool = optimise_topology(&mut ground_structure, kp_list, bc_list, pl_list, allowable_stress);
+	let truss = extract_optimised_truss(&ground_structure, kp_list, bc_list, pl_list, removal_ratio);
+
+	// Renumbering the bars, and selecting the smallest section with at least the required area.
+	let members:Vec<TopologyMember> = truss.iter().enumerate().map(|(i, conn)| {
+		let section = section_list.iter()
+			.filter(|section| section.area >= conn.area)
+			.min_by(|a, b| a.area.total_cmp(&b.area))
+			.or_else(|| section_list.iter().max_by(|a, b| a.area.total_cmp(&b.area)));
+
+		TopologyMember {
+			name: format!("opt{}", i + 1),
+			kp_1: conn.kp_1.clone(),
+			kp_2: conn.kp_2.clone(),
+			material: conn.material.clone(),
+			area: conn.area,
+			normal_force: conn.n_2,
+			section: section.map_or(String::new(), |section| section.name.clone()),
+		}
+	}).collect();
+
+	let volume:f64 = truss.iter().map(|conn| {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+		conn.area * element_geometry(kp_1, kp_2).0
+	}).sum();
+
+	TopologyResult {
+		ground_structure_bars: ground_structure.len(),
+		feasible,
+		volume,
+		members,
+	}
+}
+
+// Solving all variants of the parameter sweep. Each variant is a copy of the base model
+// with the sweep parameters set to the variant values.
+pub fn generate_result_parameter_sweep(model:&Model,
+									   sweep_parameters:&[SweepParameter],
+									   sweep_outputs:&[SweepOutput]) -> ParameterSweepResult {
+
+	let dofs:usize = dofs_per_keypoint(model.conn_list);
+	let mut variants:Vec<SweepVariant> = Vec::new();
+
+	for (i, parameter_values) in create_sweep_variants(sweep_parameters).into_iter().enumerate() {
+		let mut variant_kp_list:Vec<Keypoint> = model.kp_list.to_vec();
+		let mut variant_conn_list:Vec<Connection> = model.conn_list.to_vec();
+		let mut variant_pl_list:Vec<Pointload> = model.pl_list.to_vec();
+
+		for (sweep_parameter, &value) in sweep_parameters.iter().zip(parameter_values.iter()) {
+			apply_model_parameter(&sweep_parameter.parameter, &sweep_parameter.target, value,
+								  &mut variant_kp_list, &mut variant_conn_list, &mut variant_pl_list);
+		}
+
+		let variant = Model {
+			kp_list: &variant_kp_list,
+			conn_list: &variant_conn_list,
+			pl_list: &variant_pl_list,
+			..*model
+		};
+		let (displacement_vector, force_vector) = solve_linear_static(&variant);
+		generate_result_keypoint(&mut variant_kp_list, &force_vector, &displacement_vector, dofs);
+		// Only the end forces are needed, so the diagrams are sampled at the two ends.
+		generate_result_connection(&mut variant_conn_list, &variant_kp_list, &displacement_vector, dofs,
+								   model.dl_list, 2);
+
+		variants.push(SweepVariant {
+			variant: i + 1,
+			output_values: sweep_outputs.iter()
+				.map(|sweep_output| evaluate_model_output(&sweep_output.quantity, &sweep_output.target, &variant_kp_list, &variant_conn_list))
+				.collect(),
+			parameter_values,
+		});
+	}
+
+	ParameterSweepResult {
+		parameters: sweep_parameters.iter().map(|sweep_parameter| sweep_parameter.name.clone()).collect(),
+		outputs: sweep_outputs.iter().map(|sweep_output| sweep_output.name.clone()).collect(),
+		variants,
+	}
+}
+
+// Solving the model for random samples of the random variables, and collecting the statistics of each response.
+pub fn generate_result_reliability(model:&Model,
+								   random_variables:&[RandomVariable],
+								   reliability_outputs:&[ReliabilityOutput],
+								   number_of_samples:usize,
+								   seed:u64,
+								   histogram_bins:usize) -> ReliabilityResult {
+
+	let dofs:usize = dofs_per_keypoint(model.conn_list);
+	let mut rng = RandomNumberGenerator::new(seed);
+	let mut output_samples:Vec<Vec<f64>> = vec![Vec::new(); reliability_outputs.len()];
+
+	for _ in 0..number_of_samples {
+		let mut sample_kp_list:Vec<Keypoint> = model.kp_list.to_vec();
+		let mut sample_conn_list:Vec<Connection> = model.conn_list.to_vec();
+		let mut sample_pl_list:Vec<Pointload> = model.pl_list.to_vec();
+
+		for random_variable in random_variables {
+			let value:f64 = sample_random_variable(random_variable, &mut rng);
+			apply_model_parameter(&random_variable.parameter, &random_variable.target, value,
+								  &mut sample_kp_list, &mut sample_conn_list, &mut sample_pl_list);
+		}
+
+		let sample = Model {
+			kp_list: &sample_kp_list,
+			conn_list: &sample_conn_list,
+			pl_list: &sample_pl_list,
+			..*model
+		};
+		let (displacement_vector, force_vector) = solve_linear_static(&sample);
+		generate_result_keypoint(&mut sample_kp_list, &force_vector, &displacement_vector, dofs);
+		// Only the end forces are needed, so the diagrams are sampled at the two ends.
+		generate_result_connection(&mut sample_conn_list, &sample_kp_list, &displacement_vector, dofs,
+								   model.dl_list, 2);
+
+		for (reliability_output, samples) in reliability_outputs.iter().zip(output_samples.iter_mut()) {
+			samples.push(evaluate_model_output(&reliability_output.quantity, &reliability_output.target, &sample_kp_list, &sample_conn_list));
+		}
+	}
+
+	let outputs:Vec<ReliabilityOutputResult> = reliability_outputs.iter().zip(output_samples).map(|(reliability_output, samples)| {
+		let (mean, standard_deviation) = mean_and_standard_deviation(&samples);
+		let min:f64 = samples.iter().cloned().fold(f64::INFINITY, f64::min);
+		let max:f64 = samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+		let exceedances:usize = samples.iter().filter(|value| value.abs() > reliability_output.threshold).count();
+
+		// Equal bin widths between the min and max sample. The max sample is counted in the last bin.
+		let bin_width:f64 = (max - min) / histogram_bins as f64;
+		let mut histogram:Vec<HistogramBin> = (0..histogram_bins).map(|i| HistogramBin {
+			lower: min + bin_width * i as f64,
+			upper: min + bin_width * (i + 1) as f64,
+			count: 0,
+		}).collect();
+		for value in &samples {
+			let bin:usize = if bin_width > 0.0 { (((value - min) / bin_width) as usize).min(histogram_bins - 1) } else { 0 };
+			histogram[bin].count += 1;
+		}
+
+		ReliabilityOutputResult {
+			name: reliability_output.name.clone(),
+			quantity: reliability_output.quantity.clone(),
+			target: reliability_output.target.clone(),
+			mean,
+			standard_deviation,
+			min,
+			max,
+			threshold: reliability_output.threshold,
+			exceedance_probability: exceedances as f64 / number_of_samples as f64,
+			histogram,
+			samples,
+		}
+	}).collect();
+
+	ReliabilityResult {
+		number_of_samples,
+		seed,
+		outputs,
+	}
+}
+
+// Calculating the sensitivity tables of the responses, with the direct and adjoint methods.
+pub fn generate_result_sensitivities(model:&Model,
+									 sensitivity_responses:&[SensitivityResponse]) -> Vec<SensitivityResult> {
+
+	let Model {kp_list, conn_list, bc_list, dl_list, mat_list, load_case, ..} = *model;
+	let dofs:usize = dofs_per_keypoint(conn_list);
+	let size:usize = dofs * kp_list.len();
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs);
+
+	let global_stiffness_matrix = create_global_stiffness_matrix(kp_list, conn_list);
+	let dof_filter_vector = create_dof_filter_vector(kp_list, conn_list, bc_list);
+	let modified_global_stiffness_matrix = apply_boundary_conditions(&global_stiffness_matrix, &dof_filter_vector);
+	let (displacement_vector, _) = solve_linear_static(model);
+
+	let (lu, free_dofs) = factorise_reduced_stiffness_matrix(&modified_global_stiffness_matrix, &dof_filter_vector);
+
+	let response_location = |response:&SensitivityResponse| {
+		let offset:usize = match response.direction.as_str() {
+			"x" => 0,
+			"y" => 1,
+			"r" if dofs == 3 => 2,
+			_ => panic!("Unknown sensitivity direction for {}", response.name),
+		};
+		kp_map[&response.keypoint] + offset
+	};
+	let adjoint_vectors:Vec<DVector<f64>> = sensitivity_responses.iter()
+		.map(|response| solve_adjoint_vector(&lu, &free_dofs, response_location(response), size))
+		.collect();
+
+	let design_variables = create_design_variables(kp_list, conn_list);
+	let mut tables:Vec<Vec<SensitivityEntry>> = (0..sensitivity_responses.len()).map(|_| Vec::new()).collect();
+
+	for design_variable in &design_variables {
+		let (dk, df) = create_design_derivatives(kp_list, conn_list, dl_list, mat_list, load_case, design_variable);
+		let pseudo_load = calculate_pseudo_load(&dk, &df, &displacement_vector);
+		let du = solve_direct_sensitivity(&lu, &free_dofs, &pseudo_load);
+
+		let value:f64 = design_variable_value(design_variable, kp_list, conn_list);
+
+		for ((response, lambda), table) in sensitivity_responses.iter().zip(adjoint_vectors.iter()).zip(tables.iter_mut()) {
+			let location:usize = response_location(response);
+			table.push(SensitivityEntry {
+				parameter: design_variable.parameter.clone(),
+				target: design_variable.target.clone(),
+				value,
+				direct: du[location],
+				adjoint: lambda.dot(&pseudo_load),
+			});
+		}
+	}
+
+	sensitivity_responses.iter().zip(tables).map(|(response, sensitivities)| SensitivityResult {
+		name: response.name.clone(),
+		keypoint: response.keypoint.clone(),
+		direction: response.direction.clone(),
+		displacement: displacement_vector[response_location(response)],
+		sensitivities,
+	}).collect()
+}
+
+// Inserting the resulting forces and displacements into the keypoints of a 3D model.
+pub fn generate_result_space_keypoint(kp_list:&mut [SpaceKeypoint],
+									  force_vector:&DVector<f64>,
+									  displacement_vector:&DVector<f64>,
+									  dofs_per_keypoint:usize) {
+
+	let kp_hashmap = space_keypoint_hashmap(kp_list, dofs_per_keypoint);
+
+	for kp in kp_list.iter_mut() {
+		let number = kp_hashmap[&kp.name];
+		kp.fx = force_vector[number];
+		kp.fy = force_vector[number+1];
+		kp.fz = force_vector[number+2];
+		kp.ux = displacement_vector[number];
+		kp.uy = displacement_vector[number+1];
+		kp.uz = displacement_vector[number+2];
+
+		// Moments and rotations only exist in models with frame elements.
+		if dofs_per_keypoint == 6 {
+			kp.mx = force_vector[number+3];
+			kp.my = force_vector[number+4];
+			kp.mz = force_vector[number+5];
+			kp.rx = displacement_vector[number+3];
+			kp.ry = displacement_vector[number+4];
+			kp.rz = displacement_vector[number+5];
+		}
+	}
+}
+
+// End forces of a 3D connection in local axes, acting on the member ends.
+// x is the member axis. Forces are along, and moments about, the local axes.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct SpaceMemberEndForces {
+	pub name: String,
+	pub kp_1: String,
+	pub kp_2: String,
+	pub fx_1: f64,
+	pub fy_1: f64,
+	pub fz_1: f64,
+	pub mx_1: f64,
+	pub my_1: f64,
+	pub mz_1: f64,
+	pub fx_2: f64,
+	pub fy_2: f64,
+	pub fz_2: f64,
+	pub mx_2: f64,
+	pub my_2: f64,
+	pub mz_2: f64,
+}
+
+// Returning the member end forces of a 3D model in local axes.
+// The normal forces are also inserted into the connections, N_1 = -fx_1 and N_2 = fx_2. Tension is positive.
+pub fn generate_result_space_connection(conn_list:&mut [Connection],
+										kp_list:&[SpaceKeypoint],
+										displacement_vector:&DVector<f64>,
+										dofs_per_keypoint:usize,
+										poisson_ratio:f64) -> Vec<SpaceMemberEndForces> {
+
+	let kp_hashmap = space_keypoint_hashmap(kp_list, dofs_per_keypoint);
+
+	conn_list.iter_mut().map(|conn| {
+		let f = calculate_space_local_end_forces(conn, kp_list, &kp_hashmap, displacement_vector,
+												 dofs_per_keypoint, poisson_ratio);
+		conn.n_1 = -f[0];
+		conn.n_2 = f[6];
+
+		SpaceMemberEndForces {
+			name: conn.name.clone(),
+			kp_1: conn.kp_1.clone(),
+			kp_2: conn.kp_2.clone(),
+			fx_1: f[0], fy_1: f[1], fz_1: f[2], mx_1: f[3], my_1: f[4], mz_1: f[5],
+			fx_2: f[6], fy_2: f[7], fz_2: f[8], mx_2: f[9], my_2: f[10], mz_2: f[11],
+		}
+	}).collect()
+}
+
+// Stresses at a keypoint of a plane model, averaged over the connected surface elements.
+// The von Mises stress is the average of the element von Mises stresses at the keypoint.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct NodalStress {
+	pub keypoint: String,
+	pub sx: f64,
+	pub sy: f64,
+	pub txy: f64,
+	pub von_mises: f64,
+}
+
+// Recovering the stresses at the centroid of each surface element.
+pub fn generate_result_surface_element(surface_list:&mut [SurfaceElement],
+									   kp_list:&[Keypoint],
+									   displacement_vector:&DVector<f64>,
+									   dofs_per_keypoint:usize) {
+
+	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+
+	for surface in surface_list.iter_mut() {
+		let (xi, eta) = surface_element_centroid(surface);
+		let stress = calculate_surface_element_stress(surface, kp_list, &kp_hashmap, displacement_vector, xi, eta);
+		surface.sx = stress[0];
+		surface.sy = stress[1];
+		surface.txy = stress[2];
+		surface.von_mises = von_mises_stress(stress[0], stress[1], stress[2], surface.poisson_ratio, &surface.formulation);
+	}
+}
+
+// Recovering the stresses at the keypoints of the surface elements.
+// The element stresses are evaluated at each element keypoint and averaged over the elements sharing the keypoint.
+// Keypoints that are not part of any surface element are not included.
+pub fn generate_result_nodal_stress(kp_list:&[Keypoint],
+									surface_list:&[SurfaceElement],
+									displacement_vector:&DVector<f64>,
+									dofs_per_keypoint:usize) -> Vec<NodalStress> {
+
+	let kp_hashmap = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+
+	// Sum of (sx, sy, txy, von_mises) and the number of elements at each keypoint.
+	let mut stress_sums:HashMap<String, ([f64; 4], usize)> = HashMap::new();
+
+	for surface in surface_list {
+		let natural_coordinates = surface_element_natural_coordinates(surface);
+		for (kp_name, (xi, eta)) in surface.keypoints.iter().zip(natural_coordinates) {
+			let stress = calculate_surface_element_stress(surface, kp_list, &kp_hashmap, displacement_vector, xi, eta);
+			let von_mises = von_mises_stress(stress[0], stress[1], stress[2], surface.poisson_ratio, &surface.formulation);
+
+			let (sums, count) = stress_sums.entry(kp_name.clone()).or_insert(([0.0; 4], 0));
+			for (sum, value) in sums.iter_mut().zip([stress[0], stress[1], stress[2], von_mises]) {
+				*sum += value;
+			}
+			*count += 1;
+		}
+	}
+
+	kp_list.iter().filter_map(|kp| {
+		let (sums, count) = stress_sums.get(&kp.name)?;
+		let n = *count as f64;
+		Some(NodalStress {
+			keypoint: kp.name.clone(),
+			sx: sums[0] / n,
+			sy: sums[1] / n,
+			txy: sums[2] / n,
+			von_mises: sums[3] / n,
+		})
+	}).collect()
+}
@@ -0,0 +1,75 @@
+// This file contains the section selection of the member sizing.
+// Each group is given the lightest candidate section that passes the steel and timber checks of all its
+// connections, using the member forces of the previous analysis. The model is then re-solved,
+// as the member forces of indeterminate structures redistribute with the new stiffnesses.
+
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::material::Material;
+use crate::input::section::{Section, assign_section};
+use crate::input::steel_member::SteelMember;
+use crate::input::timber_member::TimberMember;
+use crate::input::timber_class::TimberClass;
+use crate::design::steel_check::check_steel_member;
+use crate::design::timber_check::check_timber_member;
+use crate::material_formulation::local_stiffness_matrix_frame::element_geometry;
+
+// Design parameters of the steel and timber member checks.
+pub struct MemberDesign<'a> {
+	pub steel_members: &'a [SteelMember],
+	pub timber_members: &'a [TimberMember],
+	pub timber_classes: &'a [TimberClass],
+	pub gamma_m0: f64,
+	pub gamma_m1: f64,
+}
+
+// Returning the lightest candidate that gives a utilization <= 1 in all connections of the group.
+// Candidates are sorted by area, as all connections of a group keep their material.
+// Returning the largest candidate if no candidate passes.
+pub fn select_lightest_section<'a>(group_connections:&[&Connection],
+								   candidates:&[&'a Section],
+								   design:&MemberDesign,
+								   kp_list:&[Keypoint]) -> &'a Section {
+
+	let mut sorted_candidates:Vec<&Section> = candidates.to_vec();
+	sorted_candidates.sort_by(|a, b| a.area.total_cmp(&b.area));
+
+	for candidate in &sorted_candidates {
+		let passed:bool = group_connections.iter().all(|conn| {
+			// Checking the current member forces with the candidate section.
+			// Connections without steel or timber design parameters do not govern the section.
+			let mut candidate_conn:Connection = (*conn).clone();
+			assign_section(&mut candidate_conn, candidate);
+
+			let steel_passed:bool = design.steel_members.iter()
+				.filter(|member| member.connection == conn.name)
+				.all(|member| check_steel_member(&candidate_conn, member, kp_list, design.gamma_m0, design.gamma_m1).utilization <= 1.0);
+			let timber_passed:bool = design.timber_members.iter()
+				.filter(|member| member.connection == conn.name)
+				.all(|member| {
+					let timber_class = design.timber_classes.iter().find(|class| class.name == member.timber_class).unwrap();
+					check_timber_member(&candidate_conn, member, timber_class, kp_list).utilization <= 1.0
+				});
+
+			steel_passed && timber_passed
+		});
+
+		if passed {
+			return candidate;
+		}
+	}
+
+	sorted_candidates.last().unwrap()
+}
+
+// Total weight of the connections, density * area * length.
+pub fn calculate_total_weight(conn_list:&[Connection], kp_list:&[Keypoint], mat_list:&[Material]) -> f64 {
+	conn_list.iter().map(|conn| {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+		let mat = mat_list.iter().find(|mat| mat.name == conn.material).unwrap();
+		let (length, _, _) = element_geometry(kp_1, kp_2);
+
+		mat.density * conn.area * length
+	}).sum()
+}
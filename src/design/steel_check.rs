@@ -0,0 +1,85 @@
+// This file contains the steel member checks for axial forces.
+// Tension: N_t,Rd = A*fy/gamma_M0.
+// Compression: N_b,Rd = chi*A*fy/gamma_M1, where chi is the flexural buckling reduction factor
+// found from the relative slenderness and the imperfection factor of the buckling curve.
+
+use serde::Serialize;
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::steel_member::SteelMember;
+use crate::material_formulation::local_stiffness_matrix_frame::element_geometry;
+use crate::fe_engine::member_forces::normal_force_extremes;
+
+// Result of the steel checks of a connection.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct SteelCheck {
+	pub connection: String,
+	pub tension_force: f64,
+	pub compression_force: f64,
+	pub tension_resistance: f64,
+	pub relative_slenderness: f64,
+	pub reduction_factor: f64,
+	pub compression_resistance: f64,
+	pub utilization: f64,
+	pub passed: bool,
+}
+
+// Imperfection factor of the buckling curves.
+pub fn imperfection_factor(buckling_curve:&str) -> f64 {
+	match buckling_curve {
+		"a0" => 0.13,
+		"a" => 0.21,
+		"b" => 0.34,
+		"c" => 0.49,
+		"d" => 0.76,
+		_ => panic!("Unknown buckling curve {}", buckling_curve),
+	}
+}
+
+// Flexural buckling reduction factor.
+// phi = 0.5*(1 + alpha*(lambda - 0.2) + lambda^2), chi = 1/(phi + sqrt(phi^2 - lambda^2)) <= 1.
+pub fn buckling_reduction_factor(relative_slenderness:f64, alpha:f64) -> f64 {
+	let phi:f64 = 0.5 * (1.0 + alpha * (relative_slenderness - 0.2) + relative_slenderness.powi(2));
+	let chi:f64 = 1.0 / (phi + (phi.powi(2) - relative_slenderness.powi(2)).sqrt());
+
+	chi.min(1.0)
+}
+
+pub fn check_steel_member(conn:&Connection,
+						  member:&SteelMember,
+						  kp_list:&[Keypoint],
+						  gamma_m0:f64,
+						  gamma_m1:f64) -> SteelCheck {
+
+	let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+	let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+	let (length, _, _) = element_geometry(kp_1, kp_2);
+
+	// Largest tension and compression along the connection, including the span.
+	let (tension_force, compression_force) = normal_force_extremes(conn);
+
+	// Relative slenderness, lambda = (L_cr/i) / (pi*sqrt(E/fy)).
+	let buckling_length:f64 = member.effective_length_factor * length;
+	let relative_slenderness:f64 = buckling_length / conn.radius_of_gyration
+		/ (std::f64::consts::PI * (conn.e_modulus / member.yield_strength).sqrt());
+
+	let reduction_factor:f64 = buckling_reduction_factor(relative_slenderness, imperfection_factor(&member.buckling_curve));
+
+	let tension_resistance:f64 = conn.area * member.yield_strength / gamma_m0;
+	let compression_resistance:f64 = reduction_factor * conn.area * member.yield_strength / gamma_m1;
+
+	let utilization:f64 = (tension_force / tension_resistance).max(compression_force / compression_resistance);
+
+	SteelCheck {
+		connection: conn.name.clone(),
+		tension_force,
+		compression_force,
+		tension_resistance,
+		relative_slenderness,
+		reduction_factor,
+		compression_resistance,
+		utilization,
+		passed: utilization <= 1.0,
+	}
+}
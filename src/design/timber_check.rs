@@ -0,0 +1,114 @@
+// This file contains the timber member checks for axial forces parallel to grain.
+// Design strengths: f_d = k_mod * f_k / gamma_M, where k_mod depends on the service class and load duration.
+// Tension: sigma_t,0,d <= f_t,0,d.
+// Compression: sigma_c,0,d <= k_c * f_c,0,d, where k_c is the column buckling factor
+// found from the relative slenderness and the straightness factor beta_c.
+
+use serde::Serialize;
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::timber_class::TimberClass;
+use crate::input::timber_member::TimberMember;
+use crate::material_formulation::local_stiffness_matrix_frame::element_geometry;
+use crate::fe_engine::member_forces::normal_force_extremes;
+
+// Result of the timber checks of a connection.
+#[derive(Debug)]
+#[derive(Serialize)]
+pub struct TimberCheck {
+	pub connection: String,
+	pub timber_class: String,
+	pub modification_factor: f64,
+	pub tension_stress: f64,
+	pub compression_stress: f64,
+	pub tension_strength: f64,
+	pub compression_strength: f64,
+	pub relative_slenderness: f64,
+	pub buckling_factor: f64,
+	pub utilization: f64,
+	pub passed: bool,
+}
+
+// Modification factor k_mod for solid timber and glulam.
+pub fn modification_factor(service_class:usize, load_duration:&str) -> f64 {
+	let factors:[f64; 5] = match service_class {
+		1 | 2 => [0.6, 0.7, 0.8, 0.9, 1.1],
+		3 => [0.5, 0.55, 0.65, 0.7, 0.9],
+		_ => panic!("Unknown service class {}", service_class),
+	};
+
+	match load_duration {
+		"permanent" => factors[0],
+		"long" => factors[1],
+		"medium" => factors[2],
+		"short" => factors[3],
+		"instantaneous" => factors[4],
+		_ => panic!("Unknown load duration {}", load_duration),
+	}
+}
+
+// Partial factor gamma_M and straightness factor beta_c of the timber type.
+pub fn timber_type_factors(timber_type:&str) -> (f64, f64) {
+	match timber_type {
+		"solid" => (1.3, 0.2),
+		"glulam" => (1.25, 0.1),
+		_ => panic!("Unknown timber type {}", timber_type),
+	}
+}
+
+// Column buckling factor.
+// k = 0.5*(1 + beta_c*(lambda - 0.3) + lambda^2), k_c = 1/(k + sqrt(k^2 - lambda^2)) <= 1.
+pub fn column_buckling_factor(relative_slenderness:f64, beta_c:f64) -> f64 {
+	if relative_slenderness <= 0.3 {
+		return 1.0;
+	}
+
+	let k:f64 = 0.5 * (1.0 + beta_c * (relative_slenderness - 0.3) + relative_slenderness.powi(2));
+	let k_c:f64 = 1.0 / (k + (k.powi(2) - relative_slenderness.powi(2)).sqrt());
+
+	k_c.min(1.0)
+}
+
+pub fn check_timber_member(conn:&Connection,
+						   member:&TimberMember,
+						   timber_class:&TimberClass,
+						   kp_list:&[Keypoint]) -> TimberCheck {
+
+	let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+	let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+	let (length, _, _) = element_geometry(kp_1, kp_2);
+
+	// Largest tensile and compressive stress along the connection, including the span.
+	let (tension_force, compression_force) = normal_force_extremes(conn);
+	let tension_stress:f64 = tension_force / conn.area;
+	let compression_stress:f64 = compression_force / conn.area;
+
+	// Design strengths.
+	let (gamma_m, beta_c) = timber_type_factors(&timber_class.timber_type);
+	let modification_factor:f64 = modification_factor(member.service_class, &member.load_duration);
+	let tension_strength:f64 = modification_factor * timber_class.tension_strength / gamma_m;
+	let compression_strength:f64 = modification_factor * timber_class.compression_strength / gamma_m;
+
+	// Relative slenderness, lambda = (L_cr/i) / pi * sqrt(f_c,0,k/E_0,05).
+	let buckling_length:f64 = member.effective_length_factor * length;
+	let relative_slenderness:f64 = buckling_length / conn.radius_of_gyration / std::f64::consts::PI
+		* (timber_class.compression_strength / timber_class.e_05).sqrt();
+
+	let buckling_factor:f64 = column_buckling_factor(relative_slenderness, beta_c);
+
+	let utilization:f64 = (tension_stress / tension_strength).max(compression_stress / (buckling_factor * compression_strength));
+
+	TimberCheck {
+		connection: conn.name.clone(),
+		timber_class: timber_class.name.clone(),
+		modification_factor,
+		tension_stress,
+		compression_stress,
+		tension_strength,
+		compression_strength,
+		relative_slenderness,
+		buckling_factor,
+		utilization,
+		passed: utilization <= 1.0,
+	}
+}
@@ -0,0 +1,192 @@
+// This file contains the truss topology optimisation with the ground structure method.
+// All keypoints are connected to each other with candidate bars, except bars passing through another keypoint.
+// The minimum volume truss is found with plastic layout optimisation as a linear program in the bar forces,
+// with each bar fully stressed. Bars without force are removed from the optimised truss.
+
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::input::pointload::Pointload;
+use nalgebra::{DMatrix, DVector};
+use crate::fe_engine::utils::global_stiffness_matrix_keypoint_hashmap;
+use crate::fe_engine::dof_filter_vector::create_dof_filter_vector;
+use crate::fe_engine::force_vector::create_force_vector;
+use crate::fe_engine::reduced_system::reduce_vector;
+use crate::fe_engine::linear_programming::solve_linear_program;
+use crate::material_formulation::local_stiffness_matrix_frame::element_geometry;
+
+// Returning true if a keypoint lies on the line between two other keypoints, excluding the end points.
+fn lies_between(kp:&Keypoint, kp_1:&Keypoint, kp_2:&Keypoint) -> bool {
+	let (length, c, s) = element_geometry(kp_1, kp_2);
+	let position:f64 = (kp.x - kp_1.x) * c + (kp.y - kp_1.y) * s;
+	let distance:f64 = (-(kp.x - kp_1.x) * s + (kp.y - kp_1.y) * c).abs();
+	let tolerance:f64 = 1e-9 * length;
+
+	distance < tolerance && position > tolerance && position < length - tolerance
+}
+
+// Connecting all keypoints with candidate bars. Bars passing through another keypoint are skipped,
+// as they are covered by the shorter bars.
+pub fn create_ground_structure(kp_list:&[Keypoint], material:&str, area:f64) -> Vec<Connection> {
+	let mut ground_structure:Vec<Connection> = Vec::new();
+
+	for (i, kp_1) in kp_list.iter().enumerate() {
+		for kp_2 in kp_list.iter().skip(i + 1) {
+			if kp_list.iter().any(|kp| lies_between(kp, kp_1, kp_2)) {
+				continue;
+			}
+
+			ground_structure.push(Connection {
+				name: format!("gs{}", ground_structure.len() + 1),
+				kp_1: kp_1.name.clone(),
+				kp_2: kp_2.name.clone(),
+				material: material.to_string(),
+				element: "bar".to_string(),
+				section: String::new(),
+				e_modulus: 0.0,
+				area,
+				inertia: 0.0,
+				inertia_minor: 0.0,
+				torsion_constant: 0.0,
+				radius_of_gyration: 0.0,
+				orientation: [0.0; 3],
+				release_1: String::new(),
+				release_2: String::new(),
+				n_1: 0.0, v_1: 0.0, m_1: 0.0, n_2: 0.0, v_2: 0.0, m_2: 0.0,
+				diagram: Vec::new(),
+				utilization: 0.0,
+			});
+		}
+	}
+
+	ground_structure
+}
+
+// Finding the minimum volume truss with plastic layout optimisation.
+// Bar forces are split into tension and compression parts, t = t+ - t-, and the linear program
+// minimize sum(L_i * (t+_i + t-_i) / sigma_allow), subject to B * (t+ - t-) = f and t+, t- >= 0,
+// is solved for the free dofs. Supported dofs, including spring supports, are treated as rigid.
+// The required areas |t| / sigma_allow and normal forces are stored on the connections.
+// Returning false if no truss in the ground structure can carry the loads.
+pub fn optimise_topology(ground_structure:&mut [Connection],
+						 kp_list:&[Keypoint],
+						 bc_list:&[BoundaryCondition],
+						 pl_list:&[Pointload],
+						 allowable_stress:f64) -> bool {
+
+	let dofs:usize = 2;
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs);
+	let dof_filter_vector = create_dof_filter_vector(kp_list, ground_structure, bc_list);
+	let free_dofs:Vec<usize> = (0..dof_filter_vector.nrows()).filter(|&i| dof_filter_vector[i] == -1.0).collect();
+	let force_vector = reduce_vector(&create_force_vector(kp_list, pl_list, dofs), &free_dofs);
+
+	// Equilibrium matrix. A tension force pulls kp_1 towards kp_2, and kp_2 towards kp_1.
+	let number_of_bars:usize = ground_structure.len();
+	let mut equilibrium_matrix:DMatrix<f64> = DMatrix::zeros(dofs * kp_list.len(), number_of_bars);
+	let mut cost:DVector<f64> = DVector::zeros(2 * number_of_bars);
+
+	for (i, conn) in ground_structure.iter().enumerate() {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+		let (length, c, s) = element_geometry(kp_1, kp_2);
+
+		equilibrium_matrix[(kp_map[&conn.kp_1], i)] = -c;
+		equilibrium_matrix[(kp_map[&conn.kp_1]+1, i)] = -s;
+		equilibrium_matrix[(kp_map[&conn.kp_2], i)] = c;
+		equilibrium_matrix[(kp_map[&conn.kp_2]+1, i)] = s;
+
+		cost[i] = length / allowable_stress;
+		cost[number_of_bars + i] = length / allowable_stress;
+	}
+
+	let equilibrium_matrix_reduced = DMatrix::from_fn(free_dofs.len(), number_of_bars, |i, j| equilibrium_matrix[(free_dofs[i], j)]);
+	let mut constraint_matrix:DMatrix<f64> = DMatrix::zeros(free_dofs.len(), 2 * number_of_bars);
+	constraint_matrix.columns_mut(0, number_of_bars).copy_from(&equilibrium_matrix_reduced);
+	constraint_matrix.columns_mut(number_of_bars, number_of_bars).copy_from(&(-&equilibrium_matrix_reduced));
+
+	let Some(solution) = solve_linear_program(&cost, &constraint_matrix, &force_vector) else {
+		return false;
+	};
+
+	for (i, conn) in ground_structure.iter_mut().enumerate() {
+		let normal_force:f64 = solution[i] - solution[number_of_bars + i];
+		conn.n_1 = normal_force;
+		conn.n_2 = normal_force;
+		conn.area = normal_force.abs() / allowable_stress;
+	}
+
+	true
+}
+
+// Removing the unused bars, keeping bars with area > ratio * largest area.
+// Unloaded and unsupported keypoints joining two collinear bars are removed, and the two bars are merged.
+// Otherwise the keypoint would have no stiffness perpendicular to the bars.
+pub fn extract_optimised_truss(ground_structure:&[Connection],
+							   kp_list:&[Keypoint],
+							   bc_list:&[BoundaryCondition],
+							   pl_list:&[Pointload],
+							   removal_ratio:f64) -> Vec<Connection> {
+
+	let max_area:f64 = ground_structure.iter().map(|conn| conn.area).fold(0.0, f64::max);
+	let mut truss:Vec<Connection> = ground_structure.iter()
+		.filter(|conn| conn.area > removal_ratio * max_area)
+		.cloned()
+		.collect();
+
+	loop {
+		let merge = kp_list.iter().find_map(|kp| {
+			let is_fixed:bool = bc_list.iter().any(|bc| bc.keypoint == kp.name) || pl_list.iter().any(|pl| pl.keypoint == kp.name);
+			let connected:Vec<usize> = (0..truss.len())
+				.filter(|&i| truss[i].kp_1 == kp.name || truss[i].kp_2 == kp.name)
+				.collect();
+			if is_fixed || connected.len() != 2 {
+				return None;
+			}
+
+			// Finding the far ends of the two bars.
+			let far_end = |conn:&Connection| if conn.kp_1 == kp.name { conn.kp_2.clone() } else { conn.kp_1.clone() };
+			let end_1 = far_end(&truss[connected[0]]);
+			let end_2 = far_end(&truss[connected[1]]);
+			let kp_1 = kp_list.iter().find(|kp| kp.name == end_1).unwrap();
+			let kp_2 = kp_list.iter().find(|kp| kp.name == end_2).unwrap();
+
+			if lies_between(kp, kp_1, kp_2) { Some((connected[0], connected[1], end_1, end_2)) } else { None }
+		});
+
+		let Some((index_1, index_2, end_1, end_2)) = merge else {
+			break;
+		};
+
+		let removed = truss.remove(index_2);
+		let merged = &mut truss[index_1];
+		merged.kp_1 = end_1;
+		merged.kp_2 = end_2;
+		merged.area = merged.area.max(removed.area);
+	}
+
+	truss
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::generators::utils::{create_keypoint, create_boundary_condition, create_pointload};
+
+	// Cantilever truss from two clamped supports, as generated for the base of a building.
+	// The optimal truss is a tension bar from the upper support and a compression bar from the lower support.
+	#[test]
+	fn clamped_supports_carry_the_loads_of_the_ground_structure() {
+		let kp_list = vec![create_keypoint("kp1", 0.0, 0.0), create_keypoint("kp2", 0.0, 1.0), create_keypoint("kp3", 1.0, 0.0)];
+		let bc_list = vec![create_boundary_condition("bc1", "kp1", "4"), create_boundary_condition("bc2", "kp2", "4")];
+		let pl_list = vec![create_pointload("p1", "kp3", 0.0, -10.0)];
+		let mut ground_structure = create_ground_structure(&kp_list, "mat1", 1.0);
+
+		assert!(optimise_topology(&mut ground_structure, &kp_list, &bc_list, &pl_list, 100.0));
+
+		let normal_force = |kp_1:&str, kp_2:&str| ground_structure.iter()
+			.find(|conn| conn.kp_1 == kp_1 && conn.kp_2 == kp_2).unwrap().n_1;
+		assert!(normal_force("kp1", "kp2").abs() < 1e-6);
+		assert!((normal_force("kp1", "kp3") + 10.0).abs() < 1e-6);
+		assert!((normal_force("kp2", "kp3") - 10.0 * 2.0_f64.sqrt()).abs() < 1e-6);
+	}
+}
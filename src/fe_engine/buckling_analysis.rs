@@ -0,0 +1,103 @@
+// This file contains the linear buckling (eigenvalue) analysis.
+// The eigenproblem (K + lambda*K_G)*phi = 0 is solved on the reduced dofs,
+// where K_G is the geometric stiffness matrix from the normal forces of a linear analysis.
+// The load factor lambda scales the applied loads to the critical load.
+//
+// Written as K*phi = lambda*(-K_G)*phi, the problem is solved with the same
+// Cholesky transformation as the modal analysis, where mu = 1/lambda.
+
+use nalgebra::{DMatrix, DVector};
+use crate::fe_engine::reduced_system::{free_dof_locations, reduce_matrix, expand_reduced_vector};
+use crate::fe_engine::modal_analysis::solve_stiffness_eigenproblem;
+
+pub struct BucklingMode {
+	pub number: usize,
+	pub load_factor: f64,
+	pub shape: DVector<f64>, // Global buckling mode shape, normalized to a largest value of 1.
+}
+
+pub fn solve_buckling_analysis(modified_global_stiffness_matrix:&DMatrix<f64>,
+							   global_geometric_stiffness_matrix:&DMatrix<f64>,
+							   dof_filter_vector:&DVector<f64>,
+							   number_of_modes:usize) -> Vec<BucklingMode> {
+
+	// Removing the fixed dofs from the stiffness and geometric stiffness matrix.
+	let free_dofs = free_dof_locations(dof_filter_vector);
+	let stiffness_matrix_reduced = reduce_matrix(modified_global_stiffness_matrix, &free_dofs);
+	let geometric_matrix_reduced = reduce_matrix(global_geometric_stiffness_matrix, &free_dofs);
+
+	// Only positive load factors are returned. Negative load factors require reversed loads.
+	let (mu_list, phi_list) = solve_stiffness_eigenproblem(&stiffness_matrix_reduced, &(-geometric_matrix_reduced));
+
+	let mut buckling_modes:Vec<BucklingMode> = Vec::new();
+
+	for (i, (mu, phi)) in mu_list.iter().zip(phi_list.iter()).take(number_of_modes).enumerate() {
+		let phi_normalized:DVector<f64> = phi / phi.amax();
+
+		buckling_modes.push(BucklingMode {
+			number: i + 1,
+			load_factor: 1.0 / mu,
+			shape: expand_reduced_vector(&phi_normalized, &free_dofs, dof_filter_vector.nrows()),
+		});
+	}
+
+	buckling_modes
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::input::connection::Connection;
+	use crate::fe_engine::global_stiffness_matrix::{create_global_stiffness_matrix, apply_boundary_conditions};
+	use crate::fe_engine::global_geometric_stiffness_matrix::create_global_geometric_stiffness_matrix;
+	use crate::fe_engine::dof_filter_vector::create_dof_filter_vector;
+	use crate::generators::utils::{create_keypoint, create_connection, create_boundary_condition};
+
+	fn frame(name:&str, kp_1:&str, kp_2:&str, normal_force:f64) -> Connection {
+		let mut conn = create_connection(name, kp_1, kp_2, "mat1", "frame");
+		conn.e_modulus = 210000000.0;
+		conn.area = 0.01;
+		conn.inertia = 0.0001;
+		conn.n_1 = normal_force;
+		conn.n_2 = normal_force;
+		conn
+	}
+
+	// A cantilever column braced by a leaning column through a pinned link, with a unit compression in both columns.
+	// The leaning column has no bending stiffness and reduces the sway stiffness of the cantilever by P/h,
+	// which gives the critical load from tan(x) = 2x, with P_cr = x^2 * EI / h^2.
+	#[test]
+	fn leaning_column_reduces_the_critical_load() {
+		let height:f64 = 4.0;
+		let segments:usize = 8;
+
+		let mut kp_list = vec![create_keypoint("b1", 3.0, 0.0), create_keypoint("b2", 3.0, height)];
+		let mut conn_list = Vec::new();
+		for i in 0..=segments {
+			kp_list.push(create_keypoint(&format!("a{}", i), 0.0, height * i as f64 / segments as f64));
+			if i > 0 {
+				conn_list.push(frame(&format!("a{}", i), &format!("a{}", i - 1), &format!("a{}", i), -1.0));
+			}
+		}
+		let mut leaning_column = frame("b", "b1", "b2", -1.0);
+		leaning_column.release_1 = "rz".to_string();
+		leaning_column.release_2 = "rz".to_string();
+		let mut link = frame("link", &format!("a{}", segments), "b2", 0.0);
+		link.release_1 = "rz".to_string();
+		link.release_2 = "rz".to_string();
+		conn_list.push(leaning_column);
+		conn_list.push(link);
+		let bc_list = vec![create_boundary_condition("bc1", "a0", "4"), create_boundary_condition("bc2", "b1", "2")];
+
+		let dof_filter_vector = create_dof_filter_vector(&kp_list, &conn_list, &bc_list);
+		let stiffness_matrix = apply_boundary_conditions(&create_global_stiffness_matrix(&kp_list, &conn_list), &dof_filter_vector);
+		let geometric_matrix = create_global_geometric_stiffness_matrix(&kp_list, &conn_list);
+		let buckling_modes = solve_buckling_analysis(&stiffness_matrix, &geometric_matrix, &dof_filter_vector, 1);
+
+		let x:f64 = 1.1655611852072112;
+		let critical_load:f64 = x.powi(2) * conn_list[0].e_modulus * conn_list[0].inertia / height.powi(2);
+		let load_factor:f64 = buckling_modes[0].load_factor;
+		assert!((load_factor - critical_load).abs() < 1e-3 * critical_load,
+				"load factor {} critical load {}", load_factor, critical_load);
+	}
+}
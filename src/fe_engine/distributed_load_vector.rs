@@ -0,0 +1,95 @@
+// This file converts distributed loads on connections into equivalent nodal forces and moments.
+// Frame elements receive consistent nodal loads (fixed-end forces). At released frame ends the fixed-end moment
+// is condensed out, giving the fixed-end forces of a propped cantilever, or of a simply supported span for two hinges.
+// Bar elements have no bending stiffness, and receive the reactions of a simply supported span.
+
+use nalgebra::{DMatrix, DVector};
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::distributed_load::DistributedLoad;
+use crate::material_formulation::local_stiffness_matrix_frame::{element_geometry, frame_transformation_matrix, frame_matrix_local_axes};
+use crate::material_formulation::static_condensation::condense_released_load_vector;
+use crate::fe_engine::utils::{global_stiffness_matrix_keypoint_hashmap, frame_released_dofs};
+
+// Returning the local load components per unit length of a distributed load.
+// Returned as (axial at kp_1, axial at kp_2, transverse at kp_1, transverse at kp_2).
+pub fn local_distributed_load_components(dl:&DistributedLoad, kp_1:&Keypoint, kp_2:&Keypoint) -> (f64, f64, f64, f64) {
+	let (_, c, s) = element_geometry(kp_1, kp_2);
+
+	// Global loads are decomposed into the local axial and transverse direction.
+	let (axial_factor, transverse_factor):(f64, f64) = match (dl.coordinate_system.as_str(), dl.direction.as_str()) {
+		("local", "x") => (1.0, 0.0),
+		("local", "y") => (0.0, 1.0),
+		("global", "x") => (c, -s),
+		("global", "y") => (s, c),
+		_ => panic!("Unknown distributed load direction for {}", dl.name),
+	};
+
+	(axial_factor * dl.load_start, axial_factor * dl.load_end,
+	 transverse_factor * dl.load_start, transverse_factor * dl.load_end)
+}
+
+// Returning the equivalent nodal load vector of a connection in local coordinates.
+// Vector layout: (axial 1, transverse 1, moment 1, axial 2, transverse 2, moment 2).
+pub fn equivalent_nodal_load_local(conn:&Connection, kp_1:&Keypoint, kp_2:&Keypoint, dl_list:&[DistributedLoad]) -> DVector<f64> {
+	let (length, _, _) = element_geometry(kp_1, kp_2);
+	let mut f_eq:DVector<f64> = DVector::zeros(6);
+
+	for dl in dl_list.iter().filter(|dl| dl.connection == conn.name) {
+		let (p_1, p_2, q_1, q_2) = local_distributed_load_components(dl, kp_1, kp_2);
+
+		// Axial load, linearly varying.
+		f_eq[0] += length * (2.0 * p_1 + p_2) / 6.0;
+		f_eq[3] += length * (p_1 + 2.0 * p_2) / 6.0;
+
+		// Transverse load, linearly varying.
+		if conn.element == "frame" {
+			f_eq[1] += length * (7.0 * q_1 + 3.0 * q_2) / 20.0;
+			f_eq[2] += length * length * (3.0 * q_1 + 2.0 * q_2) / 60.0;
+			f_eq[4] += length * (3.0 * q_1 + 7.0 * q_2) / 20.0;
+			f_eq[5] -= length * length * (2.0 * q_1 + 3.0 * q_2) / 60.0;
+		} else {
+			f_eq[1] += length * (2.0 * q_1 + q_2) / 6.0;
+			f_eq[4] += length * (q_1 + 2.0 * q_2) / 6.0;
+		}
+	}
+
+	// The condensation only depends on the ratios of the bending stiffness terms, so a unit section is used.
+	condense_released_load_vector(&frame_matrix_local_axes(length, 1.0, 1.0, 1.0), &f_eq, &frame_released_dofs(conn))
+}
+
+pub fn create_distributed_load_vector(kp_list:&[Keypoint], 
+									  conn_list:&[Connection], 
+									  dl_list:&[DistributedLoad], 
+									  dofs_per_keypoint:usize) -> DVector<f64> {
+	// Creating vector size based on number of keypoints and dofs per keypoint.
+	let size:usize = dofs_per_keypoint*kp_list.len();
+	let mut dl_vec:DVector<f64> = DVector::from_element(size, 0.0);
+
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+
+	for conn in conn_list {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+
+		// Rotating the equivalent nodal loads into global coordinates.
+		let t:DMatrix<f64> = frame_transformation_matrix(kp_1, kp_2);
+		let f_eq_global:DVector<f64> = t.transpose() * equivalent_nodal_load_local(conn, kp_1, kp_2, dl_list);
+
+		let loc_1 = kp_map[&conn.kp_1];
+		let loc_2 = kp_map[&conn.kp_2];
+
+		dl_vec[loc_1] += f_eq_global[0];
+		dl_vec[loc_1+1] += f_eq_global[1];
+		dl_vec[loc_2] += f_eq_global[3];
+		dl_vec[loc_2+1] += f_eq_global[4];
+
+		// Moments are only present for frame elements.
+		if conn.element == "frame" {
+			dl_vec[loc_1+2] += f_eq_global[2];
+			dl_vec[loc_2+2] += f_eq_global[5];
+		}
+	}
+
+	dl_vec
+}
@@ -3,22 +3,17 @@
 
 use nalgebra::{DVector};
 use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
 use crate::input::boundary_condition::BoundaryCondition;
-use std::collections::HashMap;
+use crate::fe_engine::utils::{global_stiffness_matrix_keypoint_hashmap, dofs_per_keypoint, frame_released_dofs};
 
-pub fn create_dof_filter_vector(kp_list:&[Keypoint], bc_list:&[BoundaryCondition]) -> DVector<f64> {
-	// Creating dict for keypoint force directions and global force vector location.
-	let mut kp_map:HashMap<String, usize> = HashMap::new();
-	let mut number:usize = 0;
+pub fn create_dof_filter_vector(kp_list:&[Keypoint], conn_list:&[Connection], bc_list:&[BoundaryCondition]) -> DVector<f64> {
+	// Creating dict for keypoint locations in the global force vector.
+	let dofs:usize = dofs_per_keypoint(conn_list);
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs);
 
-	for kp in kp_list {
-		kp_map.insert(format!("{}_x", kp.name), number);
-		kp_map.insert(format!("{}_y", kp.name), number+1);
-		number += 2;
-	}
-
-	// Creating vector size based on bar elements.
-	let size: usize = 2*kp_list.len();
+	// Creating vector size based on number of keypoints and dofs per keypoint.
+	let size: usize = dofs*kp_list.len();
 
 	// Creating boundary condition vector.
 	let mut dof_filter_vec:DVector<f64> = DVector::from_element(size, -1.0);
@@ -30,41 +25,84 @@ pub fn create_dof_filter_vector(kp_list:&[Keypoint], bc_list:&[BoundaryCondition
 	for bc in bc_list {
 
 		// Finding locations in the global stiffness matrix for the keypoint.
-		let loc_x:usize = *kp_map.get(&format!("{}_x", bc.keypoint)).unwrap();
-		let loc_y:usize = *kp_map.get(&format!("{}_y", bc.keypoint)).unwrap();
+		let loc_x:usize = kp_map[&bc.keypoint];
+		let loc_y:usize = loc_x + 1;
+		let loc_r:usize = loc_x + 2;
+
+		// Setting the boundary condition value
+		let bc_value:f64 = if bc.spring_stiffness == -1.0 {
+			0.0
+		} else if bc.spring_stiffness > 0.0 {
+			bc.spring_stiffness
+		} else {
+			continue;
+		};
 
 		// BC lateral direction
 		if bc.fixture == "0" {
-			// Setting the boundary condition value
-			if bc.spring_stiffness == -1.0 {
-				dof_filter_vec[loc_x] = 0.0;
-			} else if bc.spring_stiffness > 0.0 {
-				dof_filter_vec[loc_x] = bc.spring_stiffness;
-			}
+			dof_filter_vec[loc_x] = bc_value;
 		}
 
 		// BC vertical direction
-		if bc.fixture == "1" {
-			// Setting the boundary condition value
-			if bc.spring_stiffness == -1.0 {
-				dof_filter_vec[loc_y] = 0.0;
-			} else if bc.spring_stiffness > 0.0 {
-				dof_filter_vec[loc_y] = bc.spring_stiffness;
-			}
+		else if bc.fixture == "1" {
+			dof_filter_vec[loc_y] = bc_value;
 		}
 
 		// BC both directions
 		else if bc.fixture == "2" {
-			// Setting the boundary condition value
-			if bc.spring_stiffness == -1.0 {
-				dof_filter_vec[loc_x] = 0.0;
-				dof_filter_vec[loc_y] = 0.0;
-			} else if bc.spring_stiffness > 0.0 {
-				dof_filter_vec[loc_x] = bc.spring_stiffness;
-				dof_filter_vec[loc_y] = bc.spring_stiffness;
+			dof_filter_vec[loc_x] = bc_value;
+			dof_filter_vec[loc_y] = bc_value;
+		}
+
+		// BC rotation. Only available in models with frame elements.
+		else if bc.fixture == "3" && dofs == 3 {
+			dof_filter_vec[loc_r] = bc_value;
+		}
+
+		// BC both directions and rotation.
+		// The rotation is only restrained in models with frame elements. Without them, the support acts as a pin.
+		else if bc.fixture == "4" {
+			dof_filter_vec[loc_x] = bc_value;
+			dof_filter_vec[loc_y] = bc_value;
+			if dofs == 3 {
+				dof_filter_vec[loc_r] = bc_value;
+			}
+		}
+	}
+
+	// Keypoints that are not connected to any frame element have no rotational stiffness.
+	// The same applies when the rotation is released at all connected frame ends.
+	// Their rotational dof is fixed to keep the stiffness matrix solvable.
+	if dofs == 3 {
+		for kp in kp_list {
+			let connected_to_frame:bool = conn_list.iter().any(|conn| {
+				let released_dofs = frame_released_dofs(conn);
+				conn.element == "frame" && ((conn.kp_1 == kp.name && !released_dofs.contains(&2))
+					|| (conn.kp_2 == kp.name && !released_dofs.contains(&5)))
+			});
+			if !connected_to_frame {
+				dof_filter_vec[kp_map[&kp.name]+2] = 0.0;
 			}
 		}
 	}
 
 	dof_filter_vec
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::generators::utils::{create_keypoint, create_connection, create_boundary_condition};
+
+	#[test]
+	fn clamped_support_restrains_the_translations_of_a_bar_only_model() {
+		let kp_list = vec![create_keypoint("kp1", 0.0, 0.0), create_keypoint("kp2", 2.0, 0.0)];
+		let conn_list = vec![create_connection("c1", "kp1", "kp2", "mat1", "bar")];
+		let bc_list = vec![create_boundary_condition("bc1", "kp1", "4"), create_boundary_condition("bc2", "kp2", "3")];
+
+		let dof_filter_vec = create_dof_filter_vector(&kp_list, &conn_list, &bc_list);
+
+		assert_eq!(dof_filter_vec.len(), 4);
+		assert_eq!(dof_filter_vec.as_slice(), &[0.0, 0.0, -1.0, -1.0]);
+	}
+}
@@ -0,0 +1,42 @@
+// This file contains generation of the geometric stiffness matrix.
+// The normal forces are taken from the connection results of a linear analysis.
+
+use nalgebra::DMatrix;
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::material_formulation::local_geometric_stiffness_matrix::{local_bar_geometric_matrix, local_frame_geometric_matrix};
+use crate::fe_engine::utils::{global_stiffness_matrix_keypoint_hashmap, dofs_per_keypoint, element_dof_locations, frame_released_dofs};
+
+pub fn create_global_geometric_stiffness_matrix(kp_list:&[Keypoint], conn_list:&[Connection]) -> DMatrix<f64> {
+
+	// Creating size based on number of keypoints and dofs per keypoint.
+	let dofs:usize = dofs_per_keypoint(conn_list);
+	let size:usize = dofs*kp_list.len();
+
+	let mut global_geometric_matrix = DMatrix::<f64>::zeros(size, size);
+
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs);
+
+	for conn in conn_list {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+
+		// Applying the mean normal force, in case it varies along the connection.
+		let axial_force:f64 = (conn.n_1 + conn.n_2) / 2.0;
+
+		let local_mat:DMatrix<f64> = if conn.element == "frame" {
+			local_frame_geometric_matrix(kp_1, kp_2, axial_force, &frame_released_dofs(conn))
+		} else {
+			local_bar_geometric_matrix(kp_1, kp_2, axial_force)
+		};
+
+		let locs = element_dof_locations(conn, &kp_map);
+		for (i, loc_i) in locs.iter().enumerate() {
+			for (j, loc_j) in locs.iter().enumerate() {
+				global_geometric_matrix[(*loc_i,*loc_j)] += local_mat[(i,j)];
+			}
+		}
+	}
+
+	global_geometric_matrix
+}
@@ -0,0 +1,43 @@
+// This file contains generation of the mass matrix.
+// Only translational mass is included. Rotational dofs of frame models are massless.
+
+use nalgebra::DMatrix;
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::material::Material;
+use crate::material_formulation::local_mass_matrix_bar::local_bar_mass_matrix;
+use crate::fe_engine::utils::{global_stiffness_matrix_keypoint_hashmap, dofs_per_keypoint};
+
+pub fn create_global_mass_matrix(kp_list:&[Keypoint],
+								 conn_list:&[Connection],
+								 mat_list:&[Material],
+								 formulation:&str) -> DMatrix<f64> {
+
+	// Creating size based on number of keypoints and dofs per keypoint.
+	let dofs:usize = dofs_per_keypoint(conn_list);
+	let size:usize = dofs*kp_list.len();
+
+	let mut global_mass_matrix = DMatrix::<f64>::zeros(size, size);
+
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs);
+
+	for conn in conn_list {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+		let mat = mat_list.iter().find(|mat| mat.name == conn.material).unwrap();
+		let local_mass_mat:DMatrix<f64> = local_bar_mass_matrix(kp_1, kp_2, mat.density, conn.area, formulation);
+
+		// Inserting the translational dofs of both keypoints.
+		let loc_1 = kp_map[&conn.kp_1];
+		let loc_2 = kp_map[&conn.kp_2];
+		let locs:[usize; 4] = [loc_1, loc_1+1, loc_2, loc_2+1];
+
+		for (i, loc_i) in locs.iter().enumerate() {
+			for (j, loc_j) in locs.iter().enumerate() {
+				global_mass_matrix[(*loc_i,*loc_j)] += local_mass_mat[(i,j)];
+			}
+		}
+	}
+
+	global_mass_matrix
+}
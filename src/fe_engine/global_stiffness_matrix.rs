@@ -4,58 +4,55 @@ use nalgebra::{DMatrix, DVector};
 use crate::input::keypoint::Keypoint;
 use crate::input::connection::Connection;
 use crate::material_formulation::local_stiffness_matrix_bar::local_bar_matrix;
-use crate::fe_engine::utils::global_stiffness_matrix_keypoint_hashmap;
+use crate::material_formulation::local_stiffness_matrix_frame::local_frame_matrix;
+use crate::fe_engine::utils::{global_stiffness_matrix_keypoint_hashmap, dofs_per_keypoint, element_dof_locations, frame_released_dofs};
 use std::collections::HashMap;
 
 pub fn create_global_stiffness_matrix(kp_list: &[Keypoint], 
-									  conn_list: &[Connection], 
-									  e_module:f64, 
-									  area:f64
+									  conn_list: &[Connection]
 									  ) -> DMatrix<f64> {
 	
-	// Creating size based on bar elements
-	let size: usize = 2*kp_list.len();
+	// Creating size based on number of keypoints and dofs per keypoint.
+	let dofs:usize = dofs_per_keypoint(conn_list);
+	let size: usize = dofs*kp_list.len();
 
 	// Making an identity matrix based on number of keypoints
 	let mut global_identity_matrix = DMatrix::<f64>::zeros(size, size);
 
+	// Finding keypoint locations in the global stiffness matrix.
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs);
+
 	for conn in conn_list {
 		// Finding the keypoints structs needed for calculating the local stiffness matrix.
 		let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
 		let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
-		let local_bar_mat:DMatrix<f64> = local_bar_matrix(kp_1, kp_2, e_module, area);
-
-		// Finding keypoint locations in the global stiffness matrix.
-		let kp_map = global_stiffness_matrix_keypoint_hashmap(&kp_list);
-		let loc_1 = kp_map[&conn.kp_1];
-		let loc_2 = kp_map[&conn.kp_2];
-
-		// Inserting keypoint local values into the global stiffness matrix.
-		global_identity_matrix[(loc_1,loc_1)] += local_bar_mat[(0,0)];
-		global_identity_matrix[(loc_1,loc_1+1)] += local_bar_mat[(0,1)];
-		global_identity_matrix[(loc_1+1,loc_1)] += local_bar_mat[(1,0)];
-		global_identity_matrix[(loc_1+1,loc_1+1)] += local_bar_mat[(1,1)];
-
-		global_identity_matrix[(loc_2,loc_2)] += local_bar_mat[(2,2)];
-		global_identity_matrix[(loc_2,loc_2+1)] += local_bar_mat[(2,3)];
-		global_identity_matrix[(loc_2+1,loc_2)] += local_bar_mat[(3,2)];
-		global_identity_matrix[(loc_2+1,loc_2+1)] += local_bar_mat[(3,3)];
-
-		global_identity_matrix[(loc_1,loc_2)] += local_bar_mat[(0,2)];
-		global_identity_matrix[(loc_1,loc_2+1)] += local_bar_mat[(0,3)];
-		global_identity_matrix[(loc_1+1,loc_2)] += local_bar_mat[(1,2)];
-		global_identity_matrix[(loc_1+1,loc_2+1)] += local_bar_mat[(1,3)];
-
-		global_identity_matrix[(loc_2,loc_1)] += local_bar_mat[(2,0)];
-		global_identity_matrix[(loc_2,loc_1+1)] += local_bar_mat[(3,0)];
-		global_identity_matrix[(loc_2+1,loc_1)] += local_bar_mat[(2,1)];
-		global_identity_matrix[(loc_2+1,loc_1+1)] += local_bar_mat[(3,1)];
+		let local_mat:DMatrix<f64> = element_stiffness_matrix(conn, kp_1, kp_2);
 
+		// Inserting element local values into the global stiffness matrix.
+		let locs = element_dof_locations(conn, &kp_map);
+		for (i, loc_i) in locs.iter().enumerate() {
+			for (j, loc_j) in locs.iter().enumerate() {
+				global_identity_matrix[(*loc_i,*loc_j)] += local_mat[(i,j)];
+			}
+		}
 	}
 
 	global_identity_matrix
 }
 
+// Returning the local stiffness matrix in global coordinates, based on the connection element type.
+// Bar elements return a 4x4 matrix. Frame elements return a 6x6 matrix.
+// The modulus of elasticity, section parameters and end releases are taken from the connection.
+pub fn element_stiffness_matrix(conn:&Connection, 
+								kp_1:&Keypoint, 
+								kp_2:&Keypoint) -> DMatrix<f64> {
+	if conn.element == "frame" {
+		local_frame_matrix(kp_1, kp_2, conn.e_modulus, conn.area, conn.inertia, &frame_released_dofs(conn))
+	} else {
+		local_bar_matrix(kp_1, kp_2, conn.e_modulus, conn.area)
+	}
+}
+
 
 // Applying the boundary conditions to the global stiffnessmatrix.
 // Returning the modified global stiffness matrix.
@@ -125,7 +122,7 @@ pub fn calculate_resulting_displacement_vector(modified_global_stiffness_matrix:
 			}
 			let reduced_loc_col = global_stiffness_matrix_map[&j];
 			modified_global_stiffness_matrix_reduced[(reduced_loc_row as usize,reduced_loc_col as usize)]
-			= modified_global_stiffness_matrix[(i as usize,j as usize)];
+			= modified_global_stiffness_matrix[(i,j)];
 		}
 		force_vector_reduced[reduced_loc_row as usize] = force_vector[i];
 	}
@@ -142,11 +139,11 @@ pub fn calculate_resulting_displacement_vector(modified_global_stiffness_matrix:
 	let mut loc_reduced:i32 = 0;
 	for i in 0..size {
 		if global_stiffness_matrix_map[&i] == -999 {
-			displacement_vector[i as usize] = 0.0;
+			displacement_vector[i] = 0.0;
 		}
 		else {
 			let u_value:f64 = displacement_vector_reduced[loc_reduced as usize];
-			displacement_vector[i as usize] = u_value;
+			displacement_vector[i] = u_value;
 			loc_reduced += 1;
 		}
 	}
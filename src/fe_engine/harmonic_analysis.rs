@@ -0,0 +1,52 @@
+// This file contains the harmonic (frequency response) analysis.
+// The steady-state response to a harmonic load F*e^(i*omega*t) is found for each frequency
+// by solving the complex system on the reduced dofs:
+// (K - omega^2*M + i*omega*C)*u = F
+
+use nalgebra::{Complex, DMatrix, DVector};
+use crate::fe_engine::reduced_system::{free_dof_locations, reduce_matrix, reduce_vector};
+
+pub struct FrequencyResponse {
+	pub frequency: f64,
+	pub displacement: DVector<Complex<f64>>, // Global complex displacement amplitudes.
+}
+
+pub fn solve_harmonic_analysis(modified_global_stiffness_matrix:&DMatrix<f64>,
+							   global_mass_matrix:&DMatrix<f64>,
+							   global_damping_matrix:&DMatrix<f64>,
+							   dof_filter_vector:&DVector<f64>,
+							   force_vector:&DVector<f64>,
+							   frequencies:&[f64]) -> Vec<FrequencyResponse> {
+
+	// Removing the fixed dofs.
+	let size:usize = dof_filter_vector.nrows();
+	let free_dofs = free_dof_locations(dof_filter_vector);
+	let k:DMatrix<f64> = reduce_matrix(modified_global_stiffness_matrix, &free_dofs);
+	let m:DMatrix<f64> = reduce_matrix(global_mass_matrix, &free_dofs);
+	let c:DMatrix<f64> = reduce_matrix(global_damping_matrix, &free_dofs);
+	let f:DVector<Complex<f64>> = reduce_vector(force_vector, &free_dofs).map(|value| Complex::new(value, 0.0));
+
+	let mut responses:Vec<FrequencyResponse> = Vec::new();
+
+	for frequency in frequencies {
+		let omega:f64 = 2.0 * std::f64::consts::PI * frequency;
+
+		// Dynamic stiffness matrix, K - omega^2*M + i*omega*C.
+		let dynamic_stiffness:DMatrix<Complex<f64>> = DMatrix::from_fn(free_dofs.len(), free_dofs.len(), |i, j| {
+			Complex::new(k[(i, j)] - omega * omega * m[(i, j)], omega * c[(i, j)])
+		});
+
+		let u_reduced:DVector<Complex<f64>> = dynamic_stiffness.lu().solve(&f)
+			.expect("Dynamic stiffness matrix is singular. The frequency coincides with an undamped natural frequency.");
+
+		// Re-inserting the fixed dofs as zeros.
+		let mut u:DVector<Complex<f64>> = DVector::from_element(size, Complex::new(0.0, 0.0));
+		for (i, loc) in free_dofs.iter().enumerate() {
+			u[*loc] = u_reduced[i];
+		}
+
+		responses.push(FrequencyResponse {frequency: *frequency, displacement: u});
+	}
+
+	responses
+}
@@ -0,0 +1,170 @@
+// This file contains the influence line calculation for a unit load moving along a deck path.
+// A downward unit load is solved in each keypoint of the deck path, reusing one factorisation
+// of the reduced stiffness matrix. Load positions between two keypoints are found by linear
+// interpolation of the two keypoint solutions, as the load is shared between the keypoints.
+
+use nalgebra::{DMatrix, DVector};
+use std::collections::HashMap;
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::deck_path::DeckPath;
+use crate::input::influence_line::InfluenceLine;
+use crate::fe_engine::reduced_system::{free_dof_locations, reduce_matrix, reduce_vector, expand_reduced_vector};
+use crate::fe_engine::member_forces::calculate_local_end_forces;
+use crate::fe_engine::utils::global_stiffness_matrix_keypoint_hashmap;
+use crate::material_formulation::local_stiffness_matrix_frame::element_geometry;
+
+// Load position along a deck path, between two keypoints.
+// factor = 0 places the load in kp_1, factor = 1 places the load in kp_2.
+pub struct DeckPosition {
+	pub distance: f64,
+	pub kp_1: String,
+	pub kp_2: String,
+	pub factor: f64,
+}
+
+// Global displacements and reactions (K*u - F) of a load case.
+pub struct UnitLoadSolution {
+	pub displacement: DVector<f64>,
+	pub reaction: DVector<f64>,
+}
+
+// Returning the load positions along a deck path, with a number of steps between each pair of keypoints.
+pub fn create_deck_positions(deck_path:&DeckPath, kp_list:&[Keypoint], steps_per_segment:usize) -> Vec<DeckPosition> {
+	let mut positions:Vec<DeckPosition> = Vec::new();
+	let mut distance:f64 = 0.0;
+
+	for (i, pair) in deck_path.keypoints.windows(2).enumerate() {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == pair[0]).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == pair[1]).unwrap();
+		let (length, _, _) = element_geometry(kp_1, kp_2);
+
+		// The first position of each segment equals the last position of the previous segment.
+		let first_step:usize = if i == 0 { 0 } else { 1 };
+		for step in first_step..=steps_per_segment {
+			let factor:f64 = step as f64 / steps_per_segment as f64;
+			positions.push(DeckPosition {distance: distance + factor * length, kp_1: pair[0].clone(), kp_2: pair[1].clone(), factor});
+		}
+
+		distance += length;
+	}
+
+	positions
+}
+
+// Returning the load position at a distance along a deck path. None if the distance is outside the deck.
+pub fn deck_position_at(deck_path:&DeckPath, kp_list:&[Keypoint], distance:f64) -> Option<DeckPosition> {
+	let mut start:f64 = 0.0;
+
+	for pair in deck_path.keypoints.windows(2) {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == pair[0]).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == pair[1]).unwrap();
+		let (length, _, _) = element_geometry(kp_1, kp_2);
+
+		if distance >= start && distance <= start + length {
+			let factor:f64 = (distance - start) / length;
+			return Some(DeckPosition {distance, kp_1: pair[0].clone(), kp_2: pair[1].clone(), factor});
+		}
+
+		start += length;
+	}
+
+	None
+}
+
+// Returning the total length of a deck path.
+pub fn deck_length(deck_path:&DeckPath, kp_list:&[Keypoint]) -> f64 {
+	deck_path.keypoints.windows(2).map(|pair| {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == pair[0]).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == pair[1]).unwrap();
+		element_geometry(kp_1, kp_2).0
+	}).sum()
+}
+
+// Solving a downward unit load in each of the given keypoints.
+pub fn solve_unit_loads(global_stiffness_matrix:&DMatrix<f64>,
+						modified_global_stiffness_matrix:&DMatrix<f64>,
+						dof_filter_vector:&DVector<f64>,
+						kp_list:&[Keypoint],
+						load_keypoints:&[String],
+						dofs_per_keypoint:usize) -> HashMap<String, UnitLoadSolution> {
+
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+
+	// Factorising the reduced stiffness matrix once.
+	let size:usize = dof_filter_vector.nrows();
+	let free_dofs = free_dof_locations(dof_filter_vector);
+	let lu = reduce_matrix(modified_global_stiffness_matrix, &free_dofs).lu();
+
+	let mut solutions:HashMap<String, UnitLoadSolution> = HashMap::new();
+
+	for kp_name in load_keypoints {
+		if solutions.contains_key(kp_name) {
+			continue;
+		}
+
+		let mut force_vector:DVector<f64> = DVector::zeros(size);
+		force_vector[kp_map[kp_name]+1] = -1.0;
+
+		let displacement_reduced = lu.solve(&reduce_vector(&force_vector, &free_dofs)).unwrap();
+		let displacement = expand_reduced_vector(&displacement_reduced, &free_dofs, size);
+		let mut reaction = global_stiffness_matrix * &displacement - &force_vector;
+
+		// Only dofs with boundary conditions are kept.
+		for i in 0..size {
+			if dof_filter_vector[i] == -1.0 {
+				reaction[i] = 0.0;
+			}
+		}
+
+		solutions.insert(kp_name.clone(), UnitLoadSolution {displacement, reaction});
+	}
+
+	solutions
+}
+
+// Returning the solution of a load at a deck position, scaled by the load size.
+pub fn interpolate_unit_load_solution(position:&DeckPosition,
+									  solutions:&HashMap<String, UnitLoadSolution>,
+									  load:f64) -> UnitLoadSolution {
+	let solution_1 = &solutions[&position.kp_1];
+	let solution_2 = &solutions[&position.kp_2];
+	let factor_1:f64 = load * (1.0 - position.factor);
+	let factor_2:f64 = load * position.factor;
+
+	UnitLoadSolution {
+		displacement: &solution_1.displacement * factor_1 + &solution_2.displacement * factor_2,
+		reaction: &solution_1.reaction * factor_1 + &solution_2.reaction * factor_2,
+	}
+}
+
+// Returning the value of the influence line quantity for a solution.
+pub fn evaluate_quantity(influence_line:&InfluenceLine,
+						 solution:&UnitLoadSolution,
+						 kp_list:&[Keypoint],
+						 conn_list:&[Connection],
+						 kp_map:&HashMap<String, usize>,
+						 dofs_per_keypoint:usize) -> f64 {
+
+	// Location of the keypoint dof of reactions and displacements. Rotations only exist in models with frame elements.
+	let location = || {
+		let offset:usize = match influence_line.direction.as_str() {
+			"x" => 0,
+			"y" => 1,
+			"r" if dofs_per_keypoint == 3 => 2,
+			_ => panic!("Unknown influence line direction for {}", influence_line.name),
+		};
+		kp_map[&influence_line.target] + offset
+	};
+
+	match influence_line.quantity.as_str() {
+		"reaction" => solution.reaction[location()],
+		"displacement" => solution.displacement[location()],
+		"bar_force" => {
+			let conn = conn_list.iter().find(|conn| conn.name == influence_line.target).unwrap();
+			let f = calculate_local_end_forces(conn, kp_list, kp_map, &solution.displacement, dofs_per_keypoint, &[]);
+			(f[3] - f[0]) / 2.0
+		},
+		_ => panic!("Unknown influence line quantity for {}", influence_line.name),
+	}
+}
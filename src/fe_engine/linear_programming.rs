@@ -0,0 +1,134 @@
+// This file contains a dense two-phase simplex solver for linear programs on standard form:
+// minimize c^T * x, subject to A * x = b and x >= 0.
+// Phase 1 finds a feasible basis by minimizing the sum of artificial variables.
+// Phase 2 minimizes the objective from that basis. Bland's rule is used to avoid cycling.
+
+use nalgebra::{DMatrix, DVector};
+
+const TOLERANCE: f64 = 1e-9;
+
+// Pivoting the tableau on a row and column, and updating the basis.
+fn pivot(tableau:&mut DMatrix<f64>, basis:&mut [usize], row:usize, column:usize) {
+	let pivot_value:f64 = tableau[(row, column)];
+	let columns:usize = tableau.ncols();
+
+	for j in 0..columns {
+		tableau[(row, j)] /= pivot_value;
+	}
+
+	for i in 0..tableau.nrows() {
+		if i != row {
+			let factor:f64 = tableau[(i, column)];
+			if factor != 0.0 {
+				for j in 0..columns {
+					tableau[(i, j)] -= factor * tableau[(row, j)];
+				}
+			}
+		}
+	}
+
+	basis[row] = column;
+}
+
+// Running simplex iterations on the tableau. The last row is the reduced cost row,
+// and the last column is the right hand side. Only the allowed columns may enter the basis.
+// Returning false if the problem is unbounded.
+fn run_simplex(tableau:&mut DMatrix<f64>, basis:&mut [usize], allowed_columns:usize) -> bool {
+	let m:usize = tableau.nrows() - 1;
+	let rhs:usize = tableau.ncols() - 1;
+
+	loop {
+		// Bland's rule. Entering column is the first column with a negative reduced cost.
+		let Some(column) = (0..allowed_columns).find(|&j| tableau[(m, j)] < -TOLERANCE) else {
+			return true;
+		};
+
+		// Ratio test. Ties are broken by the smallest basis index.
+		let mut leaving:Option<usize> = None;
+		for i in 0..m {
+			if tableau[(i, column)] > TOLERANCE {
+				let ratio:f64 = tableau[(i, rhs)] / tableau[(i, column)];
+				leaving = match leaving {
+					Some(l) => {
+						let ratio_l:f64 = tableau[(l, rhs)] / tableau[(l, column)];
+						if ratio < ratio_l - TOLERANCE || (ratio < ratio_l + TOLERANCE && basis[i] < basis[l]) { Some(i) } else { Some(l) }
+					},
+					None => Some(i),
+				};
+			}
+		}
+
+		let Some(row) = leaving else {
+			return false;
+		};
+
+		pivot(tableau, basis, row, column);
+	}
+}
+
+// Returning the optimal x, or None if the problem is infeasible or unbounded.
+pub fn solve_linear_program(c:&DVector<f64>, a:&DMatrix<f64>, b:&DVector<f64>) -> Option<DVector<f64>> {
+	let m:usize = a.nrows();
+	let n:usize = a.ncols();
+
+	// Tableau columns: x (n), artificial variables (m), right hand side.
+	let mut tableau:DMatrix<f64> = DMatrix::zeros(m + 1, n + m + 1);
+	for i in 0..m {
+		// Right hand sides must be non-negative.
+		let sign:f64 = if b[i] < 0.0 { -1.0 } else { 1.0 };
+		for j in 0..n {
+			tableau[(i, j)] = sign * a[(i, j)];
+		}
+		tableau[(i, n + i)] = 1.0;
+		tableau[(i, n + m)] = sign * b[i];
+	}
+	let mut basis:Vec<usize> = (n..n + m).collect();
+
+	// Phase 1. Reduced costs of minimizing the sum of artificial variables.
+	for j in 0..n {
+		tableau[(m, j)] = -(0..m).map(|i| tableau[(i, j)]).sum::<f64>();
+	}
+	tableau[(m, n + m)] = -(0..m).map(|i| tableau[(i, n + m)]).sum::<f64>();
+
+	run_simplex(&mut tableau, &mut basis, n + m);
+
+	if -tableau[(m, n + m)] > TOLERANCE * (1.0 + b.amax()) {
+		return None;
+	}
+
+	// Driving artificial variables at zero out of the basis. Rows without other pivots are redundant.
+	for i in 0..m {
+		if basis[i] < n {
+			continue;
+		}
+		if let Some(column) = (0..n).find(|&j| tableau[(i, j)].abs() > TOLERANCE) {
+			pivot(&mut tableau, &mut basis, i, column);
+		}
+	}
+
+	// Phase 2. Reduced costs of the original objective, c_j - c_B^T * column_j.
+	for j in 0..n + m + 1 {
+		tableau[(m, j)] = if j < n { c[j] } else { 0.0 };
+	}
+	for i in 0..m {
+		if basis[i] < n {
+			let cost:f64 = c[basis[i]];
+			for j in 0..n + m + 1 {
+				tableau[(m, j)] -= cost * tableau[(i, j)];
+			}
+		}
+	}
+
+	if !run_simplex(&mut tableau, &mut basis, n) {
+		return None;
+	}
+
+	let mut x:DVector<f64> = DVector::zeros(n);
+	for i in 0..m {
+		if basis[i] < n {
+			x[basis[i]] = tableau[(i, n + m)];
+		}
+	}
+
+	Some(x)
+}
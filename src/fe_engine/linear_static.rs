@@ -0,0 +1,50 @@
+// This file contains the linear static solution of a load case.
+// Used where the model is solved repeatedly, e.g. by the member sizing.
+
+use nalgebra::DVector;
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::input::pointload::Pointload;
+use crate::input::distributed_load::DistributedLoad;
+use crate::input::material::Material;
+use crate::input::load_case::LoadCase;
+use crate::fe_engine::global_stiffness_matrix::{create_global_stiffness_matrix, apply_boundary_conditions,
+	calculate_resulting_displacement_vector, calculate_resulting_force_vector};
+use crate::fe_engine::dof_filter_vector::create_dof_filter_vector;
+use crate::fe_engine::force_vector::create_force_vector;
+use crate::fe_engine::self_weight::create_self_weight_vector;
+use crate::fe_engine::distributed_load_vector::create_distributed_load_vector;
+use crate::fe_engine::utils::dofs_per_keypoint;
+
+// The 2D model with the load case of the linear static analysis. Analyses that solve variants of the model
+// copy the struct with their own keypoints or connections, e.g. Model {conn_list: &variant_conn_list, ..*model}.
+#[derive(Clone, Copy)]
+pub struct Model<'a> {
+	pub kp_list: &'a [Keypoint],
+	pub conn_list: &'a [Connection],
+	pub bc_list: &'a [BoundaryCondition],
+	pub pl_list: &'a [Pointload],
+	pub dl_list: &'a [DistributedLoad],
+	pub mat_list: &'a [Material],
+	pub load_case: &'a LoadCase,
+}
+
+// Returning the resulting displacement and force vectors.
+pub fn solve_linear_static(model:&Model) -> (DVector<f64>, DVector<f64>) {
+
+	let dofs:usize = dofs_per_keypoint(model.conn_list);
+
+	let global_stiffness_matrix = create_global_stiffness_matrix(model.kp_list, model.conn_list);
+	let dof_filter_vector = create_dof_filter_vector(model.kp_list, model.conn_list, model.bc_list);
+
+	let force_vector = create_force_vector(model.kp_list, model.pl_list, dofs)
+		+ create_self_weight_vector(model.kp_list, model.conn_list, model.mat_list, model.load_case, dofs)
+		+ create_distributed_load_vector(model.kp_list, model.conn_list, model.dl_list, dofs);
+
+	let modified_global_stiffness_matrix = apply_boundary_conditions(&global_stiffness_matrix, &dof_filter_vector);
+	let displacement_vector = calculate_resulting_displacement_vector(&modified_global_stiffness_matrix, &force_vector, &dof_filter_vector);
+	let resulting_force_vector = calculate_resulting_force_vector(&global_stiffness_matrix, &displacement_vector);
+
+	(displacement_vector, resulting_force_vector)
+}
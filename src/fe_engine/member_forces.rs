@@ -0,0 +1,102 @@
+// This file recovers the member end forces of each connection from the global displacement vector.
+// The fixed-end forces from distributed loads are added back,
+// so that the member forces are correct within the span.
+
+use nalgebra::{DMatrix, DVector};
+use std::collections::HashMap;
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::{Connection, DiagramPoint};
+use crate::input::distributed_load::DistributedLoad;
+use crate::material_formulation::local_stiffness_matrix_frame::{element_geometry, frame_transformation_matrix, frame_matrix_local_axes};
+use crate::fe_engine::distributed_load_vector::{equivalent_nodal_load_local, local_distributed_load_components};
+use crate::fe_engine::utils::frame_released_dofs;
+use crate::material_formulation::static_condensation::condense_released_dofs;
+
+// Returning the end forces acting on the connection in local coordinates.
+// Vector layout: (axial 1, transverse 1, moment 1, axial 2, transverse 2, moment 2).
+pub fn calculate_local_end_forces(conn:&Connection,
+								  kp_list:&[Keypoint],
+								  kp_map:&HashMap<String, usize>,
+								  displacement_vector:&DVector<f64>,
+								  dofs_per_keypoint:usize,
+								  dl_list:&[DistributedLoad]) -> DVector<f64> {
+
+	let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+	let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+	let (length, _, _) = element_geometry(kp_1, kp_2);
+
+	// Collecting the element displacements in global coordinates.
+	// Rotations are zero for bar elements, and for models without rotational dofs.
+	let loc_1 = kp_map[&conn.kp_1];
+	let loc_2 = kp_map[&conn.kp_2];
+	let mut u_element:DVector<f64> = DVector::zeros(6);
+	u_element[0] = displacement_vector[loc_1];
+	u_element[1] = displacement_vector[loc_1+1];
+	u_element[3] = displacement_vector[loc_2];
+	u_element[4] = displacement_vector[loc_2+1];
+	if conn.element == "frame" && dofs_per_keypoint == 3 {
+		u_element[2] = displacement_vector[loc_1+2];
+		u_element[5] = displacement_vector[loc_2+2];
+	}
+
+	// Bar elements have axial stiffness only, which equals a frame element without bending stiffness.
+	// Released frame ends are condensed out, giving zero end moments at the hinges.
+	let element_inertia:f64 = if conn.element == "frame" { conn.inertia } else { 0.0 };
+	let k_local:DMatrix<f64> = condense_released_dofs(&frame_matrix_local_axes(length, conn.e_modulus, conn.area, element_inertia),
+													  &frame_released_dofs(conn));
+	let t:DMatrix<f64> = frame_transformation_matrix(kp_1, kp_2);
+
+	// f = k * u - f_eq. Subtracting the equivalent nodal loads adds back the fixed-end forces.
+	k_local * t * u_element - equivalent_nodal_load_local(conn, kp_1, kp_2, dl_list)
+}
+
+// Sampling the normal force, shear force and bending moment along a connection.
+// Starting from the member forces at kp_1, and integrating the distributed loads along the span.
+// N(x) = N_1 - int(p), V(x) = V_1 + int(q), M(x) = M_1 + V_1*x + int(q*(x-s)).
+// The samples are evenly spaced and include both ends, so at least 2 samples are needed.
+pub fn sample_member_diagram(conn:&Connection,
+							 kp_list:&[Keypoint],
+							 dl_list:&[DistributedLoad],
+							 number_of_samples:usize) -> Vec<DiagramPoint> {
+
+	if number_of_samples < 2 {
+		panic!("A member diagram needs at least 2 samples, got {}", number_of_samples);
+	}
+
+	let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+	let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+	let (length, _, _) = element_geometry(kp_1, kp_2);
+
+	// Summing the linearly varying local loads acting on the connection.
+	let (mut p_1, mut p_2, mut q_1, mut q_2):(f64, f64, f64, f64) = (0.0, 0.0, 0.0, 0.0);
+	for dl in dl_list.iter().filter(|dl| dl.connection == conn.name) {
+		let (dl_p_1, dl_p_2, dl_q_1, dl_q_2) = local_distributed_load_components(dl, kp_1, kp_2);
+		p_1 += dl_p_1;
+		p_2 += dl_p_2;
+		q_1 += dl_q_1;
+		q_2 += dl_q_2;
+	}
+
+	let mut diagram:Vec<DiagramPoint> = Vec::new();
+
+	for i in 0..number_of_samples {
+		let x:f64 = length * i as f64 / (number_of_samples - 1) as f64;
+
+		let n:f64 = conn.n_1 - (p_1 * x + (p_2 - p_1) * x * x / (2.0 * length));
+		let v:f64 = conn.v_1 + (q_1 * x + (q_2 - q_1) * x * x / (2.0 * length));
+		let m:f64 = conn.m_1 + conn.v_1 * x + (q_1 * x * x / 2.0 + (q_2 - q_1) * x * x * x / (6.0 * length));
+
+		diagram.push(DiagramPoint {position: x, n, v, m});
+	}
+
+	diagram
+}
+
+// Returning the largest tension and compression force along a connection, from the end forces and the sampled
+// member diagram. Distributed loads along the connection can give the largest normal force within the span.
+// Tension is positive, and both returned forces are >= 0.
+pub fn normal_force_extremes(conn:&Connection) -> (f64, f64) {
+	[conn.n_1, conn.n_2].into_iter()
+		.chain(conn.diagram.iter().map(|point| point.n))
+		.fold((0.0, 0.0), |(tension, compression):(f64, f64), n| (tension.max(n), compression.max(-n)))
+}
@@ -0,0 +1,88 @@
+// This file contains the modal analysis.
+// The generalized eigenproblem K*phi = omega^2*M*phi is solved on the reduced dofs.
+//
+// The stiffness matrix is positive definite after applying boundary conditions, while the
+// mass matrix may be singular (massless rotational dofs). The problem is therefore transformed
+// with the Cholesky factor of the stiffness matrix, K = L*L^T, into a standard symmetric problem:
+// (L^-1 * M * L^-T) * y = mu * y, where mu = 1/omega^2 and phi = L^-T * y.
+
+use nalgebra::{DMatrix, DVector, SymmetricEigen};
+use crate::fe_engine::reduced_system::{free_dof_locations, reduce_matrix, expand_reduced_vector};
+
+pub struct Mode {
+	pub number: usize,
+	pub angular_frequency: f64,
+	pub frequency: f64,
+	pub period: f64,
+	pub shape: DVector<f64>, // Global mode shape, mass-normalized.
+}
+
+// Solving K*phi = (1/mu)*B*phi for a positive definite K and a symmetric B.
+// Returning the positive eigenvalues mu in descending order, and the matching eigenvectors as columns.
+// The largest mu corresponds to the lowest eigenvalue of the original problem.
+pub fn solve_stiffness_eigenproblem(stiffness_matrix_reduced:&DMatrix<f64>,
+									b_matrix_reduced:&DMatrix<f64>) -> (Vec<f64>, Vec<DVector<f64>>) {
+
+	let cholesky = stiffness_matrix_reduced.clone().cholesky()
+		.expect("Stiffness matrix is not positive definite. Check the boundary conditions.");
+	let l:DMatrix<f64> = cholesky.l();
+
+	// A = L^-1 * B * L^-T. B is symmetric, so L^-1 * (L^-1 * B)^T = L^-1 * B * L^-T.
+	let l_inv_b:DMatrix<f64> = l.solve_lower_triangular(b_matrix_reduced).unwrap();
+	let a:DMatrix<f64> = l.solve_lower_triangular(&l_inv_b.transpose()).unwrap();
+	let a_symmetric:DMatrix<f64> = (&a + a.transpose()) * 0.5;
+
+	let eigen = SymmetricEigen::new(a_symmetric);
+
+	// Sorting eigenvalues in descending order. Skipping zero and negative eigenvalues.
+	let tolerance:f64 = 1e-12 * eigen.eigenvalues.amax().max(f64::MIN_POSITIVE);
+	let mut order:Vec<usize> = (0..eigen.eigenvalues.len())
+		.filter(|&i| eigen.eigenvalues[i] > tolerance)
+		.collect();
+	order.sort_by(|&i, &j| eigen.eigenvalues[j].total_cmp(&eigen.eigenvalues[i]));
+
+	let mut mu_list:Vec<f64> = Vec::new();
+	let mut phi_list:Vec<DVector<f64>> = Vec::new();
+
+	for i in order {
+		let y:DVector<f64> = eigen.eigenvectors.column(i).into_owned();
+		let phi:DVector<f64> = l.transpose().solve_upper_triangular(&y).unwrap();
+		mu_list.push(eigen.eigenvalues[i]);
+		phi_list.push(phi);
+	}
+
+	(mu_list, phi_list)
+}
+
+pub fn solve_modal_analysis(modified_global_stiffness_matrix:&DMatrix<f64>,
+							global_mass_matrix:&DMatrix<f64>,
+							dof_filter_vector:&DVector<f64>,
+							number_of_modes:usize) -> Vec<Mode> {
+
+	// Removing the fixed dofs from the stiffness and mass matrix.
+	let free_dofs = free_dof_locations(dof_filter_vector);
+	let stiffness_matrix_reduced = reduce_matrix(modified_global_stiffness_matrix, &free_dofs);
+	let mass_matrix_reduced = reduce_matrix(global_mass_matrix, &free_dofs);
+
+	let (mu_list, phi_list) = solve_stiffness_eigenproblem(&stiffness_matrix_reduced, &mass_matrix_reduced);
+
+	let mut modes:Vec<Mode> = Vec::new();
+
+	for (i, (mu, phi)) in mu_list.iter().zip(phi_list.iter()).take(number_of_modes).enumerate() {
+		let angular_frequency:f64 = 1.0 / mu.sqrt();
+
+		// Mass-normalizing the mode shape, phi^T * M * phi = 1.
+		let modal_mass:f64 = (phi.transpose() * &mass_matrix_reduced * phi)[(0, 0)];
+		let phi_normalized:DVector<f64> = phi / modal_mass.sqrt();
+
+		modes.push(Mode {
+			number: i + 1,
+			angular_frequency,
+			frequency: angular_frequency / (2.0 * std::f64::consts::PI),
+			period: 2.0 * std::f64::consts::PI / angular_frequency,
+			shape: expand_reduced_vector(&phi_normalized, &free_dofs, dof_filter_vector.nrows()),
+		});
+	}
+
+	modes
+}
@@ -0,0 +1,181 @@
+// This file contains the random sampling for the Monte Carlo reliability analysis.
+// Random numbers come from a seeded SplitMix64 generator, so the same seed always gives the same samples.
+// Standard normal numbers are generated with the Box-Muller transform.
+//
+// Normal: x = mean + sd * z, where sd = cov * |mean|.
+// Lognormal: |x| = exp(mu_ln + sigma_ln * z), where sigma_ln^2 = ln(1 + cov^2) and mu_ln = ln(|mean|) - sigma_ln^2 / 2.
+// The lognormal value keeps the sign of the mean, so downward loads can be given with a negative mean.
+// Areas and E-moduli must be positive. Their normal samples <= 0 are redrawn, truncating the distribution at zero.
+
+use crate::input::random_variable::RandomVariable;
+
+pub struct RandomNumberGenerator {
+	state: u64,
+}
+
+impl RandomNumberGenerator {
+	pub fn new(seed:u64) -> RandomNumberGenerator {
+		RandomNumberGenerator {state: seed}
+	}
+
+	pub fn next_u64(&mut self) -> u64 {
+		self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+		let mut z:u64 = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+		z ^ (z >> 31)
+	}
+
+	// Uniform number in the open interval (0, 1).
+	pub fn next_uniform(&mut self) -> f64 {
+		((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+	}
+
+	pub fn next_standard_normal(&mut self) -> f64 {
+		let u_1:f64 = self.next_uniform();
+		let u_2:f64 = self.next_uniform();
+		(-2.0 * u_1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u_2).cos()
+	}
+}
+
+// Returning true for the model parameters that must be positive.
+pub fn is_positive_parameter(parameter:&str) -> bool {
+	matches!(parameter, "area" | "e_module")
+}
+
+pub fn sample_random_variable(random_variable:&RandomVariable, rng:&mut RandomNumberGenerator) -> f64 {
+	let positive:bool = is_positive_parameter(&random_variable.parameter);
+	if positive && random_variable.mean <= 0.0 {
+		panic!("Random variable {} must have a positive mean", random_variable.name);
+	}
+
+	match random_variable.distribution.as_str() {
+		"normal" => loop {
+			let value:f64 = random_variable.mean + random_variable.cov * random_variable.mean.abs() * rng.next_standard_normal();
+			if value > 0.0 || !positive {
+				return value;
+			}
+		},
+		"lognormal" => {
+			let z:f64 = rng.next_standard_normal();
+			let sigma_ln:f64 = (1.0 + random_variable.cov * random_variable.cov).ln().sqrt();
+			let mu_ln:f64 = random_variable.mean.abs().ln() - sigma_ln * sigma_ln / 2.0;
+			random_variable.mean.signum() * (mu_ln + sigma_ln * z).exp()
+		},
+		_ => panic!("Unknown distribution for random variable {}", random_variable.name),
+	}
+}
+
+// Returning the mean and the sample standard deviation.
+pub fn mean_and_standard_deviation(values:&[f64]) -> (f64, f64) {
+	let n:f64 = values.len() as f64;
+	let mean:f64 = values.iter().sum::<f64>() / n;
+	let variance:f64 = if values.len() > 1 {
+		values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / (n - 1.0)
+	} else {
+		0.0
+	};
+
+	(mean, variance.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::input::material::Material;
+	use crate::input::load_case::LoadCase;
+	use crate::input::random_variable::ReliabilityOutput;
+	use crate::fe_engine::linear_static::Model;
+	use crate::generators::utils::{create_keypoint, create_connection, create_boundary_condition, create_pointload};
+	use crate::data_formatting::generate_result_structs::{ReliabilityResult, generate_result_reliability};
+
+	fn random_variable(parameter:&str, target:&str, distribution:&str, mean:f64, cov:f64) -> RandomVariable {
+		RandomVariable {name: "rv".to_string(), parameter: parameter.to_string(), target: target.to_string(),
+						distribution: distribution.to_string(), mean, cov}
+	}
+
+	fn samples(random_variable:&RandomVariable, seed:u64, number_of_samples:usize) -> Vec<f64> {
+		let mut rng = RandomNumberGenerator::new(seed);
+		(0..number_of_samples).map(|_| sample_random_variable(random_variable, &mut rng)).collect()
+	}
+
+	// Horizontal bar between a pinned support and a roller, loaded axially at the roller.
+	// The bar force equals the pointload, so its exceedance probability follows the load distribution.
+	fn bar_reliability(random_variables:&[RandomVariable], threshold:f64, number_of_samples:usize, seed:u64) -> ReliabilityResult {
+		let kp_list = vec![create_keypoint("kp1", 0.0, 0.0), create_keypoint("kp2", 2.0, 0.0)];
+		let mut conn = create_connection("c1", "kp1", "kp2", "mat1", "bar");
+		conn.area = 0.01;
+		conn.e_modulus = 210000000.0;
+		let conn_list = vec![conn];
+		let bc_list = vec![create_boundary_condition("bc1", "kp1", "2"), create_boundary_condition("bc2", "kp2", "1")];
+		let pl_list = vec![create_pointload("p1", "kp2", 100.0, 0.0)];
+		let mat_list = vec![Material {name: "mat1".to_string(), e_modulus: 210000000.0, density: 0.0}];
+		let load_case = LoadCase {name: "lc1".to_string(), gravity_x: 0.0, gravity_y: 0.0};
+		let model = Model {kp_list: &kp_list, conn_list: &conn_list, bc_list: &bc_list, pl_list: &pl_list,
+						   dl_list: &[], mat_list: &mat_list, load_case: &load_case};
+		let outputs = vec![ReliabilityOutput {name: "n".to_string(), quantity: "bar_force".to_string(),
+											  target: "c1".to_string(), threshold}];
+
+		generate_result_reliability(&model, random_variables, &outputs, number_of_samples, seed, 10)
+	}
+
+	#[test]
+	fn same_seed_gives_identical_samples_and_results() {
+		let load = random_variable("pointload_x", "p1", "normal", 100.0, 0.1);
+		assert_eq!(samples(&load, 7, 100), samples(&load, 7, 100));
+
+		let variables = [load, random_variable("area", "c1", "lognormal", 0.01, 0.1)];
+		let result_1 = bar_reliability(&variables, 110.0, 100, 7);
+		let result_2 = bar_reliability(&variables, 110.0, 100, 7);
+		assert_eq!(result_1.outputs[0].samples, result_2.outputs[0].samples);
+		assert_eq!(result_1.outputs[0].exceedance_probability, result_2.outputs[0].exceedance_probability);
+	}
+
+	#[test]
+	fn different_seed_gives_different_samples() {
+		let load = random_variable("pointload_x", "p1", "normal", 100.0, 0.1);
+		assert_ne!(samples(&load, 7, 100), samples(&load, 8, 100));
+	}
+
+	#[test]
+	fn normal_samples_match_mean_and_cov() {
+		let values = samples(&random_variable("pointload_x", "p1", "normal", -200.0, 0.2), 1, 20000);
+		let (mean, standard_deviation) = mean_and_standard_deviation(&values);
+
+		// The standard errors of the mean and the standard deviation are about 0.3 and 0.2.
+		assert!((mean + 200.0).abs() < 2.0, "mean {}", mean);
+		assert!((standard_deviation - 40.0).abs() < 1.5, "standard deviation {}", standard_deviation);
+	}
+
+	#[test]
+	fn lognormal_samples_match_mean_and_cov() {
+		let values = samples(&random_variable("e_module", "mat1", "lognormal", 210000000.0, 0.05), 2, 20000);
+		let (mean, standard_deviation) = mean_and_standard_deviation(&values);
+
+		assert!(values.iter().all(|value| *value > 0.0));
+		assert!((mean / 210000000.0 - 1.0).abs() < 0.002, "mean {}", mean);
+		assert!((standard_deviation / (0.05 * 210000000.0) - 1.0).abs() < 0.03, "standard deviation {}", standard_deviation);
+	}
+
+	#[test]
+	fn normal_samples_of_positive_parameters_are_positive() {
+		let values = samples(&random_variable("area", "c1", "normal", 0.01, 1.0), 3, 10000);
+		assert!(values.iter().all(|value| *value > 0.0));
+	}
+
+	#[test]
+	#[should_panic(expected = "must have a positive mean")]
+	fn positive_parameters_reject_non_positive_mean() {
+		samples(&random_variable("e_module", "mat1", "lognormal", -210000000.0, 0.05), 4, 1);
+	}
+
+	#[test]
+	fn exceedance_probability_matches_load_distribution() {
+		// P(N > mean + sd) = 1 - Phi(1) = 0.1587 for a normal load. The standard error with 20000 samples is 0.0026.
+		let result = bar_reliability(&[random_variable("pointload_x", "p1", "normal", 100.0, 0.1)], 110.0, 20000, 5);
+		let output = &result.outputs[0];
+
+		assert!((output.mean - 100.0).abs() < 0.3, "mean {}", output.mean);
+		assert!((output.exceedance_probability - 0.1587).abs() < 0.01, "exceedance probability {}", output.exceedance_probability);
+	}
+}
@@ -0,0 +1,55 @@
+// This file contains the moving load calculation for vehicles travelling along a deck path.
+// The vehicle is moved in steps from the leading axle entering the deck, until the last axle
+// has left the deck. The response in each step is a superposition of the unit load solutions
+// of the axles on the deck, scaled by the axle loads.
+
+use nalgebra::DVector;
+use std::collections::HashMap;
+use crate::input::keypoint::Keypoint;
+use crate::input::deck_path::DeckPath;
+use crate::input::vehicle::VehicleAxle;
+use crate::fe_engine::influence_lines::{UnitLoadSolution, deck_position_at, deck_length, interpolate_unit_load_solution};
+
+// Returning the names of the vehicles, in order of appearance.
+pub fn vehicle_names(vehicle_axles:&[VehicleAxle]) -> Vec<String> {
+	let mut names:Vec<String> = Vec::new();
+	for axle in vehicle_axles {
+		if !names.contains(&axle.name) {
+			names.push(axle.name.clone());
+		}
+	}
+
+	names
+}
+
+// Returning the positions of the leading axle, from entering the deck until the last axle has left it.
+pub fn create_vehicle_positions(axles:&[&VehicleAxle], deck_path:&DeckPath, kp_list:&[Keypoint], step:f64) -> Vec<f64> {
+	let max_offset:f64 = axles.iter().map(|axle| axle.offset).fold(0.0, f64::max);
+	let end:f64 = deck_length(deck_path, kp_list) + max_offset;
+	let number_of_steps:usize = (end / step).ceil() as usize;
+
+	(0..=number_of_steps).map(|i| (i as f64 * step).min(end)).collect()
+}
+
+// Superposing the unit load solutions of all axles on the deck, for a position of the leading axle.
+pub fn solve_vehicle_position(axles:&[&VehicleAxle],
+							  deck_path:&DeckPath,
+							  kp_list:&[Keypoint],
+							  solutions:&HashMap<String, UnitLoadSolution>,
+							  lead_position:f64) -> UnitLoadSolution {
+
+	let size:usize = solutions.values().next().unwrap().displacement.nrows();
+	let mut displacement:DVector<f64> = DVector::zeros(size);
+	let mut reaction:DVector<f64> = DVector::zeros(size);
+
+	for axle in axles {
+		// Axles outside the deck do not load the structure.
+		if let Some(position) = deck_position_at(deck_path, kp_list, lead_position - axle.offset) {
+			let solution = interpolate_unit_load_solution(&position, solutions, axle.load);
+			displacement += solution.displacement;
+			reaction += solution.reaction;
+		}
+	}
+
+	UnitLoadSolution {displacement, reaction}
+}
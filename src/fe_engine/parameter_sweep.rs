@@ -0,0 +1,111 @@
+// This file contains the parameter sweep of a base model.
+// Each sweep parameter gets a list of evenly spaced values, and the variants are all
+// combinations of the parameter values (full factorial). The first parameter varies slowest.
+
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::pointload::Pointload;
+use crate::input::sweep::SweepParameter;
+
+pub fn sweep_values(sweep_parameter:&SweepParameter) -> Vec<f64> {
+	if sweep_parameter.steps <= 1 {
+		return vec![sweep_parameter.start];
+	}
+
+	(0..sweep_parameter.steps)
+		.map(|i| sweep_parameter.start + (sweep_parameter.end - sweep_parameter.start) * i as f64 / (sweep_parameter.steps - 1) as f64)
+		.collect()
+}
+
+// Returning the parameter values of each variant.
+pub fn create_sweep_variants(sweep_parameters:&[SweepParameter]) -> Vec<Vec<f64>> {
+	let mut variants:Vec<Vec<f64>> = vec![Vec::new()];
+
+	for sweep_parameter in sweep_parameters {
+		let values:Vec<f64> = sweep_values(sweep_parameter);
+		variants = variants.iter()
+			.flat_map(|variant| values.iter().map(move |&value| {
+				let mut new_variant:Vec<f64> = variant.clone();
+				new_variant.push(value);
+				new_variant
+			}))
+			.collect();
+	}
+
+	variants
+}
+
+// Setting a parameter of the model copy to a new value. Also used for the random variables of the reliability analysis.
+// Changing the modulus of elasticity of a material changes it in all connections of that material.
+// Changing the area of a connection keeps its second moments of area, and updates the radius of gyration to sqrt(I_minor / A).
+pub fn apply_model_parameter(parameter:&str,
+							 target:&str,
+							 value:f64,
+							 kp_list:&mut [Keypoint],
+							 conn_list:&mut [Connection],
+							 pl_list:&mut [Pointload]) {
+
+	let find_keypoint = |kp_list:&[Keypoint]| -> usize {
+		kp_list.iter().position(|kp| kp.name == target)
+			.unwrap_or_else(|| panic!("Unknown keypoint {} for parameter {}", target, parameter))
+	};
+	let find_pointload = |pl_list:&[Pointload]| -> usize {
+		pl_list.iter().position(|pl| pl.name == target)
+			.unwrap_or_else(|| panic!("Unknown pointload {} for parameter {}", target, parameter))
+	};
+
+	match parameter {
+		"keypoint_x" => kp_list[find_keypoint(kp_list)].x = value,
+		"keypoint_y" => kp_list[find_keypoint(kp_list)].y = value,
+		"e_module" => {
+			let mut found:bool = false;
+			for conn in conn_list.iter_mut().filter(|conn| conn.material == target) {
+				conn.e_modulus = value;
+				found = true;
+			}
+			if !found {
+				panic!("Unknown material {} for parameter {}", target, parameter);
+			}
+		},
+		"pointload_x" => pl_list[find_pointload(pl_list)].load_x = value,
+		"pointload_y" => pl_list[find_pointload(pl_list)].load_y = value,
+		"area" => {
+			let mut found:bool = false;
+			for conn in conn_list.iter_mut().filter(|conn| conn.name == target) {
+				conn.area = value;
+				conn.radius_of_gyration = (conn.inertia_minor / value).sqrt();
+				found = true;
+			}
+			if !found {
+				panic!("Unknown connection {} for parameter {}", target, parameter);
+			}
+		},
+		_ => panic!("Unknown model parameter {}", parameter),
+	}
+}
+
+// Evaluating a result quantity from the keypoint and connection results of a solved model copy.
+// Bar forces are the mean of the normal forces at the two ends. Tension is positive.
+pub fn evaluate_model_output(quantity:&str,
+							 target:&str,
+							 kp_list:&[Keypoint],
+							 conn_list:&[Connection]) -> f64 {
+
+	let keypoint = || kp_list.iter().find(|kp| kp.name == target)
+		.unwrap_or_else(|| panic!("Unknown keypoint {} for output {}", target, quantity));
+
+	match quantity {
+		"max_displacement" => kp_list.iter().map(|kp| (kp.ux * kp.ux + kp.uy * kp.uy).sqrt()).fold(0.0, f64::max),
+		"max_bar_force" => conn_list.iter().map(|conn| conn.n_1.abs().max(conn.n_2.abs())).fold(0.0, f64::max),
+		"displacement_x" => keypoint().ux,
+		"displacement_y" => keypoint().uy,
+		"reaction_x" => keypoint().fx,
+		"reaction_y" => keypoint().fy,
+		"bar_force" => {
+			let conn = conn_list.iter().find(|conn| conn.name == target)
+				.unwrap_or_else(|| panic!("Unknown connection {} for output {}", target, quantity));
+			(conn.n_1 + conn.n_2) / 2.0
+		},
+		_ => panic!("Unknown output quantity {}", quantity),
+	}
+}
@@ -0,0 +1,144 @@
+// This file contains the linear static analysis of plane stress/strain models.
+// Surface elements have 2 translational dofs (x, y) per keypoint. They are assembled into the same
+// global stiffness matrix as the connections, so plates can be combined with bar and frame elements.
+// In models with frame elements, keypoints only connected to surface elements get a fixed rotational dof.
+//
+// Constant strain triangles (CST) have 3 keypoints and constant stresses.
+// Bilinear quadrilaterals (Q4) have 4 keypoints, and the stresses vary over the element.
+// Stresses are recovered at the element centroid, and at the keypoints for the contour plots.
+
+use nalgebra::{DMatrix, DVector, Vector3};
+use std::collections::HashMap;
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::surface_element::SurfaceElement;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::input::pointload::Pointload;
+use crate::material_formulation::local_stiffness_matrix_cst::{local_cst_matrix, cst_stress};
+use crate::material_formulation::local_stiffness_matrix_q4::{local_q4_matrix, q4_stress, q4_shape_functions, Q4_NATURAL_COORDINATES};
+use crate::fe_engine::global_stiffness_matrix::{create_global_stiffness_matrix, apply_boundary_conditions,
+	calculate_resulting_displacement_vector, calculate_resulting_force_vector};
+use crate::fe_engine::dof_filter_vector::create_dof_filter_vector;
+use crate::fe_engine::force_vector::create_force_vector;
+use crate::fe_engine::utils::{global_stiffness_matrix_keypoint_hashmap, dofs_per_keypoint};
+
+// Natural coordinates of the CST keypoints, used for the keypoint stresses and the contour plots.
+// ξ and η are the area coordinates of keypoint 2 and 3.
+pub const CST_NATURAL_COORDINATES: [(f64, f64); 3] = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)];
+
+// Returning the keypoint structs of a surface element, in the element order.
+pub fn surface_element_keypoints<'a>(surface:&SurfaceElement, kp_list:&'a [Keypoint]) -> Vec<&'a Keypoint> {
+	surface.keypoints.iter().map(|name| {
+		kp_list.iter().find(|kp| kp.name == *name)
+			.unwrap_or_else(|| panic!("Unknown keypoint {} in surface element {}", name, surface.name))
+	}).collect()
+}
+
+// Returning the natural coordinates of the keypoints of a surface element.
+pub fn surface_element_natural_coordinates(surface:&SurfaceElement) -> Vec<(f64, f64)> {
+	if surface.keypoints.len() == 3 {
+		CST_NATURAL_COORDINATES.to_vec()
+	} else {
+		Q4_NATURAL_COORDINATES.to_vec()
+	}
+}
+
+// Returning the natural coordinates of the element centroid.
+pub fn surface_element_centroid(surface:&SurfaceElement) -> (f64, f64) {
+	if surface.keypoints.len() == 3 {
+		(1.0 / 3.0, 1.0 / 3.0)
+	} else {
+		(0.0, 0.0)
+	}
+}
+
+// Returning the shape function values of a surface element at the natural coordinates (ξ, η).
+// CST elements use the area coordinates (1 - ξ - η, ξ, η).
+pub fn surface_element_shape_functions(surface:&SurfaceElement, xi:f64, eta:f64) -> Vec<f64> {
+	if surface.keypoints.len() == 3 {
+		vec![1.0 - xi - eta, xi, eta]
+	} else {
+		q4_shape_functions(xi, eta).to_vec()
+	}
+}
+
+// Returning the global stiffness matrix locations of the x and y dofs of all element keypoints.
+pub fn surface_element_dof_locations(surface:&SurfaceElement, kp_map:&HashMap<String, usize>) -> Vec<usize> {
+	surface.keypoints.iter().flat_map(|name| [kp_map[name], kp_map[name]+1]).collect()
+}
+
+// Returning the element stiffness matrix in global coordinates.
+// CST elements return a 6x6 matrix. Q4 elements return an 8x8 matrix.
+pub fn surface_element_stiffness_matrix(surface:&SurfaceElement, kp_list:&[Keypoint]) -> DMatrix<f64> {
+	let keypoints = surface_element_keypoints(surface, kp_list);
+
+	if keypoints.len() == 3 {
+		local_cst_matrix(&keypoints, surface.e_modulus, surface.poisson_ratio, surface.thickness, &surface.formulation)
+	} else {
+		local_q4_matrix(&keypoints, surface.e_modulus, surface.poisson_ratio, surface.thickness, &surface.formulation)
+	}
+}
+
+// Assembling the stiffness of the connections and the surface elements.
+pub fn create_plane_global_stiffness_matrix(kp_list:&[Keypoint],
+											conn_list:&[Connection],
+											surface_list:&[SurfaceElement]) -> DMatrix<f64> {
+
+	let dofs:usize = dofs_per_keypoint(conn_list);
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs);
+
+	let mut global_stiffness_matrix = create_global_stiffness_matrix(kp_list, conn_list);
+
+	for surface in surface_list {
+		let local_mat:DMatrix<f64> = surface_element_stiffness_matrix(surface, kp_list);
+
+		let locs = surface_element_dof_locations(surface, &kp_map);
+		for (i, loc_i) in locs.iter().enumerate() {
+			for (j, loc_j) in locs.iter().enumerate() {
+				global_stiffness_matrix[(*loc_i, *loc_j)] += local_mat[(i, j)];
+			}
+		}
+	}
+
+	global_stiffness_matrix
+}
+
+// Returning the resulting displacement and force vectors of a plane model.
+pub fn solve_plane_linear_static(kp_list:&[Keypoint],
+								 conn_list:&[Connection],
+								 surface_list:&[SurfaceElement],
+								 bc_list:&[BoundaryCondition],
+								 pl_list:&[Pointload]) -> (DVector<f64>, DVector<f64>) {
+
+	let dofs:usize = dofs_per_keypoint(conn_list);
+
+	let global_stiffness_matrix = create_plane_global_stiffness_matrix(kp_list, conn_list, surface_list);
+	let dof_filter_vector = create_dof_filter_vector(kp_list, conn_list, bc_list);
+	let force_vector = create_force_vector(kp_list, pl_list, dofs);
+
+	let modified_global_stiffness_matrix = apply_boundary_conditions(&global_stiffness_matrix, &dof_filter_vector);
+	let displacement_vector = calculate_resulting_displacement_vector(&modified_global_stiffness_matrix, &force_vector, &dof_filter_vector);
+	let resulting_force_vector = calculate_resulting_force_vector(&global_stiffness_matrix, &displacement_vector);
+
+	(displacement_vector, resulting_force_vector)
+}
+
+// Returning the stresses (σx, σy, τxy) of a surface element at the natural coordinates (ξ, η).
+// CST elements have constant stresses, so the coordinates are not used.
+pub fn calculate_surface_element_stress(surface:&SurfaceElement,
+										kp_list:&[Keypoint],
+										kp_map:&HashMap<String, usize>,
+										displacement_vector:&DVector<f64>,
+										xi:f64,
+										eta:f64) -> Vector3<f64> {
+
+	let keypoints = surface_element_keypoints(surface, kp_list);
+	let locs = surface_element_dof_locations(surface, kp_map);
+	let u_element:DVector<f64> = DVector::from_iterator(locs.len(), locs.iter().map(|loc| displacement_vector[*loc]));
+
+	if keypoints.len() == 3 {
+		cst_stress(&keypoints, &u_element, surface.e_modulus, surface.poisson_ratio, &surface.formulation)
+	} else {
+		q4_stress(&keypoints, &u_element, surface.e_modulus, surface.poisson_ratio, &surface.formulation, xi, eta)
+	}
+}
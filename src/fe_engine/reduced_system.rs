@@ -0,0 +1,30 @@
+// This file contains functions for moving between the global system
+// and the reduced system, where the fixed dofs are removed.
+// The dof filter vector defines the fixed dofs (value = 0).
+
+use nalgebra::{DMatrix, DVector};
+
+// Returning the global locations of all dofs that are not fixed.
+// The position in the returned list is the location in the reduced system.
+pub fn free_dof_locations(dof_filter_vector:&DVector<f64>) -> Vec<usize> {
+	(0..dof_filter_vector.nrows()).filter(|&i| dof_filter_vector[i] != 0.0).collect()
+}
+
+pub fn reduce_matrix(matrix:&DMatrix<f64>, free_dofs:&[usize]) -> DMatrix<f64> {
+	DMatrix::from_fn(free_dofs.len(), free_dofs.len(), |i, j| matrix[(free_dofs[i], free_dofs[j])])
+}
+
+pub fn reduce_vector(vector:&DVector<f64>, free_dofs:&[usize]) -> DVector<f64> {
+	DVector::from_fn(free_dofs.len(), |i, _| vector[free_dofs[i]])
+}
+
+// Re-inserting the removed dofs as zeros.
+pub fn expand_reduced_vector(vector_reduced:&DVector<f64>, free_dofs:&[usize], size:usize) -> DVector<f64> {
+	let mut vector:DVector<f64> = DVector::zeros(size);
+
+	for (i, loc) in free_dofs.iter().enumerate() {
+		vector[*loc] = vector_reduced[i];
+	}
+
+	vector
+}
@@ -0,0 +1,90 @@
+// This file contains the response spectrum analysis, based on the modes of the modal analysis.
+// For a base excitation in direction r, the peak response of mode i is
+// u_i = Gamma_i * phi_i * Sa(T_i) / omega_i^2, where Gamma_i = phi_i^T * M * r (mass-normalized modes).
+// The peak modal responses are combined with SRSS or CQC.
+
+use nalgebra::{DMatrix, DVector};
+use crate::input::spectrum::SpectrumPoint;
+use crate::fe_engine::modal_analysis::Mode;
+
+// Linear interpolation in the spectrum. Periods outside the spectrum use the end values.
+pub fn spectral_acceleration(spectrum:&[SpectrumPoint], period:f64) -> f64 {
+	let first = spectrum.first().unwrap();
+	let last = spectrum.last().unwrap();
+
+	if period <= first.period {
+		return first.acceleration;
+	}
+
+	for pair in spectrum.windows(2) {
+		if period >= pair[0].period && period <= pair[1].period {
+			let factor:f64 = (period - pair[0].period) / (pair[1].period - pair[0].period);
+			return pair[0].acceleration + factor * (pair[1].acceleration - pair[0].acceleration);
+		}
+	}
+
+	last.acceleration
+}
+
+// Influence vector for a base excitation. Unit values in the translational dofs of the excitation direction.
+// Fixed dofs move with the base, and are set to zero.
+pub fn create_influence_vector(dof_filter_vector:&DVector<f64>, dofs_per_keypoint:usize, direction_x:f64, direction_y:f64) -> DVector<f64> {
+	let length:f64 = (direction_x * direction_x + direction_y * direction_y).sqrt();
+
+	DVector::from_fn(dof_filter_vector.nrows(), |i, _| match i % dofs_per_keypoint {
+		_ if dof_filter_vector[i] == 0.0 => 0.0,
+		0 => direction_x / length,
+		1 => direction_y / length,
+		_ => 0.0,
+	})
+}
+
+// Modal participation factor, Gamma_i = phi_i^T * M * r.
+// The effective modal mass is Gamma_i^2.
+pub fn participation_factor(mode:&Mode, global_mass_matrix:&DMatrix<f64>, influence_vector:&DVector<f64>) -> f64 {
+	(mode.shape.transpose() * global_mass_matrix * influence_vector)[(0, 0)]
+}
+
+// Returning the peak global displacement vector of each mode.
+pub fn calculate_modal_displacements(modes:&[Mode],
+									 global_mass_matrix:&DMatrix<f64>,
+									 influence_vector:&DVector<f64>,
+									 spectrum:&[SpectrumPoint]) -> Vec<DVector<f64>> {
+	modes.iter().map(|mode| {
+		let gamma:f64 = participation_factor(mode, global_mass_matrix, influence_vector);
+		let sa:f64 = spectral_acceleration(spectrum, mode.period);
+		&mode.shape * (gamma * sa / mode.angular_frequency.powi(2))
+	}).collect()
+}
+
+// Combining modal responses value by value.
+// "srss" = square root of sum of squares.
+// "cqc" = complete quadratic combination, with equal damping ratio in all modes.
+pub fn combine_modal_responses(modal_responses:&[DVector<f64>],
+							   modes:&[Mode],
+							   damping_ratio:f64,
+							   method:&str) -> DVector<f64> {
+	let size:usize = modal_responses.first().map_or(0, |response| response.nrows());
+	let mut combined:DVector<f64> = DVector::zeros(size);
+
+	for i in 0..modal_responses.len() {
+		for j in 0..modal_responses.len() {
+			let rho:f64 = if method == "cqc" {
+				// Der Kiureghian correlation coefficient.
+				let beta:f64 = modes[j].angular_frequency / modes[i].angular_frequency;
+				let z2:f64 = damping_ratio * damping_ratio;
+				8.0 * z2 * (1.0 + beta) * beta.powf(1.5)
+					/ ((1.0 - beta * beta).powi(2) + 4.0 * z2 * beta * (1.0 + beta).powi(2))
+			} else if i == j {
+				1.0
+			} else {
+				0.0
+			};
+
+			combined += modal_responses[i].component_mul(&modal_responses[j]) * rho;
+		}
+	}
+
+	// Negative sums can only occur from round-off.
+	combined.map(|value| value.max(0.0).sqrt())
+}
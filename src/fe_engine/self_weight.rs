@@ -0,0 +1,57 @@
+// This file creates the self-weight force vector.
+// The weight of each bar (density * area * length * gravity) is lumped
+// with one half onto each of the two end keypoints.
+
+use nalgebra::{DVector, Vector2};
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::material::Material;
+use crate::input::load_case::LoadCase;
+use crate::fe_engine::utils::global_stiffness_matrix_keypoint_hashmap;
+
+pub fn create_self_weight_vector(kp_list:&[Keypoint],
+								 conn_list:&[Connection],
+								 mat_list:&[Material],
+								 load_case:&LoadCase,
+								 dofs_per_keypoint:usize) -> DVector<f64> {
+
+	// Creating vector size based on number of keypoints and dofs per keypoint.
+	let size:usize = dofs_per_keypoint*kp_list.len();
+
+	// Creating self-weight vector.
+	let mut sw_vec:DVector<f64> = DVector::from_element(size, 0.0);
+
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+
+	for conn in conn_list {
+		// Finding the keypoints and material of the connection.
+		let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+		let mat = mat_list.iter().find(|mat| mat.name == conn.material).unwrap();
+
+		// Bar length.
+		let length:f64 = (Vector2::new(kp_2.x, kp_2.y) - Vector2::new(kp_1.x, kp_1.y)).norm();
+
+		// Bar mass, lumped equally onto the two end keypoints.
+		let lumped_mass:f64 = mat.density * conn.area * length / 2.0;
+
+		let loc_1 = kp_map[&conn.kp_1];
+		let loc_2 = kp_map[&conn.kp_2];
+
+		sw_vec[loc_1] += lumped_mass * load_case.gravity_x;
+		sw_vec[loc_1+1] += lumped_mass * load_case.gravity_y;
+		sw_vec[loc_2] += lumped_mass * load_case.gravity_x;
+		sw_vec[loc_2+1] += lumped_mass * load_case.gravity_y;
+	}
+
+	sw_vec
+}
+
+// Summing a global load vector into its total x and y components.
+// Used for the load summary.
+pub fn calculate_total_load(load_vector:&DVector<f64>, dofs_per_keypoint:usize) -> (f64, f64) {
+	let total_x:f64 = load_vector.iter().step_by(dofs_per_keypoint).sum();
+	let total_y:f64 = load_vector.iter().skip(1).step_by(dofs_per_keypoint).sum();
+
+	(total_x, total_y)
+}
@@ -0,0 +1,335 @@
+// This file contains the analytical sensitivity of displacements to design variables.
+// The design variables are the connection areas and the keypoint coordinates.
+//
+// Differentiating K*u = F with respect to a design variable p gives
+// K * du/dp = dF/dp - dK/dp * u, where the right hand side is the pseudo load.
+// Direct method: one solve per design variable, giving du/dp for all dofs.
+// Adjoint method: one solve per response, K * lambda = e_r, giving du_r/dp = lambda^T * pseudo load.
+// Both methods reuse the same factorisation of the reduced stiffness matrix.
+//
+// dK/dp is assembled from the element derivatives. Bar elements use the analytical derivative of
+// local_bar_matrix. Frame elements use a central difference of the element matrix for the coordinates.
+// dF/dp includes the self-weight, which depends on the area and the length of each connection, and the
+// equivalent nodal loads of distributed loads, which depend on the element geometry (central difference).
+// Pointloads are constant.
+
+use nalgebra::{DMatrix, DVector, Dyn, LU};
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::material::Material;
+use crate::input::load_case::LoadCase;
+use crate::input::distributed_load::DistributedLoad;
+use crate::fe_engine::utils::{global_stiffness_matrix_keypoint_hashmap, dofs_per_keypoint, element_dof_locations, frame_released_dofs};
+use crate::fe_engine::reduced_system::{free_dof_locations, reduce_matrix, reduce_vector, expand_reduced_vector};
+use crate::material_formulation::local_stiffness_matrix_bar::{local_bar_matrix, local_bar_matrix_coordinate_derivative};
+use crate::material_formulation::local_stiffness_matrix_frame::{local_frame_matrix, element_geometry, frame_transformation_matrix};
+use crate::fe_engine::distributed_load_vector::equivalent_nodal_load_local;
+
+// Design variable, using the parameter names of the parameter sweep.
+#[derive(Debug)]
+pub struct DesignVariable {
+	pub parameter: String, // "area", "keypoint_x" or "keypoint_y".
+	pub target: String, // Connection or keypoint name.
+}
+
+// Returning the areas of all connections and the coordinates of all keypoints as design variables.
+// Connections sharing a name share one area variable.
+pub fn create_design_variables(kp_list:&[Keypoint], conn_list:&[Connection]) -> Vec<DesignVariable> {
+	let mut design_variables:Vec<DesignVariable> = Vec::new();
+
+	for conn in conn_list {
+		if !design_variables.iter().any(|variable| variable.target == conn.name) {
+			design_variables.push(DesignVariable {parameter: "area".to_string(), target: conn.name.clone()});
+		}
+	}
+	for kp in kp_list {
+		for parameter in ["keypoint_x", "keypoint_y"] {
+			design_variables.push(DesignVariable {parameter: parameter.to_string(), target: kp.name.clone()});
+		}
+	}
+
+	design_variables
+}
+
+// Returning the current value of a design variable.
+pub fn design_variable_value(design_variable:&DesignVariable, kp_list:&[Keypoint], conn_list:&[Connection]) -> f64 {
+	match design_variable.parameter.as_str() {
+		"area" => conn_list.iter().find(|conn| conn.name == design_variable.target).unwrap().area,
+		"keypoint_x" => kp_list.iter().find(|kp| kp.name == design_variable.target).unwrap().x,
+		"keypoint_y" => kp_list.iter().find(|kp| kp.name == design_variable.target).unwrap().y,
+		_ => panic!("Unknown design variable {}", design_variable.parameter),
+	}
+}
+
+// Returning the end keypoints of an element with one coordinate changed by the step.
+// Coordinate: 0 = kp_1.x, 1 = kp_1.y, 2 = kp_2.x, 3 = kp_2.y.
+fn perturbed_keypoints(kp_1:&Keypoint, kp_2:&Keypoint, coordinate:usize, step:f64) -> (Keypoint, Keypoint) {
+	let mut kp_1 = kp_1.clone();
+	let mut kp_2 = kp_2.clone();
+	match coordinate {
+		0 => kp_1.x += step,
+		1 => kp_1.y += step,
+		2 => kp_2.x += step,
+		_ => kp_2.y += step,
+	}
+	(kp_1, kp_2)
+}
+
+// Element stiffness matrix derivative of a frame element with respect to a keypoint coordinate.
+// Central difference of the element matrix, with a step relative to the element length.
+fn frame_matrix_coordinate_derivative(kp_1:&Keypoint, kp_2:&Keypoint, e_module:f64, area:f64, inertia:f64,
+									   released_dofs:&[usize], coordinate:usize) -> DMatrix<f64> {
+	let (length, _, _) = element_geometry(kp_1, kp_2);
+	let step:f64 = 1e-6 * length;
+
+	let perturbed_matrix = |sign:f64| {
+		let (kp_1, kp_2) = perturbed_keypoints(kp_1, kp_2, coordinate, sign * step);
+		local_frame_matrix(&kp_1, &kp_2, e_module, area, inertia, released_dofs)
+	};
+
+	(perturbed_matrix(1.0) - perturbed_matrix(-1.0)) / (2.0 * step)
+}
+
+// Derivative of the equivalent nodal loads in global coordinates with respect to a keypoint coordinate.
+// The loads depend on the length and the direction of the element. Central difference, as for the frame matrix.
+fn distributed_load_coordinate_derivative(conn:&Connection, kp_1:&Keypoint, kp_2:&Keypoint, dl_list:&[DistributedLoad],
+										  coordinate:usize) -> DVector<f64> {
+	let (length, _, _) = element_geometry(kp_1, kp_2);
+	let step:f64 = 1e-6 * length;
+
+	let perturbed_load = |sign:f64| {
+		let (kp_1, kp_2) = perturbed_keypoints(kp_1, kp_2, coordinate, sign * step);
+		frame_transformation_matrix(&kp_1, &kp_2).transpose() * equivalent_nodal_load_local(conn, &kp_1, &kp_2, dl_list)
+	};
+
+	(perturbed_load(1.0) - perturbed_load(-1.0)) / (2.0 * step)
+}
+
+// Assembling dK/dp and dF/dp for a design variable.
+pub fn create_design_derivatives(kp_list:&[Keypoint],
+								 conn_list:&[Connection],
+								 dl_list:&[DistributedLoad],
+								 mat_list:&[Material],
+								 load_case:&LoadCase,
+								 design_variable:&DesignVariable) -> (DMatrix<f64>, DVector<f64>) {
+
+	let dofs:usize = dofs_per_keypoint(conn_list);
+	let size:usize = dofs * kp_list.len();
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs);
+
+	let mut dk:DMatrix<f64> = DMatrix::zeros(size, size);
+	let mut df:DVector<f64> = DVector::zeros(size);
+
+	for conn in conn_list {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+		let mat = mat_list.iter().find(|mat| mat.name == conn.material).unwrap();
+		let (length, c, s) = element_geometry(kp_1, kp_2);
+
+		// Element stiffness derivative, the derivatives of the area and length for the self-weight,
+		// and the derivative of the equivalent nodal loads in global coordinates.
+		let (local_dk, d_area, d_length, d_f_eq):(DMatrix<f64>, f64, f64, DVector<f64>) = match design_variable.parameter.as_str() {
+			"area" if conn.name == design_variable.target => {
+				// The stiffness is linear in the area, so dK/dA is the element matrix with a unit area.
+				// End releases only affect the bending stiffness, which does not depend on the area.
+				let local_dk = if conn.element == "frame" {
+					local_frame_matrix(kp_1, kp_2, conn.e_modulus, 1.0, 0.0, &[])
+				} else {
+					local_bar_matrix(kp_1, kp_2, conn.e_modulus, 1.0)
+				};
+				(local_dk, 1.0, 0.0, DVector::zeros(6))
+			},
+			"keypoint_x" | "keypoint_y" if kp_1.name == design_variable.target || kp_2.name == design_variable.target => {
+				let is_kp_2:bool = kp_2.name == design_variable.target;
+				let is_y:bool = design_variable.parameter == "keypoint_y";
+				let coordinate:usize = 2 * is_kp_2 as usize + is_y as usize;
+
+				let local_dk = if conn.element == "frame" {
+					frame_matrix_coordinate_derivative(kp_1, kp_2, conn.e_modulus, conn.area, conn.inertia, &frame_released_dofs(conn), coordinate)
+				} else {
+					local_bar_matrix_coordinate_derivative(kp_1, kp_2, conn.e_modulus, conn.area, coordinate)
+				};
+
+				// dL = n . d_delta, where the element vector changes with -1 at kp_1 and +1 at kp_2.
+				let sign:f64 = if is_kp_2 { 1.0 } else { -1.0 };
+				let d_f_eq = distributed_load_coordinate_derivative(conn, kp_1, kp_2, dl_list, coordinate);
+				(local_dk, 0.0, sign * if is_y { s } else { c }, d_f_eq)
+			},
+			_ => continue,
+		};
+
+		let locs = element_dof_locations(conn, &kp_map);
+		for (i, loc_i) in locs.iter().enumerate() {
+			for (j, loc_j) in locs.iter().enumerate() {
+				dk[(*loc_i, *loc_j)] += local_dk[(i, j)];
+			}
+		}
+
+		// Self-weight lumped onto the end keypoints, density * A * L / 2 * gravity.
+		let d_lumped_mass:f64 = mat.density * (d_area * length + conn.area * d_length) / 2.0;
+		for loc in [kp_map[&conn.kp_1], kp_map[&conn.kp_2]] {
+			df[loc] += d_lumped_mass * load_case.gravity_x;
+			df[loc+1] += d_lumped_mass * load_case.gravity_y;
+		}
+
+		// Distributed loads, assembled as in the distributed load vector.
+		let loc_1 = kp_map[&conn.kp_1];
+		let loc_2 = kp_map[&conn.kp_2];
+		df[loc_1] += d_f_eq[0];
+		df[loc_1+1] += d_f_eq[1];
+		df[loc_2] += d_f_eq[3];
+		df[loc_2+1] += d_f_eq[4];
+		if conn.element == "frame" {
+			df[loc_1+2] += d_f_eq[2];
+			df[loc_2+2] += d_f_eq[5];
+		}
+	}
+
+	(dk, df)
+}
+
+// Factorising the reduced stiffness matrix. Spring dofs are kept, with the spring on the diagonal.
+pub fn factorise_reduced_stiffness_matrix(modified_global_stiffness_matrix:&DMatrix<f64>,
+										  dof_filter_vector:&DVector<f64>) -> (LU<f64, Dyn, Dyn>, Vec<usize>) {
+	let free_dofs = free_dof_locations(dof_filter_vector);
+	let lu = reduce_matrix(modified_global_stiffness_matrix, &free_dofs).lu();
+
+	(lu, free_dofs)
+}
+
+// Pseudo load dF/dp - dK/dp * u. Only the free dofs are used by the solution.
+pub fn calculate_pseudo_load(dk:&DMatrix<f64>, df:&DVector<f64>, displacement_vector:&DVector<f64>) -> DVector<f64> {
+	df - dk * displacement_vector
+}
+
+// Direct method. Returning du/dp for all global dofs. Fixed dofs are zero.
+pub fn solve_direct_sensitivity(lu:&LU<f64, Dyn, Dyn>, free_dofs:&[usize], pseudo_load:&DVector<f64>) -> DVector<f64> {
+	let du_reduced = lu.solve(&reduce_vector(pseudo_load, free_dofs)).unwrap();
+	expand_reduced_vector(&du_reduced, free_dofs, pseudo_load.nrows())
+}
+
+// Adjoint method. Returning the adjoint vector of a response dof, K * lambda = e_r.
+// The stiffness matrix is symmetric, so the factorisation of K is reused. A fixed response dof gives a zero vector.
+pub fn solve_adjoint_vector(lu:&LU<f64, Dyn, Dyn>, free_dofs:&[usize], response_location:usize, size:usize) -> DVector<f64> {
+	let unit_vector = DVector::from_fn(size, |i, _| if i == response_location { 1.0 } else { 0.0 });
+	let lambda_reduced = lu.solve(&reduce_vector(&unit_vector, free_dofs)).unwrap();
+	expand_reduced_vector(&lambda_reduced, free_dofs, size)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::input::pointload::Pointload;
+	use crate::input::boundary_condition::BoundaryCondition;
+	use crate::input::sensitivity_response::SensitivityResponse;
+	use crate::fe_engine::linear_static::{Model, solve_linear_static};
+	use crate::fe_engine::parameter_sweep::apply_model_parameter;
+	use crate::generators::utils::{create_keypoint, create_connection, create_boundary_condition, create_pointload};
+	use crate::data_formatting::generate_result_structs::generate_result_sensitivities;
+
+	fn response(name:&str, keypoint:&str, direction:&str) -> SensitivityResponse {
+		SensitivityResponse {name: name.to_string(), keypoint: keypoint.to_string(), direction: direction.to_string()}
+	}
+
+	fn connection(name:&str, kp_1:&str, kp_2:&str, element:&str) -> Connection {
+		let mut conn = create_connection(name, kp_1, kp_2, "mat1", element);
+		conn.e_modulus = 210000000.0;
+		conn.area = 0.01;
+		conn.inertia = 0.0001;
+		conn
+	}
+
+	// Comparing the direct and adjoint sensitivities with central finite differences,
+	// re-solving the model with each design variable changed by +/- a relative step.
+	fn assert_sensitivities_match_finite_differences(model:&Model, responses:&[SensitivityResponse]) {
+		let dofs:usize = dofs_per_keypoint(model.conn_list);
+		let kp_map = global_stiffness_matrix_keypoint_hashmap(model.kp_list, dofs);
+		let results = generate_result_sensitivities(model, responses);
+
+		for (response, result) in responses.iter().zip(results.iter()) {
+			let location:usize = kp_map[&response.keypoint] + match response.direction.as_str() {
+				"x" => 0,
+				"y" => 1,
+				_ => 2,
+			};
+			let max_sensitivity:f64 = result.sensitivities.iter().map(|entry| entry.direct.abs()).fold(0.0, f64::max);
+			assert!(max_sensitivity > 0.0);
+
+			for entry in &result.sensitivities {
+				let step:f64 = 1e-6 * entry.value.abs().max(1.0);
+				let perturbed_displacement = |sign:f64| {
+					let mut kp_list:Vec<Keypoint> = model.kp_list.to_vec();
+					let mut conn_list:Vec<Connection> = model.conn_list.to_vec();
+					let mut pl_list:Vec<Pointload> = model.pl_list.to_vec();
+					apply_model_parameter(&entry.parameter, &entry.target, entry.value + sign * step,
+										  &mut kp_list, &mut conn_list, &mut pl_list);
+					solve_linear_static(&Model {kp_list: &kp_list, conn_list: &conn_list, pl_list: &pl_list, ..*model}).0[location]
+				};
+				let finite_difference:f64 = (perturbed_displacement(1.0) - perturbed_displacement(-1.0)) / (2.0 * step);
+
+				let tolerance:f64 = 1e-5 * max_sensitivity;
+				assert!((entry.direct - entry.adjoint).abs() < tolerance,
+						"{} {} {}: direct {} adjoint {}", response.name, entry.parameter, entry.target, entry.direct, entry.adjoint);
+				assert!((entry.direct - finite_difference).abs() < tolerance,
+						"{} {} {}: direct {} finite difference {}", response.name, entry.parameter, entry.target, entry.direct, finite_difference);
+			}
+		}
+	}
+
+	// Portal frame with fixed column bases. The beam is pinned to the right column.
+	fn portal_frame() -> (Vec<Keypoint>, Vec<Connection>, Vec<BoundaryCondition>) {
+		let kp_list = vec![create_keypoint("kp1", 0.0, 0.0), create_keypoint("kp2", 0.0, 3.0),
+						   create_keypoint("kp3", 4.0, 3.5), create_keypoint("kp4", 4.0, 0.0)];
+		let mut beam = connection("c2", "kp2", "kp3", "frame");
+		beam.release_2 = "rz".to_string();
+		let conn_list = vec![connection("c1", "kp1", "kp2", "frame"), beam, connection("c3", "kp4", "kp3", "frame")];
+		let bc_list = vec![create_boundary_condition("bc1", "kp1", "4"), create_boundary_condition("bc2", "kp4", "4")];
+		(kp_list, conn_list, bc_list)
+	}
+
+	#[test]
+	fn truss_sensitivities_match_finite_differences() {
+		let kp_list = vec![create_keypoint("kp1", 0.0, 0.0), create_keypoint("kp2", 4.0, 0.0), create_keypoint("kp3", 1.5, 2.0)];
+		let conn_list = vec![connection("c1", "kp1", "kp2", "bar"), connection("c2", "kp1", "kp3", "bar"),
+							 connection("c3", "kp2", "kp3", "bar")];
+		let bc_list = vec![create_boundary_condition("bc1", "kp1", "2"), create_boundary_condition("bc2", "kp2", "1")];
+		let pl_list = vec![create_pointload("p1", "kp3", 20.0, -50.0)];
+		let mat_list = vec![Material {name: "mat1".to_string(), e_modulus: 210000000.0, density: 78.5}];
+		let load_case = LoadCase {name: "lc1".to_string(), gravity_x: 0.0, gravity_y: -9.81};
+		let model = Model {kp_list: &kp_list, conn_list: &conn_list, bc_list: &bc_list, pl_list: &pl_list,
+						   dl_list: &[], mat_list: &mat_list, load_case: &load_case};
+
+		assert_sensitivities_match_finite_differences(&model, &[response("u3x", "kp3", "x"), response("u3y", "kp3", "y")]);
+	}
+
+	#[test]
+	fn frame_sensitivities_match_finite_differences() {
+		let (kp_list, conn_list, bc_list) = portal_frame();
+		let pl_list = vec![create_pointload("p1", "kp2", 10.0, -30.0)];
+		let mat_list = vec![Material {name: "mat1".to_string(), e_modulus: 210000000.0, density: 78.5}];
+		let load_case = LoadCase {name: "lc1".to_string(), gravity_x: 0.0, gravity_y: -9.81};
+		let model = Model {kp_list: &kp_list, conn_list: &conn_list, bc_list: &bc_list, pl_list: &pl_list,
+						   dl_list: &[], mat_list: &mat_list, load_case: &load_case};
+
+		assert_sensitivities_match_finite_differences(&model, &[response("u2x", "kp2", "x"), response("u3y", "kp3", "y"),
+																 response("r2", "kp2", "r")]);
+	}
+
+	#[test]
+	fn distributed_load_sensitivities_match_finite_differences() {
+		let (kp_list, conn_list, bc_list) = portal_frame();
+		let dl_list = vec![
+			DistributedLoad {name: "dl1".to_string(), connection: "c2".to_string(), coordinate_system: "global".to_string(),
+							 direction: "y".to_string(), load_start: -10.0, load_end: -20.0},
+			DistributedLoad {name: "dl2".to_string(), connection: "c1".to_string(), coordinate_system: "local".to_string(),
+							 direction: "y".to_string(), load_start: -5.0, load_end: 0.0},
+		];
+		let mat_list = vec![Material {name: "mat1".to_string(), e_modulus: 210000000.0, density: 0.0}];
+		let load_case = LoadCase {name: "lc1".to_string(), gravity_x: 0.0, gravity_y: 0.0};
+		let model = Model {kp_list: &kp_list, conn_list: &conn_list, bc_list: &bc_list, pl_list: &[],
+						   dl_list: &dl_list, mat_list: &mat_list, load_case: &load_case};
+
+		assert_sensitivities_match_finite_differences(&model, &[response("u2x", "kp2", "x"), response("u3y", "kp3", "y"),
+																 response("r2", "kp2", "r")]);
+	}
+}
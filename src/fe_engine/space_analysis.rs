@@ -0,0 +1,344 @@
+// This file contains the linear static analysis of 3D models. The z-axis is vertical.
+// Space truss keypoints have 3 translational dofs (x, y, z). Models containing frame elements
+// have 6 dofs per keypoint (x, y, z, rx, ry, rz).
+// The global system is assembled, reduced and solved in the same way as the 2D models,
+// reusing the boundary condition and solver functions of the global stiffness matrix.
+//
+// Directions are given as letters, e.g. "xyz" or "z" for translations and "rx", "ry" or "rz" for rotations.
+// Boundary conditions use global directions, e.g. "xyz" for a pinned and "xyzrxryrz" for a fixed support.
+// End releases of frame elements use local directions, e.g. "ryrz" for a hinge.
+
+use nalgebra::{DMatrix, DVector, Matrix3, Vector3, SymmetricEigen};
+use std::collections::HashMap;
+use crate::input::space_keypoint::SpaceKeypoint;
+use crate::input::connection::Connection;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::input::space_pointload::SpacePointload;
+use crate::input::release::direction_dofs;
+use crate::material_formulation::local_stiffness_matrix_space_bar::{local_space_bar_matrix, space_element_geometry};
+use crate::material_formulation::local_stiffness_matrix_space_frame::{local_space_frame_matrix, space_frame_matrix_local_axes,
+	space_frame_transformation_matrix, space_frame_rotation_matrix};
+use crate::material_formulation::static_condensation::condense_released_dofs;
+use crate::fe_engine::global_stiffness_matrix::{apply_boundary_conditions, calculate_resulting_displacement_vector,
+	calculate_resulting_force_vector};
+
+// Returning the number of degrees of freedom for each keypoint of a 3D model.
+// Models with bar elements only have 3 (x, y, z).
+// Models containing frame elements have 6 (x, y, z, rx, ry, rz).
+pub fn space_dofs_per_keypoint(conn_list: &[Connection]) -> usize {
+	if conn_list.iter().any(|conn| conn.element == "frame") {
+		6
+	} else {
+		3
+	}
+}
+
+// Returning the first row/col number of each keypoint in the global stiffness matrix.
+pub fn space_keypoint_hashmap(kp_list: &[SpaceKeypoint], dofs_per_keypoint: usize) -> HashMap<String, usize> {
+	kp_list.iter().enumerate().map(|(i, kp)| (kp.name.clone(), i * dofs_per_keypoint)).collect()
+}
+
+// Returning the global stiffness matrix locations of all element degrees of freedom.
+// Bar elements contain the x, y and z dofs of both keypoints.
+// Frame elements additionally contain the rotational dofs of both keypoints.
+pub fn space_element_dof_locations(conn: &Connection, kp_map: &HashMap<String, usize>) -> Vec<usize> {
+	let loc_1 = kp_map[&conn.kp_1];
+	let loc_2 = kp_map[&conn.kp_2];
+
+	if conn.element == "frame" {
+		(0..6).map(|i| loc_1 + i).chain((0..6).map(|i| loc_2 + i)).collect()
+	} else {
+		vec![loc_1, loc_1+1, loc_1+2, loc_2, loc_2+1, loc_2+2]
+	}
+}
+
+// Returning the released element dofs of a frame element, in the local dof layout of both ends.
+pub fn space_released_dofs(conn: &Connection) -> Vec<usize> {
+	direction_dofs(&conn.release_1).into_iter()
+		.chain(direction_dofs(&conn.release_2).into_iter().map(|dof| dof + 6))
+		.collect()
+}
+
+// Shear modulus of the connection material, G = E / (2 * (1 + v)).
+pub fn shear_modulus(conn:&Connection, poisson_ratio:f64) -> f64 {
+	conn.e_modulus / (2.0 * (1.0 + poisson_ratio))
+}
+
+// Returning the local stiffness matrix in global coordinates, based on the connection element type.
+// Bar elements return a 6x6 matrix. Frame elements return a 12x12 matrix.
+pub fn space_element_stiffness_matrix(conn:&Connection,
+									  kp_1:&SpaceKeypoint,
+									  kp_2:&SpaceKeypoint,
+									  poisson_ratio:f64) -> DMatrix<f64> {
+	if conn.element == "frame" {
+		local_space_frame_matrix(kp_1, kp_2, conn, conn.e_modulus, shear_modulus(conn, poisson_ratio), &space_released_dofs(conn))
+	} else {
+		local_space_bar_matrix(kp_1, kp_2, conn.e_modulus, conn.area)
+	}
+}
+
+pub fn create_space_global_stiffness_matrix(kp_list: &[SpaceKeypoint],
+											conn_list: &[Connection],
+											poisson_ratio: f64) -> DMatrix<f64> {
+
+	let dofs:usize = space_dofs_per_keypoint(conn_list);
+	let size:usize = dofs * kp_list.len();
+	let kp_map = space_keypoint_hashmap(kp_list, dofs);
+
+	let mut global_stiffness_matrix = DMatrix::<f64>::zeros(size, size);
+
+	for conn in conn_list {
+		let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+		let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+		let local_mat:DMatrix<f64> = space_element_stiffness_matrix(conn, kp_1, kp_2, poisson_ratio);
+
+		let locs = space_element_dof_locations(conn, &kp_map);
+		for (i, loc_i) in locs.iter().enumerate() {
+			for (j, loc_j) in locs.iter().enumerate() {
+				global_stiffness_matrix[(*loc_i, *loc_j)] += local_mat[(i, j)];
+			}
+		}
+	}
+
+	global_stiffness_matrix
+}
+
+// Dof filter vector of a 3D model.
+// -1 = free, 0 = fixed, >0 = spring (value = spring stiffness).
+pub fn create_space_dof_filter_vector(kp_list: &[SpaceKeypoint],
+									  conn_list: &[Connection],
+									  bc_list: &[BoundaryCondition]) -> DVector<f64> {
+
+	let dofs:usize = space_dofs_per_keypoint(conn_list);
+	let kp_map = space_keypoint_hashmap(kp_list, dofs);
+
+	let mut dof_filter_vec:DVector<f64> = DVector::from_element(dofs * kp_list.len(), -1.0);
+
+	for bc in bc_list {
+		let bc_value:f64 = if bc.spring_stiffness == -1.0 {
+			0.0
+		} else if bc.spring_stiffness > 0.0 {
+			bc.spring_stiffness
+		} else {
+			continue;
+		};
+
+		// Rotational boundary conditions are only available in models with frame elements.
+		for dof in direction_dofs(&bc.fixture).into_iter().filter(|dof| *dof < dofs) {
+			dof_filter_vec[kp_map[&bc.keypoint] + dof] = bc_value;
+		}
+	}
+
+	// Keypoints that are not connected to any frame element have no rotational stiffness.
+	// The same applies to the rotations that are released at all connected frame ends.
+	// These rotations form the null space of the unreleased local rotation axes of the connected frame ends,
+	// together with the supported global rotations. Global rotations spanning the null space are fixed to keep
+	// the stiffness matrix solvable. This does not affect the results, as the rotations without stiffness take up the fixture.
+	if dofs == 6 {
+		for kp in kp_list {
+			let loc_r:usize = kp_map[&kp.name] + 3;
+			let mut restraining_axes:Vec<Vector3<f64>> = (0..3)
+				.filter(|axis| dof_filter_vec[loc_r + axis] != -1.0)
+				.map(|axis| Vector3::ith(axis, 1.0))
+				.collect();
+
+			for conn in conn_list.iter().filter(|conn| conn.element == "frame") {
+				let offset:usize = if conn.kp_1 == kp.name {
+					0
+				} else if conn.kp_2 == kp.name {
+					6
+				} else {
+					continue;
+				};
+				let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+				let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+				let rotation = space_frame_rotation_matrix(kp_1, kp_2, conn.orientation);
+				let released_dofs = space_released_dofs(conn);
+
+				restraining_axes.extend((0..3)
+					.filter(|axis| !released_dofs.contains(&(offset + 3 + axis)))
+					.map(|axis| rotation.row(axis).transpose()));
+			}
+
+			for axis in unrestrained_rotation_axes(&restraining_axes) {
+				dof_filter_vec[loc_r + axis] = 0.0;
+			}
+		}
+	}
+
+	dof_filter_vec
+}
+
+// Returning the global rotation axes to fix at a keypoint with the given restraining rotation axes.
+// The null space of the restraining axes is found from the eigenvectors of sum(a * a^T) with zero eigenvalues.
+// One global axis is chosen for each null space direction by Gaussian elimination with full pivoting,
+// so the fixed axes together span the null space.
+fn unrestrained_rotation_axes(restraining_axes:&[Vector3<f64>]) -> Vec<usize> {
+	let restraint:Matrix3<f64> = restraining_axes.iter().fold(Matrix3::zeros(), |sum, axis| sum + axis * axis.transpose());
+	let eigen = SymmetricEigen::new(restraint);
+	let mut null_space:Vec<Vector3<f64>> = (0..3)
+		.filter(|&i| eigen.eigenvalues[i].abs() < 1e-9)
+		.map(|i| eigen.eigenvectors.column(i).into_owned())
+		.collect();
+
+	let mut fixed_axes:Vec<usize> = Vec::new();
+	while !null_space.is_empty() {
+		let (index, axis) = (0..null_space.len())
+			.flat_map(|index| (0..3).map(move |axis| (index, axis)))
+			.max_by(|a, b| null_space[a.0][a.1].abs().total_cmp(&null_space[b.0][b.1].abs()))
+			.unwrap();
+		let pivot:Vector3<f64> = null_space.swap_remove(index);
+		for direction in null_space.iter_mut() {
+			*direction -= pivot * (direction[axis] / pivot[axis]);
+		}
+		fixed_axes.push(axis);
+	}
+
+	fixed_axes
+}
+
+pub fn create_space_force_vector(kp_list: &[SpaceKeypoint], pl_list: &[SpacePointload], dofs_per_keypoint: usize) -> DVector<f64> {
+	let kp_map = space_keypoint_hashmap(kp_list, dofs_per_keypoint);
+
+	let mut pl_vec:DVector<f64> = DVector::zeros(dofs_per_keypoint * kp_list.len());
+
+	for pl in pl_list {
+		let loc_x:usize = kp_map[&pl.keypoint];
+		pl_vec[loc_x] += pl.load_x;
+		pl_vec[loc_x+1] += pl.load_y;
+		pl_vec[loc_x+2] += pl.load_z;
+
+		// Moments are only applied in models with rotational dofs.
+		if dofs_per_keypoint == 6 {
+			pl_vec[loc_x+3] += pl.moment_x;
+			pl_vec[loc_x+4] += pl.moment_y;
+			pl_vec[loc_x+5] += pl.moment_z;
+		}
+	}
+
+	pl_vec
+}
+
+// Returning the resulting displacement and force vectors of a 3D model.
+pub fn solve_space_linear_static(kp_list: &[SpaceKeypoint],
+								 conn_list: &[Connection],
+								 bc_list: &[BoundaryCondition],
+								 pl_list: &[SpacePointload],
+								 poisson_ratio: f64) -> (DVector<f64>, DVector<f64>) {
+
+	let dofs:usize = space_dofs_per_keypoint(conn_list);
+
+	let global_stiffness_matrix = create_space_global_stiffness_matrix(kp_list, conn_list, poisson_ratio);
+	let dof_filter_vector = create_space_dof_filter_vector(kp_list, conn_list, bc_list);
+	let force_vector = create_space_force_vector(kp_list, pl_list, dofs);
+
+	let modified_global_stiffness_matrix = apply_boundary_conditions(&global_stiffness_matrix, &dof_filter_vector);
+	let displacement_vector = calculate_resulting_displacement_vector(&modified_global_stiffness_matrix, &force_vector, &dof_filter_vector);
+	let resulting_force_vector = calculate_resulting_force_vector(&global_stiffness_matrix, &displacement_vector);
+
+	(displacement_vector, resulting_force_vector)
+}
+
+// Returning the end forces acting on the connection in local coordinates.
+// Vector layout at each end: (x, y, z, rx, ry, rz), i.e. the axial force, the shear forces along local y and z,
+// the torsional moment and the bending moments about local y and z.
+// Bar elements have axial stiffness only, which equals a frame element without bending and torsional stiffness.
+pub fn calculate_space_local_end_forces(conn:&Connection,
+										kp_list:&[SpaceKeypoint],
+										kp_map:&HashMap<String, usize>,
+										displacement_vector:&DVector<f64>,
+										dofs_per_keypoint:usize,
+										poisson_ratio:f64) -> DVector<f64> {
+
+	let kp_1 = kp_list.iter().find(|kp| kp.name == conn.kp_1).unwrap();
+	let kp_2 = kp_list.iter().find(|kp| kp.name == conn.kp_2).unwrap();
+	let (length, _) = space_element_geometry(kp_1, kp_2);
+
+	// Collecting the element displacements in global coordinates.
+	// Rotations are zero for bar elements, and for models without rotational dofs.
+	let is_frame:bool = conn.element == "frame" && dofs_per_keypoint == 6;
+	let element_dofs:usize = if is_frame { 6 } else { 3 };
+	let mut u_element:DVector<f64> = DVector::zeros(12);
+	for (end, kp_name) in [&conn.kp_1, &conn.kp_2].iter().enumerate() {
+		for dof in 0..element_dofs {
+			u_element[6 * end + dof] = displacement_vector[kp_map[*kp_name] + dof];
+		}
+	}
+
+	let shear_module:f64 = shear_modulus(conn, poisson_ratio);
+	let k_local:DMatrix<f64> = if is_frame {
+		let k = space_frame_matrix_local_axes(length, conn.e_modulus, shear_module, conn.area, conn.inertia, conn.inertia_minor,
+											  conn.torsion_constant);
+		condense_released_dofs(&k, &space_released_dofs(conn))
+	} else {
+		space_frame_matrix_local_axes(length, conn.e_modulus, shear_module, conn.area, 0.0, 0.0, 0.0)
+	};
+	let t:DMatrix<f64> = space_frame_transformation_matrix(kp_1, kp_2, conn.orientation);
+
+	k_local * t * u_element
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::generators::utils::create_connection;
+
+	fn space_keypoint(name:&str, x:f64, y:f64, z:f64) -> SpaceKeypoint {
+		SpaceKeypoint {name: name.to_string(), x, y, z, fx: 0.0, fy: 0.0, fz: 0.0, mx: 0.0, my: 0.0, mz: 0.0,
+					   ux: 0.0, uy: 0.0, uz: 0.0, rx: 0.0, ry: 0.0, rz: 0.0}
+	}
+
+	fn tip_load(load:[f64; 3], moment:[f64; 3]) -> SpacePointload {
+		SpacePointload {name: "p1".to_string(), keypoint: "kp2".to_string(), load_x: load[0], load_y: load[1], load_z: load[2],
+						moment_x: moment[0], moment_y: moment[1], moment_z: moment[2]}
+	}
+
+	// Cantilever from kp1 to kp2, fixed at kp1, with the given releases at the tip.
+	fn cantilever(kp_2:SpaceKeypoint, release_2:&str) -> (Vec<SpaceKeypoint>, Vec<Connection>, Vec<BoundaryCondition>) {
+		let mut conn = create_connection("c1", "kp1", "kp2", "mat1", "frame");
+		conn.e_modulus = 210000000.0;
+		conn.area = 0.01;
+		conn.inertia = 0.0002;
+		conn.inertia_minor = 0.0001;
+		conn.torsion_constant = 0.00005;
+		conn.release_2 = release_2.to_string();
+		let bc = BoundaryCondition {name: "bc1".to_string(), keypoint: "kp1".to_string(), fixture: "xyzrxryrz".to_string(),
+									spring_stiffness: -1.0};
+		(vec![space_keypoint("kp1", 0.0, 0.0, 0.0), kp_2], vec![conn], vec![bc])
+	}
+
+	#[test]
+	fn released_tip_rotations_of_a_cantilever_are_fixed() {
+		let (length, load, torque, poisson_ratio) = (3.0, 10.0, 2.0, 0.3);
+
+		// Beam along x. The released local ry and rz are the global ry and rz.
+		let (kp_list, conn_list, bc_list) = cantilever(space_keypoint("kp2", length, 0.0, 0.0), "ryrz");
+		let (u, _) = solve_space_linear_static(&kp_list, &conn_list, &bc_list, &[tip_load([0.0, 0.0, load], [torque, 0.0, 0.0])],
+												poisson_ratio);
+		let conn = &conn_list[0];
+		let deflection:f64 = load * length.powi(3) / (3.0 * conn.e_modulus * conn.inertia);
+		let twist:f64 = torque * length / (shear_modulus(conn, poisson_ratio) * conn.torsion_constant);
+		assert!((u[8] - deflection).abs() < 1e-9 * deflection);
+		assert!((u[9] - twist).abs() < 1e-9 * twist);
+
+		// Column along z. The released local ry and rz are the global ry and rx, and the torsion is about global z.
+		let (kp_list, conn_list, bc_list) = cantilever(space_keypoint("kp2", 0.0, 0.0, length), "ryrz");
+		let (u, _) = solve_space_linear_static(&kp_list, &conn_list, &bc_list, &[tip_load([load, 0.0, 0.0], [0.0, 0.0, torque])],
+												poisson_ratio);
+		assert!((u[6] - deflection).abs() < 1e-9 * deflection);
+		assert!((u[11] - twist).abs() < 1e-9 * twist);
+
+		// Member inclined in the xz-plane. The released local ry is the global ry, but the released local rz
+		// is perpendicular to the member in the xz-plane, with components along the global rx and rz.
+		let (cos, sin) = (30.0_f64.to_radians().cos(), 30.0_f64.to_radians().sin());
+		let (axis, normal) = (Vector3::new(cos, 0.0, sin), Vector3::new(-sin, 0.0, cos));
+		let (kp_list, conn_list, bc_list) = cantilever(space_keypoint("kp2", length * cos, 0.0, length * sin), "ryrz");
+		let (u, _) = solve_space_linear_static(&kp_list, &conn_list, &bc_list,
+												&[tip_load((normal * load).into(), (axis * torque).into())], poisson_ratio);
+		let displacement:Vector3<f64> = Vector3::new(u[6], u[7], u[8]);
+		let rotation:Vector3<f64> = Vector3::new(u[9], u[10], u[11]);
+		assert!((displacement.dot(&normal) - deflection).abs() < 1e-9 * deflection);
+		assert!(displacement.dot(&axis).abs() < 1e-9 * deflection);
+		assert!((rotation.dot(&axis) - twist).abs() < 1e-9 * twist);
+	}
+}
@@ -0,0 +1,42 @@
+// This file creates the force vector at a given time, based on time-varying pointload inputs.
+// Each load history is linearly interpolated between its points, and is zero outside its time range.
+
+use nalgebra::DVector;
+use crate::input::keypoint::Keypoint;
+use crate::input::time_load::TimeLoad;
+use crate::fe_engine::utils::global_stiffness_matrix_keypoint_hashmap;
+
+pub fn create_time_load_vector(kp_list:&[Keypoint], tl_list:&[TimeLoad], time:f64, dofs_per_keypoint:usize) -> DVector<f64> {
+	let kp_map = global_stiffness_matrix_keypoint_hashmap(kp_list, dofs_per_keypoint);
+
+	// Creating vector size based on number of keypoints and dofs per keypoint.
+	let size:usize = dofs_per_keypoint*kp_list.len();
+	let mut tl_vec:DVector<f64> = DVector::from_element(size, 0.0);
+
+	// Finding the unique load histories.
+	let mut names:Vec<&str> = Vec::new();
+	for tl in tl_list {
+		if !names.contains(&tl.name.as_str()) {
+			names.push(tl.name.as_str());
+		}
+	}
+
+	for name in names {
+		let mut history:Vec<&TimeLoad> = tl_list.iter().filter(|tl| tl.name == name).collect();
+		history.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+		// Finding the interval containing the time, and interpolating the load.
+		for pair in history.windows(2) {
+			let (tl_1, tl_2) = (pair[0], pair[1]);
+			if time >= tl_1.time && time <= tl_2.time && tl_2.time > tl_1.time {
+				let factor:f64 = (time - tl_1.time) / (tl_2.time - tl_1.time);
+				let loc_x:usize = kp_map[&tl_1.keypoint];
+				tl_vec[loc_x] += tl_1.load_x + factor * (tl_2.load_x - tl_1.load_x);
+				tl_vec[loc_x+1] += tl_1.load_y + factor * (tl_2.load_y - tl_1.load_y);
+				break;
+			}
+		}
+	}
+
+	tl_vec
+}
@@ -0,0 +1,99 @@
+// This file contains the transient dynamic analysis with HHT-alpha time integration.
+// With alpha = 0 the method reduces to the Newmark average acceleration method (beta = 1/4, gamma = 1/2).
+// Negative alpha (down to -1/3) adds numerical damping of high frequencies.
+//
+// Equation of motion at each time step, solved for the acceleration a_n+1:
+// M*a_n+1 + (1+alpha)*C*v_n+1 - alpha*C*v_n + (1+alpha)*K*u_n+1 - alpha*K*u_n = F_n+alpha
+// Newmark update:
+// u_n+1 = u_n + dt*v_n + dt^2*((1/2-beta)*a_n + beta*a_n+1)
+// v_n+1 = v_n + dt*((1-gamma)*a_n + gamma*a_n+1)
+
+use nalgebra::{DMatrix, DVector};
+use crate::fe_engine::reduced_system::{free_dof_locations, reduce_matrix, reduce_vector, expand_reduced_vector};
+
+pub struct TimeStep {
+	pub time: f64,
+	pub displacement: DVector<f64>, // Global displacement vector.
+	pub force: DVector<f64>, // Global force vector, K*u.
+}
+
+// Time step, end time and alpha parameter of the HHT-alpha time integration.
+pub struct TimeIntegration {
+	pub time_step: f64,
+	pub end_time: f64,
+	pub hht_alpha: f64,
+}
+
+// Rayleigh damping, C = a_0*M + a_1*K.
+pub fn create_rayleigh_damping_matrix(global_mass_matrix:&DMatrix<f64>,
+									  global_stiffness_matrix:&DMatrix<f64>,
+									  mass_coefficient:f64,
+									  stiffness_coefficient:f64) -> DMatrix<f64> {
+	global_mass_matrix * mass_coefficient + global_stiffness_matrix * stiffness_coefficient
+}
+
+pub fn solve_transient_analysis<F>(global_stiffness_matrix:&DMatrix<f64>,
+								   modified_global_stiffness_matrix:&DMatrix<f64>,
+								   global_mass_matrix:&DMatrix<f64>,
+								   global_damping_matrix:&DMatrix<f64>,
+								   dof_filter_vector:&DVector<f64>,
+								   load_at_time:F,
+								   integration:&TimeIntegration) -> Vec<TimeStep>
+	where F: Fn(f64) -> DVector<f64> {
+
+	// HHT-alpha parameters.
+	let hht_alpha:f64 = integration.hht_alpha;
+	let beta:f64 = (1.0 - hht_alpha).powi(2) / 4.0;
+	let gamma:f64 = (1.0 - 2.0 * hht_alpha) / 2.0;
+	let dt:f64 = integration.time_step;
+
+	// Removing the fixed dofs.
+	let size:usize = dof_filter_vector.nrows();
+	let free_dofs = free_dof_locations(dof_filter_vector);
+	let k:DMatrix<f64> = reduce_matrix(modified_global_stiffness_matrix, &free_dofs);
+	let m:DMatrix<f64> = reduce_matrix(global_mass_matrix, &free_dofs);
+	let c:DMatrix<f64> = reduce_matrix(global_damping_matrix, &free_dofs);
+
+	// The effective matrix is constant, and is factorised once.
+	let k_effective:DMatrix<f64> = &m + &c * ((1.0 + hht_alpha) * gamma * dt) + &k * ((1.0 + hht_alpha) * beta * dt * dt);
+	let lu_effective = k_effective.lu();
+
+	// Starting from rest. The initial acceleration follows from M*a_0 = F_0, solved for the dofs with mass.
+	// Massless dofs (e.g. rotations with a lumped mass matrix) have no inertia, and start with zero acceleration.
+	let mut u:DVector<f64> = DVector::zeros(free_dofs.len());
+	let mut v:DVector<f64> = DVector::zeros(free_dofs.len());
+	let mut f_previous:DVector<f64> = reduce_vector(&load_at_time(0.0), &free_dofs);
+	let mass_dofs:Vec<usize> = (0..free_dofs.len()).filter(|&i| m.row(i).iter().any(|value| *value != 0.0)).collect();
+	let a_mass:DVector<f64> = reduce_matrix(&m, &mass_dofs).lu().solve(&reduce_vector(&f_previous, &mass_dofs)).unwrap();
+	let mut a:DVector<f64> = expand_reduced_vector(&a_mass, &mass_dofs, free_dofs.len());
+
+	let mut time_steps:Vec<TimeStep> = Vec::new();
+	let u_global = expand_reduced_vector(&u, &free_dofs, size);
+	time_steps.push(TimeStep {time: 0.0, force: global_stiffness_matrix * &u_global, displacement: u_global});
+
+	let number_of_steps:usize = (integration.end_time / dt).round() as usize;
+
+	for n in 1..=number_of_steps {
+		let time:f64 = n as f64 * dt;
+		let f_current:DVector<f64> = reduce_vector(&load_at_time(time), &free_dofs);
+		let f_alpha:DVector<f64> = &f_current * (1.0 + hht_alpha) - &f_previous * hht_alpha;
+
+		// Predictors.
+		let u_predictor:DVector<f64> = &u + &v * dt + &a * ((0.5 - beta) * dt * dt);
+		let v_predictor:DVector<f64> = &v + &a * ((1.0 - gamma) * dt);
+
+		let rhs:DVector<f64> = f_alpha
+			- &c * (&v_predictor * (1.0 + hht_alpha) - &v * hht_alpha)
+			- &k * (&u_predictor * (1.0 + hht_alpha) - &u * hht_alpha);
+
+		a = lu_effective.solve(&rhs).unwrap();
+		u = u_predictor + &a * (beta * dt * dt);
+		v = v_predictor + &a * (gamma * dt);
+		f_previous = f_current;
+
+		let u_global = expand_reduced_vector(&u, &free_dofs, size);
+		time_steps.push(TimeStep {time, force: global_stiffness_matrix * &u_global, displacement: u_global});
+	}
+
+	time_steps
+}
@@ -1,18 +1,65 @@
 use std::collections::HashMap;
 use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::release::direction_dofs;
 
 // Creating a hashmap the takes in a keypoint name.
 // And returns the row/col number in the global stiffness matrix.
 // Which is the same row in the force and displacement vector.
 // Note that only the first degree of freedom number is returned.
-pub fn global_stiffness_matrix_keypoint_hashmap(kp_list: &[Keypoint]) -> HashMap<String, usize> {
+pub fn global_stiffness_matrix_keypoint_hashmap(kp_list: &[Keypoint], dofs_per_keypoint: usize) -> HashMap<String, usize> {
 	let mut kp_map: HashMap<String, usize> = HashMap::new();	
 	let mut number: usize = 0;
 
 	for kp in kp_list {
 		kp_map.insert(kp.name.clone(), number);
-		number += 2;
+		number += dofs_per_keypoint;
 	}
 
 	kp_map
-}
\ No newline at end of file
+}
+
+// Returning the number of degrees of freedom for each keypoint.
+// Models with bar elements only have 2 (x, y).
+// Models containing frame elements have 3 (x, y, rotation).
+pub fn dofs_per_keypoint(conn_list: &[Connection]) -> usize {
+	if conn_list.iter().any(|conn| conn.element == "frame") {
+		3
+	} else {
+		2
+	}
+}
+
+// Returning the global stiffness matrix locations of all element degrees of freedom.
+// Bar elements contain the x and y dofs of both keypoints.
+// Frame elements additionally contain the rotational dof of both keypoints.
+pub fn element_dof_locations(conn: &Connection, kp_map: &HashMap<String, usize>) -> Vec<usize> {
+	let loc_1 = kp_map[&conn.kp_1];
+	let loc_2 = kp_map[&conn.kp_2];
+
+	if conn.element == "frame" {
+		vec![loc_1, loc_1+1, loc_1+2, loc_2, loc_2+1, loc_2+2]
+	} else {
+		vec![loc_1, loc_1+1, loc_2, loc_2+1]
+	}
+}
+
+// Returning the released local dofs of a 2D frame element, (axial 1, transverse 1, rotation 1, axial 2, transverse 2, rotation 2).
+// Only the rotation (rz) can be released in 2D models. Bar elements have no releases.
+pub fn frame_released_dofs(conn: &Connection) -> Vec<usize> {
+	if conn.element != "frame" {
+		return Vec::new();
+	}
+
+	let mut released_dofs:Vec<usize> = Vec::new();
+	for (end, release) in [&conn.release_1, &conn.release_2].iter().enumerate() {
+		for dof in direction_dofs(release) {
+			if dof != 5 {
+				panic!("Only rz can be released in 2D frame element {}", conn.name);
+			}
+			released_dofs.push(3 * end + 2);
+		}
+	}
+
+	released_dofs
+}
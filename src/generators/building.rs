@@ -0,0 +1,146 @@
+// This file contains a generator for regular 2D building frames.
+// Column lines are placed along the x-axis from x = 0, and storeys along the y-axis from the base at y = 0.
+//
+// Keypoint names:
+// kp<level>_<column line>, with level 0 at the base. Chevron bracing adds a keypoint
+// mid<level>_<bay> in the middle of the beam above the braced bay.
+// Connection names:
+// col = columns, beam = beams, br = bracing.
+//
+// Columns and beams are frame elements with rigid joints. Braces are bar elements.
+// Bracing patterns per braced bay and storey:
+// "none": no bracing (moment frame).
+// "diagonal": a single diagonal from the bottom left to the top right corner.
+// "x": two crossing diagonals, not connected where they cross.
+// "chevron": two braces from the bottom corners to the middle of the beam above (inverted V).
+
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::input::pointload::Pointload;
+use crate::generators::utils::{ConnectionList, create_keypoint, create_boundary_condition, create_pointload};
+
+pub const BRACING_PATTERNS: [&str; 4] = ["none", "diagonal", "x", "chevron"];
+
+pub fn building_keypoint_name(level:usize, column_line:usize) -> String {
+	format!("kp{}_{}", level, column_line)
+}
+
+fn beam_mid_keypoint_name(level:usize, bay:usize) -> String {
+	format!("mid{}_{}", level, bay)
+}
+
+// Generating the keypoints and connections of a building frame.
+// The bracing pattern is applied in all storeys of the braced bays, numbered from 0 at the left.
+pub fn generate_building_frame(bay_widths:&[f64],
+							   storey_heights:&[f64],
+							   bracing:&str,
+							   braced_bays:&[usize],
+							   column_material:&str,
+							   beam_material:&str,
+							   brace_material:&str) -> (Vec<Keypoint>, Vec<Connection>) {
+
+	if !BRACING_PATTERNS.contains(&bracing) {
+		panic!("Unknown bracing pattern {}", bracing);
+	}
+	if bay_widths.is_empty() || storey_heights.is_empty() {
+		panic!("A building frame needs at least 1 bay and 1 storey");
+	}
+	if let Some(bay) = braced_bays.iter().find(|&&bay| bay >= bay_widths.len()) {
+		panic!("Braced bay {} does not exist", bay);
+	}
+
+	// Coordinates of the column lines and levels.
+	let x_list:Vec<f64> = std::iter::once(0.0)
+		.chain(bay_widths.iter().scan(0.0, |x, width| { *x += width; Some(*x) }))
+		.collect();
+	let y_list:Vec<f64> = std::iter::once(0.0)
+		.chain(storey_heights.iter().scan(0.0, |y, height| { *y += height; Some(*y) }))
+		.collect();
+
+	let is_chevron_bay = |bay:usize| bracing == "chevron" && braced_bays.contains(&bay);
+
+	let mut kp_list:Vec<Keypoint> = Vec::new();
+	let mut conn_list = ConnectionList::default();
+
+	for (level, &y) in y_list.iter().enumerate() {
+		for (column_line, &x) in x_list.iter().enumerate() {
+			kp_list.push(create_keypoint(&building_keypoint_name(level, column_line), x, y));
+		}
+	}
+
+	for (level, &y) in y_list.iter().enumerate().skip(1) {
+		for column_line in 0..x_list.len() {
+			conn_list.add("col", &building_keypoint_name(level-1, column_line), &building_keypoint_name(level, column_line), column_material, "frame");
+		}
+
+		// Beams above chevron braced bays are split at the brace connection.
+		for bay in 0..bay_widths.len() {
+			let left:String = building_keypoint_name(level, bay);
+			let right:String = building_keypoint_name(level, bay+1);
+			if is_chevron_bay(bay) {
+				let mid:String = beam_mid_keypoint_name(level, bay);
+				kp_list.push(create_keypoint(&mid, (x_list[bay] + x_list[bay+1]) / 2.0, y));
+				conn_list.add("beam", &left, &mid, beam_material, "frame");
+				conn_list.add("beam", &mid, &right, beam_material, "frame");
+			} else {
+				conn_list.add("beam", &left, &right, beam_material, "frame");
+			}
+		}
+
+		for &bay in braced_bays {
+			let bottom_left:String = building_keypoint_name(level-1, bay);
+			let bottom_right:String = building_keypoint_name(level-1, bay+1);
+			let top_left:String = building_keypoint_name(level, bay);
+			let top_right:String = building_keypoint_name(level, bay+1);
+
+			match bracing {
+				"diagonal" => {
+					conn_list.add("br", &bottom_left, &top_right, brace_material, "bar");
+				},
+				"x" => {
+					conn_list.add("br", &bottom_left, &top_right, brace_material, "bar");
+					conn_list.add("br", &bottom_right, &top_left, brace_material, "bar");
+				},
+				"chevron" => {
+					let mid:String = beam_mid_keypoint_name(level, bay);
+					conn_list.add("br", &bottom_left, &mid, brace_material, "bar");
+					conn_list.add("br", &bottom_right, &mid, brace_material, "bar");
+				},
+				_ => {},
+			}
+		}
+	}
+
+	(kp_list, conn_list.connections)
+}
+
+// Generating fixed base supports (x, y and rotation) at all column lines.
+pub fn generate_building_supports(bay_widths:&[f64]) -> Vec<BoundaryCondition> {
+	(0..=bay_widths.len())
+		.map(|column_line| create_boundary_condition(&format!("bc{}", column_line+1), &building_keypoint_name(0, column_line), "4"))
+		.collect()
+}
+
+// Generating storey loads as pointloads in the beam-column joints above the base.
+// The floor load (force per length, downwards) is lumped onto the column lines by their tributary width.
+// The horizontal storey load (force per storey, in positive x-direction) is applied at the leftmost column line.
+pub fn generate_storey_loads(bay_widths:&[f64],
+							 storey_heights:&[f64],
+							 floor_load:f64,
+							 storey_load:f64) -> Vec<Pointload> {
+	let mut pl_list:Vec<Pointload> = Vec::new();
+
+	for level in 1..=storey_heights.len() {
+		for column_line in 0..=bay_widths.len() {
+			let left_width:f64 = if column_line > 0 { bay_widths[column_line-1] } else { 0.0 };
+			let right_width:f64 = if column_line < bay_widths.len() { bay_widths[column_line] } else { 0.0 };
+			let load_x:f64 = if column_line == 0 { storey_load } else { 0.0 };
+			let load_y:f64 = -floor_load * (left_width + right_width) / 2.0;
+
+			pl_list.push(create_pointload(&format!("p{}_{}", level, column_line), &building_keypoint_name(level, column_line), load_x, load_y));
+		}
+	}
+
+	pl_list
+}
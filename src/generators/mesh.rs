@@ -0,0 +1,593 @@
+// This file contains a 2D mesh generator for the surface elements of plane models.
+// Regions are outlined by existing keypoints, and filled with surface elements of a target size.
+//
+// Keypoint names:
+// <region>_kp<number>. Generated keypoints at the location of an existing keypoint are merged with it,
+// so the mesh connects to the outline keypoints, to neighbouring regions and to the connections.
+// Element names:
+// <region>_e<number>.
+//
+// "quad": structured mesh of Q4 elements. The 4 outline keypoints are mapped bilinearly to a grid.
+// "tri": Delaunay mesh of CST elements within a simple polygon (Bowyer-Watson algorithm).
+//
+// The element size can be refined near keypoints. The size grows linearly from the refined size at the keypoint
+// to the region size at the refinement radius. Each outline edge is divided by the size field along that edge only,
+// and existing keypoints on the edge, e.g. from a neighbouring region that is already meshed, are kept as divisions.
+// Neighbouring regions with the same element size and refinements therefore share the keypoints along common edges.
+// Meshes that do not share the keypoints along a common edge are rejected when they are added to the model.
+
+use std::collections::{HashMap, HashSet};
+use crate::input::keypoint::Keypoint;
+use crate::input::surface_element::SurfaceElement;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::input::mesh_region::{MeshRegion, MeshRefinement, EdgeBoundaryCondition};
+use crate::generators::utils::{create_keypoint, create_surface_element};
+
+// Keypoints closer than the tolerance are merged, and keypoints closer than the tolerance to an edge lie on the edge.
+const MESH_TOLERANCE: f64 = 1e-6;
+// Number of samples of the size field along an edge, used for grading the element divisions.
+const GRADING_SAMPLES: usize = 200;
+// Elements up to this fraction larger than the local element size are accepted when counting the edge divisions.
+// Keeps the division count of the part of an edge between two existing keypoints from rounding up.
+const GRADING_TOLERANCE: f64 = 1e-3;
+// Interior keypoints of triangle meshes are kept at these fractions of the element size from
+// other keypoints and from the outline.
+const KEYPOINT_SPACING_FACTOR: f64 = 0.85;
+const OUTLINE_SPACING_FACTOR: f64 = 0.6;
+// Maximum number of boundary edge splits when recovering the outline of triangle meshes.
+const BOUNDARY_RECOVERY_ITERATIONS: usize = 20;
+
+type Point = (f64, f64);
+
+fn mesh_keypoint_name(region:&MeshRegion, number:usize) -> String {
+	format!("{}_kp{}", region.name, number)
+}
+
+fn mesh_element_name(region:&MeshRegion, number:usize) -> String {
+	format!("{}_e{}", region.name, number)
+}
+
+fn keypoint_location(name:&str, kp_list:&[Keypoint]) -> Point {
+	let kp = kp_list.iter().find(|kp| kp.name == name).unwrap_or_else(|| panic!("Unknown mesh keypoint {}", name));
+	(kp.x, kp.y)
+}
+
+fn distance(a:Point, b:Point) -> f64 {
+	((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+}
+
+fn interpolate(a:Point, b:Point, s:f64) -> Point {
+	(a.0 + (b.0 - a.0) * s, a.1 + (b.1 - a.1) * s)
+}
+
+fn distance_to_segment(p:Point, a:Point, b:Point) -> f64 {
+	let length_squared:f64 = (b.0 - a.0).powi(2) + (b.1 - a.1).powi(2);
+	if length_squared == 0.0 {
+		return distance(p, a);
+	}
+	let s:f64 = (((p.0 - a.0) * (b.0 - a.0) + (p.1 - a.1) * (b.1 - a.1)) / length_squared).clamp(0.0, 1.0);
+	distance(p, interpolate(a, b, s))
+}
+
+// Ray casting test for a point inside a simple polygon.
+fn point_in_polygon(p:Point, polygon:&[Point]) -> bool {
+	let mut inside:bool = false;
+	for i in 0..polygon.len() {
+		let a = polygon[i];
+		let b = polygon[(i + 1) % polygon.len()];
+		if (a.1 > p.1) != (b.1 > p.1) && p.0 < a.0 + (p.1 - a.1) * (b.0 - a.0) / (b.1 - a.1) {
+			inside = !inside;
+		}
+	}
+	inside
+}
+
+// Twice the signed area of a triangle. Positive for counter-clockwise triangles.
+fn twice_signed_area(a:Point, b:Point, c:Point) -> f64 {
+	(b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1)
+}
+
+// Element size field of a region, with the refinement keypoints resolved to their locations.
+struct SizeField {
+	element_size: f64,
+	refinements: Vec<(Point, f64, f64)>, // Location, element size and radius.
+}
+
+impl SizeField {
+	fn new(region:&MeshRegion, refinements:&[MeshRefinement], kp_list:&[Keypoint]) -> SizeField {
+		SizeField {
+			element_size: region.element_size,
+			refinements: refinements.iter()
+				.map(|refinement| (keypoint_location(&refinement.keypoint, kp_list), refinement.element_size, refinement.radius))
+				.collect(),
+		}
+	}
+
+	fn size_at(&self, p:Point) -> f64 {
+		self.refinements.iter().fold(self.element_size, |size, (location, refined_size, radius)| {
+			let d:f64 = distance(p, *location);
+			if d < *radius {
+				size.min(refined_size + (self.element_size - refined_size) * d / radius)
+			} else {
+				size
+			}
+		})
+	}
+
+	fn min_size(&self) -> f64 {
+		self.refinements.iter().fold(self.element_size, |size, (_, refined_size, _)| size.min(*refined_size))
+	}
+}
+
+// Cumulative number of elements along an edge (0 to 1), for an element size given along the edge.
+// The integral of length / size is sampled with the trapezoidal rule.
+fn cumulative_divisions(length:f64, size_at:impl Fn(f64) -> f64) -> Vec<f64> {
+	let density = |s:f64| length / size_at(s);
+
+	let mut cumulative:Vec<f64> = vec![0.0; GRADING_SAMPLES + 1];
+	for i in 1..=GRADING_SAMPLES {
+		let s_0:f64 = (i - 1) as f64 / GRADING_SAMPLES as f64;
+		let s_1:f64 = i as f64 / GRADING_SAMPLES as f64;
+		cumulative[i] = cumulative[i-1] + (density(s_0) + density(s_1)) / 2.0 / GRADING_SAMPLES as f64;
+	}
+	cumulative
+}
+
+// Number of elements along an edge, the integral of length / size rounded up.
+fn graded_division_count(cumulative:&[f64]) -> usize {
+	((cumulative[GRADING_SAMPLES] - GRADING_TOLERANCE).ceil() as usize).max(1)
+}
+
+// Returning the parameters (0 to 1) of the element divisions along an edge. The divisions split the integral of
+// length / size into equal parts, so the elements follow the size field. Reversing the edge gives the same divisions.
+fn graded_parameters(cumulative:&[f64], divisions:usize) -> Vec<f64> {
+	let total:f64 = cumulative[GRADING_SAMPLES];
+
+	(0..=divisions).map(|k| {
+		if k == 0 {
+			return 0.0;
+		}
+		if k == divisions {
+			return 1.0;
+		}
+		let target:f64 = total * k as f64 / divisions as f64;
+		let i:usize = cumulative.partition_point(|value| *value < target);
+		let fraction:f64 = (target - cumulative[i-1]) / (cumulative[i] - cumulative[i-1]);
+		((i - 1) as f64 + fraction) / GRADING_SAMPLES as f64
+	}).collect()
+}
+
+// Element divisions of an outline edge from a to b.
+struct EdgeDivisions {
+	parameters: Vec<f64>, // Parameters (0 to 1) of the division keypoints, including both ends.
+	fixed: bool, // The edge has existing keypoints, so the divisions can not be changed.
+}
+
+// Dividing an outline edge by the size field along the edge. Existing keypoints on the edge are kept as divisions,
+// and the parts of the edge between them are graded separately.
+fn divide_edge(a:Point, b:Point, size_field:&SizeField, kp_list:&[Keypoint]) -> EdgeDivisions {
+	let length:f64 = distance(a, b);
+
+	let mut fixed_parameters:Vec<f64> = kp_list.iter()
+		.map(|kp| (kp.x, kp.y))
+		.filter(|p| distance_to_segment(*p, a, b) < MESH_TOLERANCE)
+		.map(|p| ((p.0 - a.0) * (b.0 - a.0) + (p.1 - a.1) * (b.1 - a.1)) / length.powi(2))
+		.filter(|s| *s * length > MESH_TOLERANCE && (1.0 - s) * length > MESH_TOLERANCE)
+		.collect();
+	fixed_parameters.sort_by(|x, y| x.total_cmp(y));
+	fixed_parameters.dedup_by(|x, y| (*x - *y) * length < MESH_TOLERANCE);
+
+	let ends:Vec<f64> = std::iter::once(0.0).chain(fixed_parameters.iter().cloned()).chain(std::iter::once(1.0)).collect();
+	let mut parameters:Vec<f64> = vec![0.0];
+	for part in ends.windows(2) {
+		let (s_0, s_1) = (part[0], part[1]);
+		let cumulative = cumulative_divisions((s_1 - s_0) * length, |s| size_field.size_at(interpolate(a, b, s_0 + (s_1 - s_0) * s)));
+		let part_parameters = graded_parameters(&cumulative, graded_division_count(&cumulative));
+		parameters.extend(part_parameters[1..].iter().map(|s| s_0 + (s_1 - s_0) * s));
+	}
+
+	EdgeDivisions {parameters, fixed: !fixed_parameters.is_empty()}
+}
+
+// Giving two opposite edges of a quad region the same number of divisions, as needed for a structured mesh.
+// A free edge is re-graded with the division count of the other edge, or with the larger count when both are free.
+fn match_opposite_edges(region:&MeshRegion, edge_1:&mut EdgeDivisions, edge_2:&mut EdgeDivisions,
+						regrade:&impl Fn(usize, usize) -> Vec<f64>, edges:(usize, usize)) {
+	let (divisions_1, divisions_2) = (edge_1.parameters.len() - 1, edge_2.parameters.len() - 1);
+	if divisions_1 == divisions_2 {
+		return;
+	}
+	match (edge_1.fixed, edge_2.fixed) {
+		(true, true) => panic!("Opposite edges of quad mesh region {} have {} and {} divisions from existing keypoints",
+							   region.name, divisions_1, divisions_2),
+		(true, false) => edge_2.parameters = regrade(edges.1, divisions_1),
+		(false, true) => edge_1.parameters = regrade(edges.0, divisions_2),
+		(false, false) if divisions_1 > divisions_2 => edge_2.parameters = regrade(edges.1, divisions_1),
+		(false, false) => edge_1.parameters = regrade(edges.0, divisions_2),
+	}
+}
+
+// Generating the mesh of a region, returning the mesh keypoints and surface elements.
+pub fn generate_mesh(region:&MeshRegion, kp_list:&[Keypoint], refinements:&[MeshRefinement]) -> (Vec<Keypoint>, Vec<SurfaceElement>) {
+	match region.mesh_type.as_str() {
+		"quad" => generate_quad_mesh(region, kp_list, refinements),
+		"tri" => generate_tri_mesh(region, kp_list, refinements),
+		_ => panic!("Unknown mesh type {} for mesh region {}", region.mesh_type, region.name),
+	}
+}
+
+// Structured mesh of Q4 elements. With the outline keypoints c1..c4, the region is mapped from (s, t) in 0..1 as
+// p(s, t) = (1-s)(1-t)·c1 + s(1-t)·c2 + st·c3 + (1-s)t·c4.
+// Each edge is divided by the size field along the edge, and opposite edges get the same number of divisions.
+// The grid lines join the divisions of opposite edges, and the keypoints are placed at their intersections.
+pub fn generate_quad_mesh(region:&MeshRegion, kp_list:&[Keypoint], refinements:&[MeshRefinement]) -> (Vec<Keypoint>, Vec<SurfaceElement>) {
+	let c:Vec<Point> = region.outline.iter().map(|name| keypoint_location(name, kp_list)).collect();
+	let size_field = SizeField::new(region, refinements, kp_list);
+
+	let map = |s:f64, t:f64| -> Point {
+		let weights = [(1.0 - s) * (1.0 - t), s * (1.0 - t), s * t, (1.0 - s) * t];
+		(weights.iter().zip(&c).map(|(w, p)| w * p.0).sum(), weights.iter().zip(&c).map(|(w, p)| w * p.1).sum())
+	};
+
+	// Edges in the directions of s and t: bottom c1-c2, top c4-c3, left c1-c4 and right c2-c3.
+	let edges:[(Point, Point); 4] = [(c[0], c[1]), (c[3], c[2]), (c[0], c[3]), (c[1], c[2])];
+	let [mut bottom, mut top, mut left, mut right] = edges.map(|(a, b)| divide_edge(a, b, &size_field, kp_list));
+	let regrade = |edge:usize, count:usize| {
+		let (a, b) = edges[edge];
+		graded_parameters(&cumulative_divisions(distance(a, b), |s| size_field.size_at(interpolate(a, b, s))), count)
+	};
+	match_opposite_edges(region, &mut bottom, &mut top, &regrade, (0, 1));
+	match_opposite_edges(region, &mut left, &mut right, &regrade, (2, 3));
+	let (s_bottom, s_top) = (&bottom.parameters, &top.parameters);
+	let (t_left, t_right) = (&left.parameters, &right.parameters);
+
+	// Intersection of the grid lines s = s_bottom + (s_top - s_bottom)·t and t = t_left + (t_right - t_left)·s.
+	let grid_point = |i:usize, j:usize| -> Point {
+		let (ds, dt) = (s_top[i] - s_bottom[i], t_right[j] - t_left[j]);
+		let s:f64 = (s_bottom[i] + ds * t_left[j]) / (1.0 - ds * dt);
+		map(s, t_left[j] + dt * s)
+	};
+
+	let mut mesh_kp_list:Vec<Keypoint> = Vec::new();
+	for j in 0..t_left.len() {
+		for i in 0..s_bottom.len() {
+			let (x, y) = grid_point(i, j);
+			mesh_kp_list.push(create_keypoint(&mesh_keypoint_name(region, mesh_kp_list.len() + 1), x, y));
+		}
+	}
+
+	let kp_name = |i:usize, j:usize| mesh_kp_list[j * s_bottom.len() + i].name.clone();
+	let mut mesh_surface_list:Vec<SurfaceElement> = Vec::new();
+	for j in 0..t_left.len() - 1 {
+		for i in 0..s_bottom.len() - 1 {
+			let keypoints = [kp_name(i, j), kp_name(i+1, j), kp_name(i+1, j+1), kp_name(i, j+1)];
+			mesh_surface_list.push(create_surface_element(&mesh_element_name(region, mesh_surface_list.len() + 1), &keypoints,
+														  &region.material, &region.formulation, region.thickness, region.poisson_ratio));
+		}
+	}
+
+	(mesh_kp_list, mesh_surface_list)
+}
+
+// Delaunay mesh of CST elements.
+// 1. The outline edges are divided following the size field along each edge, keeping existing keypoints on the edges.
+// 2. Interior keypoints are taken from a fine triangular lattice, smallest element size first, keeping the
+//    local element size to other keypoints and the outline.
+// 3. The keypoints are triangulated, and triangles with the centroid outside the outline are removed.
+// 4. Outline edges that are missing from the triangulation are split at the midpoint until all are present.
+pub fn generate_tri_mesh(region:&MeshRegion, kp_list:&[Keypoint], refinements:&[MeshRefinement]) -> (Vec<Keypoint>, Vec<SurfaceElement>) {
+	let outline:Vec<Point> = region.outline.iter().map(|name| keypoint_location(name, kp_list)).collect();
+	let size_field = SizeField::new(region, refinements, kp_list);
+
+	let mut points:Vec<Point> = Vec::new();
+	for i in 0..outline.len() {
+		let a = outline[i];
+		let b = outline[(i + 1) % outline.len()];
+		let parameters = divide_edge(a, b, &size_field, kp_list).parameters;
+		for &s in &parameters[..parameters.len() - 1] {
+			points.push(interpolate(a, b, s));
+		}
+	}
+	let mut segments:Vec<(usize, usize)> = (0..points.len()).map(|i| (i, (i + 1) % points.len())).collect();
+
+	let interior_points = generate_interior_points(&outline, &points, &size_field);
+	points.extend(interior_points);
+
+	let mut iteration:usize = 0;
+	let triangles:Vec<[usize; 3]> = loop {
+		let triangles:Vec<[usize; 3]> = delaunay_triangulation(&points).into_iter()
+			.filter(|[a, b, c]| {
+				let centroid = ((points[*a].0 + points[*b].0 + points[*c].0) / 3.0, (points[*a].1 + points[*b].1 + points[*c].1) / 3.0);
+				point_in_polygon(centroid, &outline)
+			})
+			.collect();
+
+		let edges:HashSet<(usize, usize)> = triangles.iter()
+			.flat_map(|[a, b, c]| [(*a, *b), (*b, *c), (*c, *a)])
+			.map(|(a, b)| (a.min(b), a.max(b)))
+			.collect();
+		let missing:Vec<usize> = (0..segments.len())
+			.filter(|i| !edges.contains(&(segments[*i].0.min(segments[*i].1), segments[*i].0.max(segments[*i].1))))
+			.collect();
+
+		if missing.is_empty() {
+			break triangles;
+		}
+		if iteration == BOUNDARY_RECOVERY_ITERATIONS {
+			panic!("The outline of mesh region {} could not be recovered. Check that the outline is a simple polygon", region.name);
+		}
+		for &i in missing.iter().rev() {
+			let (a, b) = segments[i];
+			points.push(interpolate(points[a], points[b], 0.5));
+			segments[i] = (a, points.len() - 1);
+			segments.insert(i + 1, (points.len() - 1, b));
+		}
+		iteration += 1;
+	};
+
+	let mesh_kp_list:Vec<Keypoint> = points.iter().enumerate()
+		.map(|(i, (x, y))| create_keypoint(&mesh_keypoint_name(region, i + 1), *x, *y))
+		.collect();
+
+	// Keypoints that are not used by any triangle are left out.
+	let used:HashSet<usize> = triangles.iter().flatten().cloned().collect();
+	let mesh_surface_list:Vec<SurfaceElement> = triangles.iter().enumerate().map(|(i, triangle)| {
+		let keypoints:Vec<String> = triangle.iter().map(|point| mesh_kp_list[*point].name.clone()).collect();
+		create_surface_element(&mesh_element_name(region, i + 1), &keypoints, &region.material, &region.formulation,
+							   region.thickness, region.poisson_ratio)
+	}).collect();
+
+	let mesh_kp_list:Vec<Keypoint> = mesh_kp_list.into_iter().enumerate()
+		.filter(|(i, _)| used.contains(i))
+		.map(|(_, kp)| kp)
+		.collect();
+
+	(mesh_kp_list, mesh_surface_list)
+}
+
+// Interior keypoints of a triangle mesh, selected from a triangular lattice with half the smallest element size.
+// Lattice points are visited from the smallest local element size, and kept when they are far enough
+// from the outline and from the keypoints already placed.
+fn generate_interior_points(outline:&[Point], boundary_points:&[Point], size_field:&SizeField) -> Vec<Point> {
+	let spacing:f64 = size_field.min_size() / 2.0;
+	let row_height:f64 = spacing * 3.0_f64.sqrt() / 2.0;
+
+	let min_x:f64 = outline.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+	let max_x:f64 = outline.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+	let min_y:f64 = outline.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+	let max_y:f64 = outline.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+	let columns:usize = ((max_x - min_x) / spacing).ceil() as usize + 1;
+	let rows:usize = ((max_y - min_y) / row_height).ceil() as usize + 1;
+
+	let mut candidates:Vec<(Point, f64)> = Vec::new();
+	for row in 0..rows {
+		let offset:f64 = if row % 2 == 1 { spacing / 2.0 } else { 0.0 };
+		for column in 0..columns {
+			let p:Point = (min_x + offset + column as f64 * spacing, min_y + row as f64 * row_height);
+			if point_in_polygon(p, outline) {
+				candidates.push((p, size_field.size_at(p)));
+			}
+		}
+	}
+	candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+	let mut interior_points:Vec<Point> = Vec::new();
+	for (p, size) in candidates {
+		let near_outline:bool = (0..outline.len())
+			.any(|i| distance_to_segment(p, outline[i], outline[(i + 1) % outline.len()]) < OUTLINE_SPACING_FACTOR * size);
+		if near_outline {
+			continue;
+		}
+		let near_keypoint:bool = boundary_points.iter().chain(interior_points.iter())
+			.any(|q| distance(p, *q) < KEYPOINT_SPACING_FACTOR * size);
+		if !near_keypoint {
+			interior_points.push(p);
+		}
+	}
+
+	interior_points
+}
+
+// Returning the Delaunay triangulation of the points as counter-clockwise triangles of point numbers.
+// Bowyer-Watson algorithm: the points are inserted one by one into a triangulation that starts as a large
+// super triangle. The triangles whose circumcircle contains the new point are removed, and the cavity
+// is filled with triangles from its edges to the new point. Triangles with super triangle vertices are removed at the end.
+pub fn delaunay_triangulation(points:&[Point]) -> Vec<[usize; 3]> {
+	let min_x:f64 = points.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+	let max_x:f64 = points.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+	let min_y:f64 = points.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+	let max_y:f64 = points.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+	let size:f64 = (max_x - min_x).max(max_y - min_y).max(MESH_TOLERANCE);
+	let centre:Point = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+	let n:usize = points.len();
+	let mut vertices:Vec<Point> = points.to_vec();
+	vertices.push((centre.0 - 100.0 * size, centre.1 - 100.0 * size));
+	vertices.push((centre.0 + 100.0 * size, centre.1 - 100.0 * size));
+	vertices.push((centre.0, centre.1 + 100.0 * size));
+
+	// Triangles with their circumcircle centre and squared radius.
+	let circumcircle = |[a, b, c]:[usize; 3]| -> (Point, f64) {
+		let (pa, pb, pc) = (vertices[a], vertices[b], vertices[c]);
+		let d:f64 = 2.0 * twice_signed_area(pa, pb, pc);
+		let a_2:f64 = pa.0 * pa.0 + pa.1 * pa.1;
+		let b_2:f64 = pb.0 * pb.0 + pb.1 * pb.1;
+		let c_2:f64 = pc.0 * pc.0 + pc.1 * pc.1;
+		let x:f64 = (a_2 * (pb.1 - pc.1) + b_2 * (pc.1 - pa.1) + c_2 * (pa.1 - pb.1)) / d;
+		let y:f64 = (a_2 * (pc.0 - pb.0) + b_2 * (pa.0 - pc.0) + c_2 * (pb.0 - pa.0)) / d;
+		((x, y), (pa.0 - x).powi(2) + (pa.1 - y).powi(2))
+	};
+
+	let mut triangles:Vec<([usize; 3], Point, f64)> = Vec::new();
+	let (centre, radius_squared) = circumcircle([n, n+1, n+2]);
+	triangles.push(([n, n+1, n+2], centre, radius_squared));
+
+	for (index, p) in points.iter().enumerate() {
+		let (bad, good):(Vec<_>, Vec<_>) = triangles.into_iter()
+			.partition(|(_, centre, radius_squared)| (p.0 - centre.0).powi(2) + (p.1 - centre.1).powi(2) < *radius_squared);
+		triangles = good;
+
+		// Edges of the cavity are the edges of exactly one removed triangle.
+		let mut edge_count:HashMap<(usize, usize), usize> = HashMap::new();
+		for ([a, b, c], _, _) in &bad {
+			for (i, j) in [(*a, *b), (*b, *c), (*c, *a)] {
+				*edge_count.entry((i.min(j), i.max(j))).or_insert(0) += 1;
+			}
+		}
+		for ([a, b, c], _, _) in &bad {
+			for (i, j) in [(*a, *b), (*b, *c), (*c, *a)] {
+				if edge_count[&(i.min(j), i.max(j))] == 1 {
+					let triangle = [i, j, index];
+					let (centre, radius_squared) = circumcircle(triangle);
+					triangles.push((triangle, centre, radius_squared));
+				}
+			}
+		}
+	}
+
+	triangles.into_iter()
+		.map(|(triangle, _, _)| triangle)
+		.filter(|triangle| triangle.iter().all(|vertex| *vertex < n))
+		.map(|[a, b, c]| if twice_signed_area(points[a], points[b], points[c]) > 0.0 { [a, b, c] } else { [a, c, b] })
+		.collect()
+}
+
+// Adding a generated mesh to the model. Mesh keypoints at the location of an existing keypoint are
+// replaced by the existing keypoint in the mesh elements.
+// Panics when a surface element keypoint lies within the outer edge of another element, since the elements
+// along that edge would not be connected, e.g. when neighbouring regions divide a common edge differently.
+pub fn add_mesh_to_model(kp_list:&mut Vec<Keypoint>,
+						 surface_list:&mut Vec<SurfaceElement>,
+						 mesh_kp_list:Vec<Keypoint>,
+						 mesh_surface_list:Vec<SurfaceElement>) {
+
+	let mut merged_names:HashMap<String, String> = HashMap::new();
+	for mesh_kp in mesh_kp_list {
+		match kp_list.iter().find(|kp| distance((kp.x, kp.y), (mesh_kp.x, mesh_kp.y)) < MESH_TOLERANCE) {
+			Some(kp) => {
+				merged_names.insert(mesh_kp.name, kp.name.clone());
+			},
+			None => kp_list.push(mesh_kp),
+		}
+	}
+
+	for mut surface in mesh_surface_list {
+		for name in surface.keypoints.iter_mut() {
+			if let Some(merged_name) = merged_names.get(name) {
+				*name = merged_name.clone();
+			}
+		}
+		surface_list.push(surface);
+	}
+
+	check_unconnected_edges(kp_list, surface_list);
+}
+
+// Checking that no surface element keypoint lies within an edge that belongs to a single surface element.
+fn check_unconnected_edges(kp_list:&[Keypoint], surface_list:&[SurfaceElement]) {
+	let mut edge_count:HashMap<(&str, &str), usize> = HashMap::new();
+	for surface in surface_list {
+		for i in 0..surface.keypoints.len() {
+			let (a, b) = (surface.keypoints[i].as_str(), surface.keypoints[(i + 1) % surface.keypoints.len()].as_str());
+			*edge_count.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+		}
+	}
+
+	let surface_keypoints:HashSet<&str> = surface_list.iter().flat_map(|surface| surface.keypoints.iter().map(|name| name.as_str())).collect();
+	let locations:Vec<(&str, Point)> = kp_list.iter()
+		.filter(|kp| surface_keypoints.contains(kp.name.as_str()))
+		.map(|kp| (kp.name.as_str(), (kp.x, kp.y)))
+		.collect();
+
+	for ((a, b), _) in edge_count.iter().filter(|(_, count)| **count == 1) {
+		let (p_a, p_b) = (keypoint_location(a, kp_list), keypoint_location(b, kp_list));
+		for (name, p) in &locations {
+			if distance_to_segment(*p, p_a, p_b) < MESH_TOLERANCE && distance(*p, p_a) > MESH_TOLERANCE && distance(*p, p_b) > MESH_TOLERANCE {
+				panic!("Keypoint {} lies within the element edge {}-{} without being connected to it. \
+						Check that neighbouring mesh regions divide their common edges the same way", name, a, b);
+			}
+		}
+	}
+}
+
+// Returning a boundary condition for each keypoint on the straight edge between the two keypoints of an edge bc.
+// The boundary conditions are named <edge bc>_<keypoint>.
+pub fn generate_edge_boundary_conditions(edge_bc_list:&[EdgeBoundaryCondition], kp_list:&[Keypoint]) -> Vec<BoundaryCondition> {
+	let mut bc_list:Vec<BoundaryCondition> = Vec::new();
+
+	for edge_bc in edge_bc_list {
+		let a = keypoint_location(&edge_bc.keypoint_1, kp_list);
+		let b = keypoint_location(&edge_bc.keypoint_2, kp_list);
+		for kp in kp_list.iter().filter(|kp| distance_to_segment((kp.x, kp.y), a, b) < MESH_TOLERANCE) {
+			bc_list.push(BoundaryCondition {name: format!("{}_{}", edge_bc.name, kp.name), keypoint: kp.name.clone(),
+											fixture: edge_bc.fixture.clone(), spring_stiffness: edge_bc.spring_stiffness});
+		}
+	}
+
+	bc_list
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn region(name:&str, mesh_type:&str, element_size:f64, outline:&[&str]) -> MeshRegion {
+		MeshRegion {name: name.to_string(), mesh_type: mesh_type.to_string(), element_size, material: "concrete".to_string(),
+					formulation: "plane_stress".to_string(), thickness: 0.3, poisson_ratio: 0.2,
+					outline: outline.iter().map(|name| name.to_string()).collect()}
+	}
+
+	// Column and corbel of the plane mesh example, sharing the edge c4-c3, with a refinement
+	// that is not at a corner of the shared edge.
+	fn column_and_corbel() -> Vec<Keypoint> {
+		vec![create_keypoint("c1", 0.0, 0.0), create_keypoint("c2", 1.0, 0.0), create_keypoint("c3", 1.0, 2.0),
+			 create_keypoint("c4", 0.0, 2.0), create_keypoint("b1", 3.0, 2.6), create_keypoint("b2", 3.0, 3.0),
+			 create_keypoint("b3", 0.0, 3.0), create_keypoint("r", 0.5, 1.2)]
+	}
+
+	// Meshing the regions in order, and returning the keypoints and surface elements of the model.
+	fn mesh_regions(regions:&[MeshRegion], refinements:&[MeshRefinement]) -> (Vec<Keypoint>, Vec<SurfaceElement>) {
+		let mut kp_list = column_and_corbel();
+		let mut surface_list:Vec<SurfaceElement> = Vec::new();
+		for region in regions {
+			let (mesh_kp_list, mesh_surface_list) = generate_mesh(region, &kp_list, refinements);
+			add_mesh_to_model(&mut kp_list, &mut surface_list, mesh_kp_list, mesh_surface_list);
+		}
+		(kp_list, surface_list)
+	}
+
+	// Names of the keypoints on the edge c4-c3 that are used by the elements of a region.
+	fn shared_edge_keypoints(kp_list:&[Keypoint], surface_list:&[SurfaceElement], region:&str) -> HashSet<String> {
+		surface_list.iter()
+			.filter(|surface| surface.name.starts_with(&format!("{}_", region)))
+			.flat_map(|surface| surface.keypoints.iter().cloned())
+			.filter(|name| {
+				let p = keypoint_location(name, kp_list);
+				distance_to_segment(p, (0.0, 2.0), (1.0, 2.0)) < MESH_TOLERANCE
+			})
+			.collect()
+	}
+
+	#[test]
+	fn neighbouring_regions_share_the_keypoints_of_a_common_edge() {
+		let refinements = vec![MeshRefinement {keypoint: "r".to_string(), element_size: 0.05, radius: 1.0}];
+		let column = || region("column", "quad", 0.2, &["c1", "c2", "c3", "c4"]);
+		let corbel = || region("corbel", "tri", 0.2, &["c4", "c3", "b1", "b2", "b3"]);
+
+		// The keypoints are shared in both meshing orders.
+		for regions in [[column(), corbel()], [corbel(), column()]] {
+			let (kp_list, surface_list) = mesh_regions(&regions, &refinements);
+			let column_keypoints = shared_edge_keypoints(&kp_list, &surface_list, "column");
+			assert!(column_keypoints.len() > 2);
+			assert_eq!(column_keypoints, shared_edge_keypoints(&kp_list, &surface_list, "corbel"));
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "without being connected")]
+	fn regions_with_different_divisions_of_a_common_edge_are_rejected() {
+		let column = region("column", "quad", 0.5, &["c1", "c2", "c3", "c4"]);
+		let corbel = region("corbel", "tri", 0.1, &["c4", "c3", "b1", "b2", "b3"]);
+		mesh_regions(&[column, corbel], &[]);
+	}
+}
@@ -0,0 +1,131 @@
+// This file contains generators for standard plane trusses.
+// The trusses span along the x-axis with the bottom chord at y = 0, and are made of bar elements.
+//
+// Keypoint names:
+// b0..bn = bottom chord, t0..tn = top chord, m1..m(n-1) = mid-height keypoints of the K-truss.
+// Connection names:
+// bot = bottom chord, top = top chord, ver = verticals, dia = diagonals.
+//
+// Pratt: verticals and diagonals sloping down towards midspan (diagonals in tension under gravity loads).
+// Howe: verticals and diagonals sloping up towards midspan (diagonals in compression under gravity loads).
+// Warren: no verticals. The top chord keypoints are placed at the middle of each panel.
+// K: verticals split at mid-height, with two diagonals from the mid-height keypoint to the chords of the next vertical outwards.
+
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::generators::utils::{ConnectionList, create_keypoint, create_boundary_condition};
+
+pub const TRUSS_TYPES: [&str; 4] = ["pratt", "howe", "warren", "k"];
+
+pub fn bottom_keypoint_name(i:usize) -> String {
+	format!("b{}", i)
+}
+
+pub fn top_keypoint_name(i:usize) -> String {
+	format!("t{}", i)
+}
+
+fn mid_keypoint_name(i:usize) -> String {
+	format!("m{}", i)
+}
+
+// Generating the keypoints and connections of a truss.
+// The chord material is used for the top and bottom chords, and the web material for the verticals and diagonals.
+pub fn generate_truss(truss_type:&str,
+					  span:f64,
+					  height:f64,
+					  panels:usize,
+					  chord_material:&str,
+					  web_material:&str) -> (Vec<Keypoint>, Vec<Connection>) {
+
+	if !TRUSS_TYPES.contains(&truss_type) {
+		panic!("Unknown truss type {}", truss_type);
+	}
+	if panels < 2 {
+		panic!("A truss needs at least 2 panels");
+	}
+
+	let panel_length:f64 = span / panels as f64;
+	let mut kp_list:Vec<Keypoint> = Vec::new();
+	let mut conn_list = ConnectionList::default();
+
+	// Bottom chord.
+	for i in 0..=panels {
+		kp_list.push(create_keypoint(&bottom_keypoint_name(i), i as f64 * panel_length, 0.0));
+	}
+	for i in 0..panels {
+		conn_list.add("bot", &bottom_keypoint_name(i), &bottom_keypoint_name(i+1), chord_material, "bar");
+	}
+
+	if truss_type == "warren" {
+		// Top chord keypoints in the middle of each panel.
+		for i in 0..panels {
+			kp_list.push(create_keypoint(&top_keypoint_name(i), (i as f64 + 0.5) * panel_length, height));
+		}
+		for i in 0..panels-1 {
+			conn_list.add("top", &top_keypoint_name(i), &top_keypoint_name(i+1), chord_material, "bar");
+		}
+		for i in 0..panels {
+			conn_list.add("dia", &bottom_keypoint_name(i), &top_keypoint_name(i), web_material, "bar");
+			conn_list.add("dia", &top_keypoint_name(i), &bottom_keypoint_name(i+1), web_material, "bar");
+		}
+
+		return (kp_list, conn_list.connections);
+	}
+
+	// Top chord above the bottom chord.
+	for i in 0..=panels {
+		kp_list.push(create_keypoint(&top_keypoint_name(i), i as f64 * panel_length, height));
+	}
+	for i in 0..panels {
+		conn_list.add("top", &top_keypoint_name(i), &top_keypoint_name(i+1), chord_material, "bar");
+	}
+
+	// Panels left of midspan. A middle panel in trusses with an odd number of panels belongs to the right half.
+	let is_left_half = |i:usize| 2 * i + 1 < panels;
+
+	if truss_type == "k" {
+		// End verticals are single bars. Inner verticals are split at the mid-height keypoint.
+		for i in 1..panels {
+			kp_list.push(create_keypoint(&mid_keypoint_name(i), i as f64 * panel_length, height / 2.0));
+		}
+		for i in 0..=panels {
+			if i == 0 || i == panels {
+				conn_list.add("ver", &bottom_keypoint_name(i), &top_keypoint_name(i), web_material, "bar");
+			} else {
+				conn_list.add("ver", &bottom_keypoint_name(i), &mid_keypoint_name(i), web_material, "bar");
+				conn_list.add("ver", &mid_keypoint_name(i), &top_keypoint_name(i), web_material, "bar");
+			}
+		}
+		for i in 0..panels {
+			let (inner, outer) = if is_left_half(i) { (i+1, i) } else { (i, i+1) };
+			conn_list.add("dia", &mid_keypoint_name(inner), &bottom_keypoint_name(outer), web_material, "bar");
+			conn_list.add("dia", &mid_keypoint_name(inner), &top_keypoint_name(outer), web_material, "bar");
+		}
+
+		return (kp_list, conn_list.connections);
+	}
+
+	for i in 0..=panels {
+		conn_list.add("ver", &bottom_keypoint_name(i), &top_keypoint_name(i), web_material, "bar");
+	}
+
+	// The diagonals are mirrored about midspan.
+	for i in 0..panels {
+		let falling_to_the_right:bool = (truss_type == "pratt") == is_left_half(i);
+		if falling_to_the_right {
+			conn_list.add("dia", &top_keypoint_name(i), &bottom_keypoint_name(i+1), web_material, "bar");
+		} else {
+			conn_list.add("dia", &bottom_keypoint_name(i), &top_keypoint_name(i+1), web_material, "bar");
+		}
+	}
+
+	(kp_list, conn_list.connections)
+}
+
+// Generating simple supports for a truss. Pinned at the left end and a roller at the right end of the bottom chord.
+pub fn generate_truss_supports(panels:usize) -> Vec<BoundaryCondition> {
+	vec![create_boundary_condition("bc1", &bottom_keypoint_name(0), "2"),
+		 create_boundary_condition("bc2", &bottom_keypoint_name(panels), "1")]
+}
@@ -0,0 +1,54 @@
+// Helper functions for creating model objects in the generators.
+// The objects are created the same way as the parsers create them from the input files.
+
+use std::collections::HashMap;
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::input::pointload::Pointload;
+use crate::input::surface_element::SurfaceElement;
+
+pub fn create_keypoint(name:&str, x:f64, y:f64) -> Keypoint {
+	Keypoint {name:name.to_string(), x, y, fx:0.0, fy:0.0, mz:0.0, ux:0.0, uy:0.0, rz:0.0}
+}
+
+// Section and material parameters are assigned afterwards, as for parsed connections.
+pub fn create_connection(name:&str, kp_1:&str, kp_2:&str, material:&str, element:&str) -> Connection {
+	Connection {name:name.to_string(), kp_1:kp_1.to_string(), kp_2:kp_2.to_string(),
+				material:material.to_string(), element:element.to_string(), section:String::new(),
+				e_modulus:0.0, area:0.0, inertia:0.0, inertia_minor:0.0, torsion_constant:0.0, radius_of_gyration:0.0,
+				orientation:[0.0; 3], release_1:String::new(), release_2:String::new(),
+				n_1:0.0, v_1:0.0, m_1:0.0, n_2:0.0, v_2:0.0, m_2:0.0, diagram:Vec::new(),
+				utilization:0.0}
+}
+
+pub fn create_boundary_condition(name:&str, keypoint:&str, fixture:&str) -> BoundaryCondition {
+	BoundaryCondition {name:name.to_string(), keypoint:keypoint.to_string(), fixture:fixture.to_string(), spring_stiffness:-1.0}
+}
+
+pub fn create_pointload(name:&str, keypoint:&str, load_x:f64, load_y:f64) -> Pointload {
+	Pointload {name:name.to_string(), keypoint:keypoint.to_string(), load_x, load_y, moment:0.0}
+}
+
+// The E-modulus is assigned afterwards, as for parsed surface elements. Stresses are recovered after the analysis.
+pub fn create_surface_element(name:&str, keypoints:&[String], material:&str, formulation:&str, thickness:f64,
+							  poisson_ratio:f64) -> SurfaceElement {
+	SurfaceElement {name:name.to_string(), keypoints:keypoints.to_vec(), material:material.to_string(),
+					formulation:formulation.to_string(), thickness, poisson_ratio, e_modulus:0.0, sx:0.0, sy:0.0, txy:0.0, von_mises:0.0}
+}
+
+// Numbering the connections of each member group from 1, as in the input files.
+#[derive(Default)]
+pub struct ConnectionList {
+	pub connections: Vec<Connection>,
+	counters: HashMap<String, usize>,
+}
+
+impl ConnectionList {
+	pub fn add(&mut self, prefix:&str, kp_1:&str, kp_2:&str, material:&str, element:&str) {
+		let counter:&mut usize = self.counters.entry(prefix.to_string()).or_insert(0);
+		*counter += 1;
+		let name:String = format!("{}{}", prefix, counter);
+		self.connections.push(create_connection(&name, kp_1, kp_2, material, element));
+	}
+}
@@ -4,7 +4,7 @@ use std::fs;
 pub struct BoundaryCondition {
 	pub name: String,
 	pub keypoint: String,
-	pub fixture: String, // 0=x-direction, 1=y-direction, 2=both x and y-direction.
+	pub fixture: String, // 0=x-direction, 1=y-direction, 2=both x and y-direction, 3=rotation, 4=x, y and rotation.
 	pub spring_stiffness: f64 // -1 is a fixed bc. Other values are the spring value.
 }
 
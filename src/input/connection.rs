@@ -1,11 +1,42 @@
 use std::fs;
+use serde::Serialize;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[derive(Serialize)]
 pub struct Connection {
 	pub name: String,
 	pub kp_1: String,
 	pub kp_2: String,
 	pub material: String,
+	pub element: String, // "bar" = axial stiffness only, "frame" = axial and bending stiffness.
+	pub section: String, // Section name from the section library. Empty for the default section.
+	pub e_modulus: f64, // Modulus of elasticity, assigned from the material after parsing.
+	pub area: f64,
+	pub inertia: f64, // Second moment of area for in-plane bending. Major axis (local y) of 3D frame elements.
+	pub inertia_minor: f64, // Second moment of area about the minor axis (local z). Only used by 3D frame elements.
+	pub torsion_constant: f64, // Only used by 3D frame elements.
+	pub radius_of_gyration: f64, // Smallest radius of gyration, used for buckling checks.
+	pub orientation: [f64; 3], // Direction of the local z-axis of 3D frame elements. Zero for the default orientation.
+	pub release_1: String, // Released local dofs at kp_1, e.g. "ryrz". Empty for a rigid end.
+	pub release_2: String, // Released local dofs at kp_2.
+	pub n_1: f64, // Normal force at kp_1. Tension is positive.
+	pub v_1: f64, // Shear force at kp_1.
+	pub m_1: f64, // Bending moment at kp_1. Sagging is positive.
+	pub n_2: f64, // Normal force at kp_2. Tension is positive.
+	pub v_2: f64, // Shear force at kp_2.
+	pub m_2: f64, // Bending moment at kp_2. Sagging is positive.
+	pub diagram: Vec<DiagramPoint>, // Sampled member forces along the connection.
+	pub utilization: f64, // Governing utilization ratio from the member design checks.
+}
+
+// Member forces at a position along a connection, measured from kp_1.
+#[derive(Debug, Clone)]
+#[derive(Serialize)]
+pub struct DiagramPoint {
+	pub position: f64,
+	pub n: f64,
+	pub v: f64,
+	pub m: f64,
 }
 
 pub fn parse_connection(file_path: &str) -> Vec<Connection> {
@@ -26,8 +57,18 @@ pub fn parse_connection(file_path: &str) -> Vec<Connection> {
 		let kp_2: String = parts[2].to_string();
 		let material: String = parts[3].to_string();
 
-		connections.push(Connection {name, kp_1, kp_2, material});
+		// Element type is optional. Connections default to bar elements.
+		let element: String = if parts.len() > 4 { parts[4].to_string() } else { "bar".to_string() };
+
+		// Section is optional. Section parameters are assigned from the section library after parsing.
+		let section: String = if parts.len() > 5 { parts[5].to_string() } else { String::new() };
+
+		connections.push(Connection {name, kp_1, kp_2, material, element, section,
+									 e_modulus: 0.0, area:0.0, inertia:0.0, inertia_minor:0.0, torsion_constant:0.0, radius_of_gyration:0.0,
+									 orientation:[0.0; 3], release_1:String::new(), release_2:String::new(),
+									 n_1:0.0, v_1:0.0, m_1:0.0, n_2:0.0, v_2:0.0, m_2:0.0, diagram:Vec::new(),
+									 utilization:0.0});
 	}
 
 	connections
-}
\ No newline at end of file
+}
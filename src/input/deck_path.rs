@@ -0,0 +1,30 @@
+use std::fs;
+
+// Path of keypoints along a deck. Moving loads travel from the first to the last keypoint.
+#[derive(Debug)]
+pub struct DeckPath {
+	pub name: String,
+	pub keypoints: Vec<String>,
+}
+
+pub fn parse_deck_path(file_path: &str) -> Vec<DeckPath> {
+	let mut deck_paths: Vec<DeckPath> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let keypoints: Vec<String> = parts[1..].iter().map(|s| s.to_string()).collect();
+
+		deck_paths.push(DeckPath {name, keypoints});
+	}
+
+	deck_paths
+}
@@ -0,0 +1,37 @@
+use std::fs;
+
+#[derive(Debug)]
+pub struct DistributedLoad {
+	pub name: String,
+	pub connection: String,
+	pub coordinate_system: String, // "local" = along/perpendicular to the connection, "global" = global x/y.
+	pub direction: String, // "x" or "y". Local x points from kp_1 towards kp_2.
+	pub load_start: f64, // Load per unit length at kp_1.
+	pub load_end: f64, // Load per unit length at kp_2.
+}
+
+pub fn parse_distributed_load(file_path: &str) -> Vec<DistributedLoad> {
+	let mut distributed_loads: Vec<DistributedLoad> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let connection: String = parts[1].to_string();
+		let coordinate_system: String = parts[2].to_string();
+		let direction: String = parts[3].to_string();
+		let load_start: f64 = parts[4].parse().unwrap();
+		let load_end: f64 = parts[5].parse().unwrap();
+
+		distributed_loads.push(DistributedLoad {name, connection, coordinate_system, direction, load_start, load_end});
+	}
+
+	distributed_loads
+}
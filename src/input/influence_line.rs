@@ -0,0 +1,36 @@
+use std::fs;
+
+// Definition of an influence line for a quantity, due to a unit load moving along a deck path.
+#[derive(Debug)]
+pub struct InfluenceLine {
+	pub name: String,
+	pub deck_path: String,
+	pub quantity: String, // "reaction", "bar_force" or "displacement".
+	pub target: String, // Keypoint for reactions and displacements. Connection for bar forces.
+	pub direction: String, // "x", "y" or "r" (rotation). Not used for bar forces.
+}
+
+pub fn parse_influence_line(file_path: &str) -> Vec<InfluenceLine> {
+	let mut influence_lines: Vec<InfluenceLine> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let deck_path: String = parts[1].to_string();
+		let quantity: String = parts[2].to_string();
+		let target: String = parts[3].to_string();
+		let direction: String = parts[4].to_string();
+
+		influence_lines.push(InfluenceLine {name, deck_path, quantity, target, direction});
+	}
+
+	influence_lines
+}
@@ -2,7 +2,7 @@ use std::fs;
 use serde::Serialize;
 
 // Structure for keypoint objects.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[derive(Serialize)]
 pub struct Keypoint {
     pub name: String,
@@ -10,8 +10,10 @@ pub struct Keypoint {
     pub y: f64,
     pub fx: f64,
     pub fy: f64,
+    pub mz: f64, // Moment. Only used in models with frame elements.
     pub ux: f64,
     pub uy: f64,
+    pub rz: f64, // Rotation. Only used in models with frame elements.
 }
 
 pub fn parse_keypoint(file_path: &str) -> Vec<Keypoint> {
@@ -34,7 +36,7 @@ pub fn parse_keypoint(file_path: &str) -> Vec<Keypoint> {
         let x = parts[1].parse::<f64>().unwrap();
         let y = parts[2].parse::<f64>().unwrap();
 
-        kp_list.push(Keypoint { name, x, y, fx:0.0, fy:0.0, ux:0.0, uy:0.0});
+        kp_list.push(Keypoint { name, x, y, fx:0.0, fy:0.0, mz:0.0, ux:0.0, uy:0.0, rz:0.0});
 
     }
 
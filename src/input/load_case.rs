@@ -0,0 +1,31 @@
+use std::fs;
+
+#[derive(Debug)]
+pub struct LoadCase {
+	pub name: String,
+	pub gravity_x: f64, // Gravity acceleration in x-direction. Used for self-weight.
+	pub gravity_y: f64, // Gravity acceleration in y-direction. Used for self-weight.
+}
+
+pub fn parse_load_case(file_path: &str) -> Vec<LoadCase> {
+	let mut load_cases: Vec<LoadCase> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let gravity_x: f64 = parts[1].parse().unwrap();
+		let gravity_y: f64 = parts[2].parse().unwrap();
+
+		load_cases.push(LoadCase {name, gravity_x, gravity_y});
+	}
+
+	load_cases
+}
@@ -1,9 +1,12 @@
 use std::fs;
+use crate::input::connection::Connection;
+use crate::input::surface_element::SurfaceElement;
 
 #[derive(Debug)]
 pub struct Material {
 	pub name: String,
 	pub e_modulus: f64,
+	pub density: f64,
 }
 
 pub fn parse_material(file_path: &str) -> Vec<Material> {
@@ -20,9 +23,26 @@ pub fn parse_material(file_path: &str) -> Vec<Material> {
 
 		let name: String = parts[0].to_string();
 		let e_modulus: f64 = parts[1].parse().unwrap();
+		let density: f64 = parts[2].parse().unwrap();
 
-		materials.push(Material {name, e_modulus});
+		materials.push(Material {name, e_modulus, density});
 	}
 
 	materials
-}
\ No newline at end of file
+}
+
+pub fn assign_material_properties(conn_list:&mut [Connection], mat_list:&[Material]) {
+	for conn in conn_list.iter_mut() {
+		let mat = mat_list.iter().find(|mat| mat.name == conn.material)
+			.unwrap_or_else(|| panic!("Unknown material {} in connection {}", conn.material, conn.name));
+		conn.e_modulus = mat.e_modulus;
+	}
+}
+
+pub fn assign_surface_material_properties(surface_list:&mut [SurfaceElement], mat_list:&[Material]) {
+	for surface in surface_list.iter_mut() {
+		let mat = mat_list.iter().find(|mat| mat.name == surface.material)
+			.unwrap_or_else(|| panic!("Unknown material {} in surface element {}", surface.material, surface.name));
+		surface.e_modulus = mat.e_modulus;
+	}
+}
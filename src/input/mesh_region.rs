@@ -0,0 +1,125 @@
+use std::fs;
+
+// Region of a plane model that is filled with surface elements by the mesh generator.
+// The outline is a simple polygon through existing keypoints, ordered counter-clockwise.
+// "quad" regions need an outline of 4 keypoints, and get a structured mesh of Q4 elements.
+// "tri" regions can have any number of outline keypoints, and get a Delaunay mesh of CST elements.
+#[derive(Debug)]
+pub struct MeshRegion {
+	pub name: String,
+	pub mesh_type: String, // "quad" or "tri".
+	pub element_size: f64, // Target element size.
+	pub material: String,
+	pub formulation: String, // "plane_stress" or "plane_strain".
+	pub thickness: f64,
+	pub poisson_ratio: f64,
+	pub outline: Vec<String>,
+}
+
+// Local element size near a keypoint. The size grows linearly to the region element size at the radius.
+#[derive(Debug)]
+pub struct MeshRefinement {
+	pub keypoint: String,
+	pub element_size: f64,
+	pub radius: f64,
+}
+
+// Boundary condition applied to all keypoints on the straight edge between two keypoints.
+// Used to support the generated mesh keypoints along an edge of a region.
+#[derive(Debug)]
+pub struct EdgeBoundaryCondition {
+	pub name: String,
+	pub keypoint_1: String,
+	pub keypoint_2: String,
+	pub fixture: String, // Same directions as the boundary conditions.
+	pub spring_stiffness: f64, // -1 is a fixed bc. Other values are the spring value.
+}
+
+pub fn parse_mesh_region(file_path: &str) -> Vec<MeshRegion> {
+	let mut mesh_regions: Vec<MeshRegion> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let mesh_type: String = parts[1].to_string();
+		let element_size: f64 = parts[2].parse().unwrap();
+		let material: String = parts[3].to_string();
+		let formulation: String = parts[4].to_string();
+		let thickness: f64 = parts[5].parse().unwrap();
+		let poisson_ratio: f64 = parts[6].parse().unwrap();
+		let outline: Vec<String> = parts[7..].iter().map(|s| s.to_string()).collect();
+
+		match mesh_type.as_str() {
+			"quad" if outline.len() != 4 => panic!("Quad mesh region {} must have an outline of 4 keypoints", name),
+			"tri" if outline.len() < 3 => panic!("Tri mesh region {} must have an outline of at least 3 keypoints", name),
+			"quad" | "tri" => {},
+			_ => panic!("Unknown mesh type {} for mesh region {}", mesh_type, name),
+		}
+		if formulation != "plane_stress" && formulation != "plane_strain" {
+			panic!("Unknown formulation {} for mesh region {}", formulation, name);
+		}
+		if element_size <= 0.0 {
+			panic!("Element size of mesh region {} must be positive", name);
+		}
+
+		mesh_regions.push(MeshRegion {name, mesh_type, element_size, material, formulation, thickness, poisson_ratio, outline});
+	}
+
+	mesh_regions
+}
+
+pub fn parse_mesh_refinement(file_path: &str) -> Vec<MeshRefinement> {
+	let mut mesh_refinements: Vec<MeshRefinement> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let keypoint: String = parts[0].to_string();
+		let element_size: f64 = parts[1].parse().unwrap();
+		let radius: f64 = parts[2].parse().unwrap();
+
+		mesh_refinements.push(MeshRefinement {keypoint, element_size, radius});
+	}
+
+	mesh_refinements
+}
+
+pub fn parse_edge_boundary_condition(file_path: &str) -> Vec<EdgeBoundaryCondition> {
+	let mut edge_bcs: Vec<EdgeBoundaryCondition> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let keypoint_1: String = parts[1].to_string();
+		let keypoint_2: String = parts[2].to_string();
+		let fixture: String = parts[3].to_string();
+		let spring_stiffness: f64 = parts[4].parse().unwrap();
+
+		edge_bcs.push(EdgeBoundaryCondition {name, keypoint_1, keypoint_2, fixture, spring_stiffness});
+	}
+
+	edge_bcs
+}
@@ -0,0 +1,49 @@
+use std::fs;
+use crate::input::connection::Connection;
+
+// Orientation vector of 3D frame elements. The local z-axis is the part of the vector perpendicular to the member.
+#[derive(Debug)]
+pub struct Orientation {
+	pub connection: String,
+	pub x: f64,
+	pub y: f64,
+	pub z: f64,
+}
+
+pub fn parse_orientation(file_path: &str) -> Vec<Orientation> {
+	let mut orientations: Vec<Orientation> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let connection: String = parts[0].to_string();
+		let x: f64 = parts[1].parse().unwrap();
+		let y: f64 = parts[2].parse().unwrap();
+		let z: f64 = parts[3].parse().unwrap();
+
+		orientations.push(Orientation {connection, x, y, z});
+	}
+
+	orientations
+}
+
+// Assigning the orientation vectors to all connections with the given name.
+pub fn assign_orientations(conn_list:&mut [Connection], orientation_list:&[Orientation]) {
+	for orientation in orientation_list {
+		let mut found:bool = false;
+		for conn in conn_list.iter_mut().filter(|conn| conn.name == orientation.connection) {
+			conn.orientation = [orientation.x, orientation.y, orientation.z];
+			found = true;
+		}
+		if !found {
+			panic!("Unknown connection {} in orientation input", orientation.connection);
+		}
+	}
+}
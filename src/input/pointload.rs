@@ -1,11 +1,12 @@
 use std::fs;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Pointload {
 	pub name: String,
 	pub keypoint: String,
 	pub load_x: f64,
 	pub load_y: f64,
+	pub moment: f64, // Only used in models with frame elements.
 }
 
 pub fn parse_pointload(file_path: &str) -> Vec<Pointload> {
@@ -26,7 +27,10 @@ pub fn parse_pointload(file_path: &str) -> Vec<Pointload> {
 		let load_x: f64 = parts[2].parse().unwrap();
 		let load_y: f64 = parts[3].parse().unwrap();
 
-		pointloads.push(Pointload {name, keypoint, load_x, load_y});
+		// Moment is optional. Defaults to zero.
+		let moment: f64 = if parts.len() > 4 { parts[4].parse().unwrap() } else { 0.0 };
+
+		pointloads.push(Pointload {name, keypoint, load_x, load_y, moment});
 	}
 
 	pointloads
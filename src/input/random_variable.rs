@@ -0,0 +1,72 @@
+use std::fs;
+
+// Model parameter treated as a random variable by the reliability analysis.
+// The parameters are the same as for the parameter sweep.
+#[derive(Debug)]
+pub struct RandomVariable {
+	pub name: String,
+	pub parameter: String, // "keypoint_x", "keypoint_y", "e_module", "pointload_x", "pointload_y" or "area".
+	pub target: String, // Keypoint, pointload or connection name. Material name for e_module.
+	pub distribution: String, // "normal" or "lognormal".
+	pub mean: f64,
+	pub cov: f64, // Coefficient of variation, standard deviation / |mean|.
+}
+
+// Response collected by the reliability analysis, with the threshold for the exceedance probability.
+#[derive(Debug)]
+pub struct ReliabilityOutput {
+	pub name: String,
+	pub quantity: String, // Same quantities as the sweep outputs.
+	pub target: String,
+	pub threshold: f64, // Exceedance is counted when the absolute value of the response is above the threshold.
+}
+
+pub fn parse_random_variable(file_path: &str) -> Vec<RandomVariable> {
+	let mut random_variables: Vec<RandomVariable> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let parameter: String = parts[1].to_string();
+		let target: String = parts[2].to_string();
+		let distribution: String = parts[3].to_string();
+		let mean: f64 = parts[4].parse().unwrap();
+		let cov: f64 = parts[5].parse().unwrap();
+
+		random_variables.push(RandomVariable {name, parameter, target, distribution, mean, cov});
+	}
+
+	random_variables
+}
+
+pub fn parse_reliability_output(file_path: &str) -> Vec<ReliabilityOutput> {
+	let mut reliability_outputs: Vec<ReliabilityOutput> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let quantity: String = parts[1].to_string();
+		let target: String = parts[2].to_string();
+		let threshold: f64 = parts[3].parse().unwrap();
+
+		reliability_outputs.push(ReliabilityOutput {name, quantity, target, threshold});
+	}
+
+	reliability_outputs
+}
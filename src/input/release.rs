@@ -0,0 +1,78 @@
+use std::fs;
+use crate::input::connection::Connection;
+
+// Released local dofs at the ends of a frame element, as direction letters.
+// "x", "y" and "z" are the local translations, "rx", "ry" and "rz" the local rotations, e.g. "ryrz" for a hinge.
+// "none" keeps the end rigid.
+#[derive(Debug)]
+pub struct Release {
+	pub connection: String,
+	pub release_1: String, // Released dofs at kp_1.
+	pub release_2: String, // Released dofs at kp_2.
+}
+
+pub fn parse_release(file_path: &str) -> Vec<Release> {
+	let mut releases: Vec<Release> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let connection: String = parts[0].to_string();
+		let release_1: String = if parts[1] == "none" { String::new() } else { parts[1].to_string() };
+		let release_2: String = if parts[2] == "none" { String::new() } else { parts[2].to_string() };
+
+		releases.push(Release {connection, release_1, release_2});
+	}
+
+	releases
+}
+
+// Assigning the end releases to all connections with the given name.
+pub fn assign_releases(conn_list:&mut [Connection], release_list:&[Release]) {
+	for release in release_list {
+		let mut found:bool = false;
+		for conn in conn_list.iter_mut().filter(|conn| conn.name == release.connection) {
+			conn.release_1 = release.release_1.clone();
+			conn.release_2 = release.release_2.clone();
+			found = true;
+		}
+		if !found {
+			panic!("Unknown connection {} in release input", release.connection);
+		}
+	}
+}
+
+// Returning the dof numbers within a keypoint for a string of direction letters, e.g. "ryrz".
+// Used for end releases and for the fixtures of 3D boundary conditions.
+// 0 = x, 1 = y, 2 = z, 3 = rx, 4 = ry, 5 = rz.
+pub fn direction_dofs(directions: &str) -> Vec<usize> {
+	let mut dofs:Vec<usize> = Vec::new();
+	let mut is_rotation:bool = false;
+
+	for direction in directions.chars() {
+		let axis:usize = match direction {
+			'r' if !is_rotation => {
+				is_rotation = true;
+				continue;
+			},
+			'x' => 0,
+			'y' => 1,
+			'z' => 2,
+			_ => panic!("Unknown direction {} in {}", direction, directions),
+		};
+		dofs.push(if is_rotation { axis + 3 } else { axis });
+		is_rotation = false;
+	}
+	if is_rotation {
+		panic!("Missing rotation axis in {}", directions);
+	}
+
+	dofs
+}
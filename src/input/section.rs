@@ -0,0 +1,75 @@
+use std::fs;
+use crate::input::connection::Connection;
+
+// Cross-section properties of a standard profile.
+#[derive(Debug)]
+pub struct Section {
+	pub name: String,
+	pub shape: String, // "i_beam", "hollow", "angle" or "round_bar".
+	pub area: f64,
+	pub inertia: f64, // Second moment of area about the major axis.
+	pub radius_of_gyration: f64, // Smallest radius of gyration.
+	pub inertia_minor: f64, // Second moment of area about the minor axis, area * radius_of_gyration^2.
+	pub torsion_constant: f64, // St. Venant torsion constant. Only used by 3D frame elements.
+}
+
+pub fn parse_section(file_path: &str) -> Vec<Section> {
+	let mut sections: Vec<Section> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let shape: String = parts[1].to_string();
+		let area: f64 = parts[2].parse().unwrap();
+		let inertia: f64 = parts[3].parse().unwrap();
+		let radius_of_gyration: f64 = parts[4].parse().unwrap();
+		let inertia_minor: f64 = area * radius_of_gyration * radius_of_gyration;
+
+		// Torsion constant is optional. Defaults to zero.
+		let torsion_constant: f64 = if parts.len() > 5 { parts[5].parse().unwrap() } else { 0.0 };
+
+		sections.push(Section {name, shape, area, inertia, radius_of_gyration, inertia_minor, torsion_constant});
+	}
+
+	sections
+}
+
+// Assigning the section parameters to the connections.
+// Connections without a section get the default area and moment of inertia.
+// The default section has the same moment of inertia about both axes, and the torsion constant of a round section, 2 * I.
+pub fn assign_section_properties(conn_list:&mut [Connection],
+								 section_list:&[Section],
+								 default_area:f64,
+								 default_inertia:f64) {
+	for conn in conn_list.iter_mut() {
+		if conn.section.is_empty() {
+			conn.area = default_area;
+			conn.inertia = default_inertia;
+			conn.radius_of_gyration = (default_inertia / default_area).sqrt();
+			conn.inertia_minor = default_inertia;
+			conn.torsion_constant = 2.0 * default_inertia;
+		} else {
+			let section = section_list.iter().find(|section| section.name == conn.section)
+				.unwrap_or_else(|| panic!("Unknown section {} in connection {}", conn.section, conn.name));
+			assign_section(conn, section);
+		}
+	}
+}
+
+// Assigning a section and its parameters to a connection.
+pub fn assign_section(conn:&mut Connection, section:&Section) {
+	conn.section = section.name.clone();
+	conn.area = section.area;
+	conn.inertia = section.inertia;
+	conn.radius_of_gyration = section.radius_of_gyration;
+	conn.inertia_minor = section.inertia_minor;
+	conn.torsion_constant = section.torsion_constant;
+}
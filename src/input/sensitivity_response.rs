@@ -0,0 +1,32 @@
+use std::fs;
+
+// Keypoint displacement for which the design sensitivities are calculated.
+#[derive(Debug)]
+pub struct SensitivityResponse {
+	pub name: String,
+	pub keypoint: String,
+	pub direction: String, // "x", "y" or "r" (rotation, frame models only).
+}
+
+pub fn parse_sensitivity_response(file_path: &str) -> Vec<SensitivityResponse> {
+	let mut sensitivity_responses: Vec<SensitivityResponse> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let keypoint: String = parts[1].to_string();
+		let direction: String = parts[2].to_string();
+
+		sensitivity_responses.push(SensitivityResponse {name, keypoint, direction});
+	}
+
+	sensitivity_responses
+}
@@ -0,0 +1,59 @@
+use std::fs;
+
+// Group of connections that are given the same section by the member sizing.
+#[derive(Debug)]
+pub struct SizingGroup {
+	pub name: String,
+	pub connections: Vec<String>,
+}
+
+// Candidate sections of a sizing group.
+#[derive(Debug)]
+pub struct SizingCandidates {
+	pub group: String,
+	pub sections: Vec<String>,
+}
+
+pub fn parse_sizing_group(file_path: &str) -> Vec<SizingGroup> {
+	let mut sizing_groups: Vec<SizingGroup> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let connections: Vec<String> = parts[1..].iter().map(|s| s.to_string()).collect();
+
+		sizing_groups.push(SizingGroup {name, connections});
+	}
+
+	sizing_groups
+}
+
+pub fn parse_sizing_candidates(file_path: &str) -> Vec<SizingCandidates> {
+	let mut sizing_candidates: Vec<SizingCandidates> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let group: String = parts[0].to_string();
+		let sections: Vec<String> = parts[1..].iter().map(|s| s.to_string()).collect();
+
+		sizing_candidates.push(SizingCandidates {group, sections});
+	}
+
+	sizing_candidates
+}
@@ -0,0 +1,49 @@
+use std::fs;
+use serde::Serialize;
+
+// Structure for keypoints of 3D models. The z-axis is vertical.
+#[derive(Debug, Clone)]
+#[derive(Serialize)]
+pub struct SpaceKeypoint {
+    pub name: String,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub fx: f64,
+    pub fy: f64,
+    pub fz: f64,
+    pub mx: f64, // Moments. Only used in models with frame elements.
+    pub my: f64,
+    pub mz: f64,
+    pub ux: f64,
+    pub uy: f64,
+    pub uz: f64,
+    pub rx: f64, // Rotations. Only used in models with frame elements.
+    pub ry: f64,
+    pub rz: f64,
+}
+
+pub fn parse_space_keypoint(file_path: &str) -> Vec<SpaceKeypoint> {
+    let mut kp_list: Vec<SpaceKeypoint> = Vec::new();
+
+    let content: String = fs::read_to_string(file_path).unwrap();
+
+    for line in content.lines() {
+
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+        let name = parts[0].to_string();
+        let x = parts[1].parse::<f64>().unwrap();
+        let y = parts[2].parse::<f64>().unwrap();
+        let z = parts[3].parse::<f64>().unwrap();
+
+        kp_list.push(SpaceKeypoint { name, x, y, z, fx:0.0, fy:0.0, fz:0.0, mx:0.0, my:0.0, mz:0.0,
+                                     ux:0.0, uy:0.0, uz:0.0, rx:0.0, ry:0.0, rz:0.0});
+    }
+
+    kp_list
+}
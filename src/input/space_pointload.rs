@@ -0,0 +1,45 @@
+use std::fs;
+
+// Pointload acting on a keypoint of a 3D model.
+#[derive(Debug, Clone)]
+pub struct SpacePointload {
+	pub name: String,
+	pub keypoint: String,
+	pub load_x: f64,
+	pub load_y: f64,
+	pub load_z: f64,
+	pub moment_x: f64, // Moments are only used in models with frame elements.
+	pub moment_y: f64,
+	pub moment_z: f64,
+}
+
+pub fn parse_space_pointload(file_path: &str) -> Vec<SpacePointload> {
+	let mut pointloads: Vec<SpacePointload> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let keypoint: String = parts[1].to_string();
+		let load_x: f64 = parts[2].parse().unwrap();
+		let load_y: f64 = parts[3].parse().unwrap();
+		let load_z: f64 = parts[4].parse().unwrap();
+
+		// Moments are optional. Default to zero.
+		let moment = |i:usize| -> f64 { if parts.len() > i { parts[i].parse().unwrap() } else { 0.0 } };
+		let moment_x: f64 = moment(5);
+		let moment_y: f64 = moment(6);
+		let moment_z: f64 = moment(7);
+
+		pointloads.push(SpacePointload {name, keypoint, load_x, load_y, load_z, moment_x, moment_y, moment_z});
+	}
+
+	pointloads
+}
@@ -0,0 +1,30 @@
+use std::fs;
+
+// Point on a response spectrum. Spectral acceleration as a function of period.
+#[derive(Debug)]
+pub struct SpectrumPoint {
+	pub period: f64,
+	pub acceleration: f64,
+}
+
+pub fn parse_spectrum(file_path: &str) -> Vec<SpectrumPoint> {
+	let mut spectrum: Vec<SpectrumPoint> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let period: f64 = parts[0].parse().unwrap();
+		let acceleration: f64 = parts[1].parse().unwrap();
+
+		spectrum.push(SpectrumPoint {period, acceleration});
+	}
+
+	spectrum
+}
@@ -0,0 +1,34 @@
+use std::fs;
+
+// Steel design parameters of a connection.
+#[derive(Debug)]
+pub struct SteelMember {
+	pub connection: String,
+	pub yield_strength: f64,
+	pub buckling_curve: String, // "a0", "a", "b", "c" or "d".
+	pub effective_length_factor: f64, // Buckling length = factor * connection length.
+}
+
+pub fn parse_steel_member(file_path: &str) -> Vec<SteelMember> {
+	let mut steel_members: Vec<SteelMember> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let connection: String = parts[0].to_string();
+		let yield_strength: f64 = parts[1].parse().unwrap();
+		let buckling_curve: String = parts[2].to_string();
+		let effective_length_factor: f64 = parts[3].parse().unwrap();
+
+		steel_members.push(SteelMember {connection, yield_strength, buckling_curve, effective_length_factor});
+	}
+
+	steel_members
+}
@@ -0,0 +1,58 @@
+use std::fs;
+use serde::Serialize;
+
+// Structure for plane stress/strain surface elements.
+// 3 keypoints give a constant strain triangle (CST), 4 keypoints give a bilinear quadrilateral (Q4).
+// The keypoints are ordered counter-clockwise.
+#[derive(Debug, Clone)]
+#[derive(Serialize)]
+pub struct SurfaceElement {
+    pub name: String,
+    pub keypoints: Vec<String>,
+    pub material: String,
+    pub formulation: String, // "plane_stress" or "plane_strain".
+    pub thickness: f64,
+    pub poisson_ratio: f64,
+    pub e_modulus: f64, // Modulus of elasticity, assigned from the material after parsing.
+    pub sx: f64, // Stresses at the element centroid.
+    pub sy: f64,
+    pub txy: f64,
+    pub von_mises: f64,
+}
+
+pub fn parse_surface_element(file_path: &str) -> Vec<SurfaceElement> {
+    let mut surface_list: Vec<SurfaceElement> = Vec::new();
+
+    let content: String = fs::read_to_string(file_path).unwrap();
+
+    // Iterate through lines of input file.
+    for line in content.lines() {
+
+        // Skip line if it starts with hashtag.
+        if line.starts_with('#') {
+            continue;
+        }
+
+        // Parse the line into surface element struct.
+        let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+        let name = parts[0].to_string();
+        let material = parts[1].to_string();
+        let formulation = parts[2].to_string();
+        let thickness = parts[3].parse::<f64>().unwrap();
+        let poisson_ratio = parts[4].parse::<f64>().unwrap();
+        let keypoints: Vec<String> = parts[5..].iter().map(|s| s.to_string()).collect();
+
+        if formulation != "plane_stress" && formulation != "plane_strain" {
+            panic!("Unknown formulation {} for surface element {}", formulation, name);
+        }
+        if keypoints.len() != 3 && keypoints.len() != 4 {
+            panic!("Surface element {} must have 3 (CST) or 4 (Q4) keypoints", name);
+        }
+
+        surface_list.push(SurfaceElement { name, keypoints, material, formulation, thickness, poisson_ratio,
+                                           e_modulus:0.0, sx:0.0, sy:0.0, txy:0.0, von_mises:0.0});
+    }
+
+    surface_list
+}
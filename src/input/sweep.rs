@@ -0,0 +1,69 @@
+use std::fs;
+
+// Model parameter varied by the parameter sweep. The values are spread evenly from start to end.
+#[derive(Debug)]
+pub struct SweepParameter {
+	pub name: String,
+	pub parameter: String, // "keypoint_x", "keypoint_y", "e_module", "pointload_x", "pointload_y" or "area".
+	pub target: String, // Keypoint, pointload or connection name. Material name for e_module.
+	pub start: f64,
+	pub end: f64,
+	pub steps: usize, // Number of values, including start and end.
+}
+
+// Result written to the summary table for each variant of the parameter sweep.
+#[derive(Debug)]
+pub struct SweepOutput {
+	pub name: String,
+	pub quantity: String, // "max_displacement", "max_bar_force", "displacement_x/y", "reaction_x/y" or "bar_force".
+	pub target: String, // Keypoint or connection name. Not used for the max values.
+}
+
+pub fn parse_sweep_parameter(file_path: &str) -> Vec<SweepParameter> {
+	let mut sweep_parameters: Vec<SweepParameter> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let parameter: String = parts[1].to_string();
+		let target: String = parts[2].to_string();
+		let start: f64 = parts[3].parse().unwrap();
+		let end: f64 = parts[4].parse().unwrap();
+		let steps: usize = parts[5].parse().unwrap();
+
+		sweep_parameters.push(SweepParameter {name, parameter, target, start, end, steps});
+	}
+
+	sweep_parameters
+}
+
+pub fn parse_sweep_output(file_path: &str) -> Vec<SweepOutput> {
+	let mut sweep_outputs: Vec<SweepOutput> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let quantity: String = parts[1].to_string();
+		let target: String = parts[2].to_string();
+
+		sweep_outputs.push(SweepOutput {name, quantity, target});
+	}
+
+	sweep_outputs
+}
@@ -0,0 +1,40 @@
+use std::fs;
+
+// Strength and stiffness properties of a timber strength class.
+#[derive(Debug)]
+pub struct TimberClass {
+	pub name: String,
+	pub timber_type: String, // "solid" or "glulam".
+	pub tension_strength: f64, // Characteristic tensile strength parallel to grain, f_t,0,k.
+	pub compression_strength: f64, // Characteristic compressive strength parallel to grain, f_c,0,k.
+	pub e_mean: f64, // Mean modulus of elasticity parallel to grain, E_0,mean.
+	pub e_05: f64, // 5% modulus of elasticity parallel to grain, E_0,05.
+	pub density: f64, // Characteristic density.
+}
+
+pub fn parse_timber_class(file_path: &str) -> Vec<TimberClass> {
+	let mut timber_classes: Vec<TimberClass> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let timber_type: String = parts[1].to_string();
+		let tension_strength: f64 = parts[2].parse().unwrap();
+		let compression_strength: f64 = parts[3].parse().unwrap();
+		let e_mean: f64 = parts[4].parse().unwrap();
+		let e_05: f64 = parts[5].parse().unwrap();
+		let density: f64 = parts[6].parse().unwrap();
+
+		timber_classes.push(TimberClass {name, timber_type, tension_strength, compression_strength, e_mean, e_05, density});
+	}
+
+	timber_classes
+}
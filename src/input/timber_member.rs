@@ -0,0 +1,60 @@
+use std::fs;
+use crate::input::connection::Connection;
+use crate::input::material::Material;
+use crate::input::timber_class::TimberClass;
+
+// Timber design parameters of a connection.
+#[derive(Debug)]
+pub struct TimberMember {
+	pub connection: String,
+	pub timber_class: String,
+	pub service_class: usize, // 1, 2 or 3.
+	pub load_duration: String, // "permanent", "long", "medium", "short" or "instantaneous".
+	pub effective_length_factor: f64, // Buckling length = factor * connection length.
+}
+
+pub fn parse_timber_member(file_path: &str) -> Vec<TimberMember> {
+	let mut timber_members: Vec<TimberMember> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let connection: String = parts[0].to_string();
+		let timber_class: String = parts[1].to_string();
+		let service_class: usize = parts[2].parse().unwrap();
+		let load_duration: String = parts[3].to_string();
+		let effective_length_factor: f64 = parts[4].parse().unwrap();
+
+		timber_members.push(TimberMember {connection, timber_class, service_class, load_duration, effective_length_factor});
+	}
+
+	timber_members
+}
+
+// Checking that the material of each timber member has the stiffness and density of its timber class.
+// The stiffness and self-weight of the analysis are taken from the material, so a mismatch is rejected.
+pub fn check_timber_materials(conn_list:&[Connection],
+							  mat_list:&[Material],
+							  timber_members:&[TimberMember],
+							  timber_classes:&[TimberClass]) {
+	for member in timber_members {
+		let conn = conn_list.iter().find(|conn| conn.name == member.connection)
+			.unwrap_or_else(|| panic!("Unknown connection {} in timber member input", member.connection));
+		let timber_class = timber_classes.iter().find(|class| class.name == member.timber_class)
+			.unwrap_or_else(|| panic!("Unknown timber class {} in timber member {}", member.timber_class, member.connection));
+		let mat = mat_list.iter().find(|mat| mat.name == conn.material)
+			.unwrap_or_else(|| panic!("Unknown material {} in connection {}", conn.material, conn.name));
+
+		if mat.e_modulus != timber_class.e_mean || mat.density != timber_class.density {
+			panic!("Material {} of timber member {} does not match timber class {} (E-modulus {}, density {})",
+				   mat.name, conn.name, timber_class.name, timber_class.e_mean, timber_class.density);
+		}
+	}
+}
@@ -0,0 +1,37 @@
+use std::fs;
+
+// Time-varying pointload. Lines with the same name define a load history,
+// that is linearly interpolated between the given times.
+#[derive(Debug)]
+pub struct TimeLoad {
+	pub name: String,
+	pub keypoint: String,
+	pub time: f64,
+	pub load_x: f64,
+	pub load_y: f64,
+}
+
+pub fn parse_time_load(file_path: &str) -> Vec<TimeLoad> {
+	let mut time_loads: Vec<TimeLoad> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let keypoint: String = parts[1].to_string();
+		let time: f64 = parts[2].parse().unwrap();
+		let load_x: f64 = parts[3].parse().unwrap();
+		let load_y: f64 = parts[4].parse().unwrap();
+
+		time_loads.push(TimeLoad {name, keypoint, time, load_x, load_y});
+	}
+
+	time_loads
+}
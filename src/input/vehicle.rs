@@ -0,0 +1,35 @@
+use std::fs;
+
+// Axle of a vehicle moving along a deck path. Lines with the same name define one vehicle.
+// The offset is the distance behind the leading axle, and the load acts downwards.
+#[derive(Debug)]
+pub struct VehicleAxle {
+	pub name: String,
+	pub deck_path: String,
+	pub offset: f64,
+	pub load: f64,
+}
+
+pub fn parse_vehicle(file_path: &str) -> Vec<VehicleAxle> {
+	let mut vehicle_axles: Vec<VehicleAxle> = Vec::new();
+
+	let content: String = fs::read_to_string(file_path).unwrap();
+
+	for line in content.lines() {
+
+		if line.starts_with('#') {
+			continue;
+		}
+
+		let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+
+		let name: String = parts[0].to_string();
+		let deck_path: String = parts[1].to_string();
+		let offset: f64 = parts[2].parse().unwrap();
+		let load: f64 = parts[3].parse().unwrap();
+
+		vehicle_axles.push(VehicleAxle {name, deck_path, offset, load});
+	}
+
+	vehicle_axles
+}
@@ -0,0 +1,115 @@
+// Library of the rust-fe-calculator.
+// The modules are shared by the command line program in main.rs, and can be used
+// directly to build and analyse models from Rust code.
+
+pub mod input {
+    pub mod keypoint;
+    pub mod space_keypoint;
+    pub mod connection;
+    pub mod boundary_condition;
+    pub mod pointload;
+    pub mod space_pointload;
+    pub mod orientation;
+    pub mod release;
+    pub mod surface_element;
+    pub mod mesh_region;
+    pub mod distributed_load;
+    pub mod time_load;
+    pub mod spectrum;
+    pub mod deck_path;
+    pub mod influence_line;
+    pub mod vehicle;
+    pub mod steel_member;
+    pub mod timber_class;
+    pub mod timber_member;
+    pub mod section;
+    pub mod sizing_group;
+    pub mod sweep;
+    pub mod random_variable;
+    pub mod sensitivity_response;
+    pub mod material;
+    pub mod load_case;
+}
+
+pub mod fe_engine {
+    pub mod global_stiffness_matrix;
+    pub mod dof_filter_vector;
+    pub mod force_vector;
+    pub mod self_weight;
+    pub mod distributed_load_vector;
+    pub mod member_forces;
+    pub mod global_mass_matrix;
+    pub mod reduced_system;
+    pub mod modal_analysis;
+    pub mod global_geometric_stiffness_matrix;
+    pub mod buckling_analysis;
+    pub mod time_load_vector;
+    pub mod transient_analysis;
+    pub mod harmonic_analysis;
+    pub mod response_spectrum;
+    pub mod influence_lines;
+    pub mod linear_static;
+    pub mod linear_programming;
+    pub mod moving_loads;
+    pub mod parameter_sweep;
+    pub mod monte_carlo;
+    pub mod sensitivity;
+    pub mod space_analysis;
+    pub mod plane_analysis;
+    pub mod utils;
+}
+
+pub mod material_formulation {
+    pub mod local_stiffness_matrix_bar;
+    pub mod local_stiffness_matrix_frame;
+    pub mod local_stiffness_matrix_space_bar;
+    pub mod local_stiffness_matrix_space_frame;
+    pub mod static_condensation;
+    pub mod plane_elasticity;
+    pub mod local_stiffness_matrix_cst;
+    pub mod local_stiffness_matrix_q4;
+    pub mod local_mass_matrix_bar;
+    pub mod local_geometric_stiffness_matrix;
+}
+
+pub mod output {
+    pub mod canvas;
+    pub mod figures;
+    pub mod content_and_labels;
+    pub mod keypoint_results;
+    pub mod connection_results;
+    pub mod modal_results;
+    pub mod buckling_results;
+    pub mod transient_results;
+    pub mod harmonic_results;
+    pub mod response_spectrum_results;
+    pub mod influence_line_results;
+    pub mod moving_load_results;
+    pub mod design_results;
+    pub mod topology_results;
+    pub mod model_files;
+    pub mod sweep_results;
+    pub mod reliability_results;
+    pub mod sensitivity_results;
+    pub mod space_results;
+    pub mod projection;
+    pub mod surface_results;
+}
+
+pub mod design {
+    pub mod steel_check;
+    pub mod timber_check;
+    pub mod member_sizing;
+    pub mod topology_optimisation;
+}
+
+pub mod generators {
+    pub mod truss;
+    pub mod building;
+    pub mod mesh;
+    pub mod utils;
+}
+
+pub mod data_formatting {
+    pub mod generate_result_structs;
+}
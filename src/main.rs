@@ -1,94 +1,451 @@
-mod input {
-    pub mod keypoint;
-    pub mod connection;
-    pub mod boundary_condition;
-    pub mod pointload;
-    //pub mod material;
-}
-
-mod fe_engine {
-    pub mod global_stiffness_matrix;
-    pub mod dof_filter_vector;
-    pub mod force_vector;
-    pub mod utils;
-}
-
-mod material_formulation {
-    pub mod local_stiffness_matrix_bar;
-}
-
-mod output {
-    pub mod canvas;
-    pub mod figures;
-    pub mod content_and_labels;
-    pub mod keypoint_results;
-    
-}
-
-mod data_formatting {
-    pub mod generate_result_structs;
-}
-
-use input::keypoint::{parse_keypoint};
-use input::connection::{parse_connection};
-use input::boundary_condition::{parse_boundary_condition};
-use input::pointload::{parse_pointload};
-//use input::material::{parse_material, Material};
-use fe_engine::global_stiffness_matrix::{
+use rust_fe_calculator::input::keypoint::{parse_keypoint};
+use rust_fe_calculator::input::connection::{parse_connection};
+use rust_fe_calculator::input::section::{Section, parse_section, assign_section_properties};
+use rust_fe_calculator::input::sizing_group::{parse_sizing_group, parse_sizing_candidates};
+use rust_fe_calculator::input::sweep::{parse_sweep_parameter, parse_sweep_output};
+use rust_fe_calculator::input::random_variable::{parse_random_variable, parse_reliability_output};
+use rust_fe_calculator::input::sensitivity_response::parse_sensitivity_response;
+use rust_fe_calculator::input::distributed_load::{parse_distributed_load};
+use rust_fe_calculator::input::time_load::{parse_time_load};
+use rust_fe_calculator::input::spectrum::{parse_spectrum};
+use rust_fe_calculator::input::deck_path::{parse_deck_path};
+use rust_fe_calculator::input::influence_line::{parse_influence_line};
+use rust_fe_calculator::input::vehicle::{parse_vehicle};
+use rust_fe_calculator::input::steel_member::{parse_steel_member};
+use rust_fe_calculator::input::timber_class::{parse_timber_class};
+use rust_fe_calculator::input::timber_member::{parse_timber_member, check_timber_materials};
+use rust_fe_calculator::input::boundary_condition::{parse_boundary_condition};
+use rust_fe_calculator::input::pointload::{parse_pointload};
+use rust_fe_calculator::input::material::{Material, parse_material, assign_material_properties, assign_surface_material_properties};
+use rust_fe_calculator::input::space_keypoint::parse_space_keypoint;
+use rust_fe_calculator::input::space_pointload::parse_space_pointload;
+use rust_fe_calculator::input::orientation::{parse_orientation, assign_orientations};
+use rust_fe_calculator::input::release::{parse_release, assign_releases};
+use rust_fe_calculator::input::surface_element::parse_surface_element;
+use rust_fe_calculator::input::mesh_region::{parse_mesh_region, parse_mesh_refinement, parse_edge_boundary_condition};
+use rust_fe_calculator::input::load_case::{parse_load_case};
+use rust_fe_calculator::fe_engine::global_stiffness_matrix::{
     create_global_stiffness_matrix,
     apply_boundary_conditions, 
     calculate_resulting_displacement_vector,
     calculate_resulting_force_vector};
-use fe_engine::dof_filter_vector::{create_dof_filter_vector};
-use fe_engine::force_vector::{create_force_vector};
-use output::figures::{geometry_plot, reaction_plot};
-use output::keypoint_results::eksport_keypoint_structs;
-use data_formatting::generate_result_structs::{generate_result_keypoint};
+use rust_fe_calculator::fe_engine::dof_filter_vector::{create_dof_filter_vector};
+use rust_fe_calculator::fe_engine::force_vector::{create_force_vector};
+use rust_fe_calculator::output::canvas::PlotSettings;
+use rust_fe_calculator::fe_engine::self_weight::{create_self_weight_vector, calculate_total_load};
+use rust_fe_calculator::fe_engine::distributed_load_vector::{create_distributed_load_vector};
+use rust_fe_calculator::fe_engine::utils::{dofs_per_keypoint};
+use rust_fe_calculator::fe_engine::global_mass_matrix::{create_global_mass_matrix};
+use rust_fe_calculator::fe_engine::modal_analysis::{solve_modal_analysis};
+use rust_fe_calculator::fe_engine::global_geometric_stiffness_matrix::{create_global_geometric_stiffness_matrix};
+use rust_fe_calculator::fe_engine::buckling_analysis::{solve_buckling_analysis};
+use rust_fe_calculator::fe_engine::time_load_vector::{create_time_load_vector};
+use rust_fe_calculator::fe_engine::transient_analysis::{TimeIntegration, create_rayleigh_damping_matrix, solve_transient_analysis};
+use rust_fe_calculator::fe_engine::harmonic_analysis::{solve_harmonic_analysis};
+use rust_fe_calculator::fe_engine::space_analysis::{solve_space_linear_static, space_dofs_per_keypoint};
+use rust_fe_calculator::fe_engine::plane_analysis::solve_plane_linear_static;
+use rust_fe_calculator::fe_engine::response_spectrum::{create_influence_vector, participation_factor, calculate_modal_displacements};
+use rust_fe_calculator::fe_engine::linear_static::Model;
+use rust_fe_calculator::design::member_sizing::MemberDesign;
+use rust_fe_calculator::output::figures::{geometry_plot, reaction_plot, member_force_plot, deformed_shape_plot, frequency_response_plot, 
+    influence_line_plot, utilization_plot, topology_plot, histogram_plot, space_geometry_plot, space_deformed_shape_plot,
+    surface_geometry_plot, contour_plot};
+use rust_fe_calculator::output::keypoint_results::eksport_keypoint_structs;
+use rust_fe_calculator::output::connection_results::eksport_connection_structs;
+use rust_fe_calculator::output::modal_results::eksport_mode_results;
+use rust_fe_calculator::output::buckling_results::eksport_buckling_mode_results;
+use rust_fe_calculator::output::transient_results::{eksport_keypoint_histories, eksport_keypoint_histories_csv};
+use rust_fe_calculator::output::harmonic_results::eksport_frequency_responses;
+use rust_fe_calculator::output::response_spectrum_results::eksport_response_spectrum_results;
+use rust_fe_calculator::output::influence_line_results::eksport_influence_line_results;
+use rust_fe_calculator::output::moving_load_results::eksport_moving_load_results;
+use rust_fe_calculator::output::design_results::{eksport_steel_check_results, eksport_timber_check_results, eksport_sizing_results};
+use rust_fe_calculator::output::topology_results::{eksport_topology_results, write_topology_input_files};
+use rust_fe_calculator::output::model_files::write_model_input_files;
+use rust_fe_calculator::output::sweep_results::eksport_parameter_sweep_csv;
+use rust_fe_calculator::output::reliability_results::eksport_reliability_results;
+use rust_fe_calculator::output::space_results::{eksport_space_keypoint_structs, eksport_space_member_end_forces};
+use rust_fe_calculator::output::surface_results::{eksport_surface_element_structs, eksport_nodal_stresses};
+use rust_fe_calculator::output::sensitivity_results::{eksport_sensitivity_results, eksport_sensitivity_tables_csv};
+use rust_fe_calculator::generators::truss::{generate_truss, generate_truss_supports, TRUSS_TYPES};
+use rust_fe_calculator::generators::building::{generate_building_frame, generate_building_supports, generate_storey_loads, BRACING_PATTERNS};
+use rust_fe_calculator::generators::mesh::{generate_mesh, add_mesh_to_model, generate_edge_boundary_conditions};
+use rust_fe_calculator::data_formatting::generate_result_structs::{generate_result_keypoint, generate_result_connection, 
+    generate_displaced_keypoints, generate_result_modes, generate_result_buckling_modes,
+    generate_result_keypoint_histories, generate_result_frequency_responses, generate_result_response_spectrum,
+    generate_result_influence_lines, generate_result_moving_loads, generate_result_steel_checks,
+    generate_result_timber_checks, generate_result_member_sizing, generate_result_topology_optimisation,
+    generate_result_parameter_sweep, generate_result_reliability, generate_result_sensitivities,
+    generate_result_space_keypoint, generate_result_space_connection, generate_result_surface_element,
+    generate_result_nodal_stress};
 
 // Hardcoding material parameters, 
 // A=Area
-// E=Stiffness
+// I=Second moment of area (only used by frame elements)
+// A and I are the default section, used by connections without a section from the section library.
+// The example inputs use kN and m.
 const MATERIAL_AREA: f64 = 0.1;
-const MATERIAL_E_MODULE: f64 = 210000.0;
+const MATERIAL_MOMENT_OF_INERTIA: f64 = 0.001;
+// Poisson's ratio, giving the shear modulus G = E / (2 * (1 + v)) for the torsion of 3D frame elements.
+const MATERIAL_POISSON_RATIO: f64 = 0.3;
+const ACTIVE_LOAD_CASE: &str = "lc1";
+// Mass formulation for modal analysis: "lumped" or "consistent".
+const MASS_FORMULATION: &str = "lumped";
+const MODAL_NUMBER_OF_MODES: usize = 3;
+const BUCKLING_NUMBER_OF_MODES: usize = 3;
+// Transient analysis. HHT alpha between -1/3 and 0. Zero gives the Newmark average acceleration method.
+// Rayleigh damping, C = a_0*M + a_1*K.
+const TRANSIENT_TIME_STEP: f64 = 0.01;
+const TRANSIENT_END_TIME: f64 = 5.0;
+const HHT_ALPHA: f64 = -0.05;
+const RAYLEIGH_MASS_COEFFICIENT: f64 = 0.1;
+const RAYLEIGH_STIFFNESS_COEFFICIENT: f64 = 0.001;
+// Harmonic analysis. Frequency sweep in Hz, with the pointloads as load amplitudes.
+const HARMONIC_START_FREQUENCY: f64 = 0.1;
+const HARMONIC_END_FREQUENCY: f64 = 10.0;
+const HARMONIC_NUMBER_OF_FREQUENCIES: usize = 200;
+const HARMONIC_PLOT_KEYPOINT: &str = "kp6";
+// Response spectrum analysis. Base excitation direction and modal damping ratio for CQC.
+const SPECTRUM_DIRECTION_X: f64 = 1.0;
+const SPECTRUM_DIRECTION_Y: f64 = 0.0;
+const SPECTRUM_DAMPING_RATIO: f64 = 0.05;
+// Influence lines. Number of load positions between each pair of deck keypoints.
+const INFLUENCE_LINE_STEPS_PER_SEGMENT: usize = 10;
+// Moving loads. Distance the vehicle travels between each step.
+const VEHICLE_STEP: f64 = 0.1;
+// Steel design. Partial factors for cross-section resistance and member buckling resistance.
+const STEEL_GAMMA_M0: f64 = 1.0;
+const STEEL_GAMMA_M1: f64 = 1.0;
+// Member sizing. Maximum number of re-analyses before stopping.
+const SIZING_MAX_ITERATIONS: usize = 20;
+// Topology optimisation. The ground structure connects all keypoints, and bars are fully stressed to the allowable stress (kN/m2).
+// Bars with an area below the removal ratio of the largest area are removed.
+const TOPOLOGY_MATERIAL: &str = "mat1";
+const TOPOLOGY_ALLOWABLE_STRESS: f64 = 235000.0;
+const TOPOLOGY_REMOVAL_RATIO: f64 = 1e-3;
 const PLOT_GEOMETRY_OUTPUT_PATH: &str = "outputs/geometry_plot.png";
 const PLOT_REACTION_OUTPUT_PATH: &str = "outputs/reaction_plot.png";
+const PLOT_NORMAL_FORCE_OUTPUT_PATH: &str = "outputs/normal_force_plot.png";
+const PLOT_SHEAR_FORCE_OUTPUT_PATH: &str = "outputs/shear_force_plot.png";
+const PLOT_BENDING_MOMENT_OUTPUT_PATH: &str = "outputs/bending_moment_plot.png";
 const SAVE_KEYPOINT_STRUCTS_PATH: &str = "outputs/keypoint_result_data.json";
+const SAVE_CONNECTION_STRUCTS_PATH: &str = "outputs/connection_result_data.json";
+const SAVE_STEEL_CHECK_RESULTS_PATH: &str = "outputs/steel_check_result_data.json";
+const SAVE_TIMBER_CHECK_RESULTS_PATH: &str = "outputs/timber_check_result_data.json";
+const SAVE_SIZING_RESULTS_PATH: &str = "outputs/sizing_result_data.json";
+const SAVE_TOPOLOGY_RESULTS_PATH: &str = "outputs/topology_result_data.json";
+const SAVE_TOPOLOGY_KEYPOINTS_PATH: &str = "outputs/topology_keypoints.txt";
+const SAVE_TOPOLOGY_CONNECTIONS_PATH: &str = "outputs/topology_connections.txt";
+const SAVE_TOPOLOGY_BCS_PATH: &str = "outputs/topology_bcs.txt";
+const PLOT_TOPOLOGY_OUTPUT_PATH: &str = "outputs/topology_plot.png";
+const PLOT_UTILIZATION_OUTPUT_PATH: &str = "outputs/utilization_plot.png";
+const SAVE_MODE_RESULTS_PATH: &str = "outputs/modal_result_data.json";
+const PLOT_MODE_SHAPE_OUTPUT_PATH: &str = "outputs/mode_shape_plot";
+const SAVE_BUCKLING_RESULTS_PATH: &str = "outputs/buckling_result_data.json";
+const PLOT_BUCKLING_MODE_OUTPUT_PATH: &str = "outputs/buckling_mode_plot";
+const SAVE_TRANSIENT_RESULTS_PATH: &str = "outputs/transient_result_data.json";
+const SAVE_TRANSIENT_RESULTS_CSV_PATH: &str = "outputs/transient_result_data.csv";
+const SAVE_HARMONIC_RESULTS_PATH: &str = "outputs/harmonic_result_data.json";
+const PLOT_FREQUENCY_RESPONSE_OUTPUT_PATH: &str = "outputs/frequency_response_plot.png";
+const SAVE_RESPONSE_SPECTRUM_RESULTS_PATH: &str = "outputs/response_spectrum_result_data.json";
+const SAVE_INFLUENCE_LINE_RESULTS_PATH: &str = "outputs/influence_line_data.json";
+const PLOT_INFLUENCE_LINE_OUTPUT_PATH: &str = "outputs/influence_line_plot";
+const SAVE_MOVING_LOAD_RESULTS_PATH: &str = "outputs/moving_load_result_data.json";
+const SAVE_PARAMETER_SWEEP_RESULTS_PATH: &str = "outputs/parameter_sweep_results.csv";
+const SAVE_RELIABILITY_RESULTS_PATH: &str = "outputs/reliability_result_data.json";
+const PLOT_HISTOGRAM_OUTPUT_PATH: &str = "outputs/histogram_plot";
+const SAVE_SENSITIVITY_RESULTS_PATH: &str = "outputs/sensitivity_result_data.json";
+const SAVE_SENSITIVITY_TABLES_PATH: &str = "outputs/sensitivity_table";
+// 3D models. Analysed separately from the 2D model, with their own input directories.
+// The results are saved with the output prefix, e.g. outputs/space_frame_keypoint_result_data.json.
+const SPACE_MODELS: [(&str, &str); 2] = [("inputs/space", "outputs/space"), ("inputs/space_frame", "outputs/space_frame")];
+const PLOT_SPACE_DIMENSION: (u32, u32) = (600, 600);
+// Plane stress/strain models with surface elements, analysed in the same way as the 3D models.
+// Contour plots are made for each stress quantity, e.g. outputs/plane_von_mises_contour_plot.png.
+const PLANE_MODELS: [(&str, &str); 2] = [("inputs/plane", "outputs/plane"), ("inputs/plane_mesh", "outputs/plane_mesh")];
+const PLANE_CONTOUR_QUANTITIES: [&str; 4] = ["von_mises", "sx", "sy", "txy"];
+const PLOT_PLANE_DIMENSION: (u32, u32) = (600, 600);
+// Model generators. Generated models are written as input files to this directory.
+const GENERATOR_OUTPUT_DIRECTORY: &str = "outputs/generated";
+// Monte Carlo reliability analysis. The seed makes the samples reproducible.
+const MONTE_CARLO_SAMPLES: usize = 1000;
+const MONTE_CARLO_SEED: u64 = 42;
+const MONTE_CARLO_HISTOGRAM_BINS: usize = 20;
 const PLOT_DIMENSION: (u32, u32) = (800, 300);
 const PLOT_RESULT_SCALE: f32 = 1.0;
 const PLOT_FEATURE_SIZE: f32 = 2.0;
 const PLOT_RESULT_DECIMALS: usize = 2;
+const DIAGRAM_SAMPLES: usize = 11;
+
+fn plot_settings<'a>(output_path:&'a str, dimension:(u32, u32), chart_title:&'a str) -> PlotSettings<'a> {
+    PlotSettings {output_path, dimension, chart_title, plot_feature_size: PLOT_FEATURE_SIZE, plot_result_decimals: PLOT_RESULT_DECIMALS}
+}
+
+const GENERATOR_USAGE: &str = "Usage:
+  rust-fe-calculator                 Analyse the model in the inputs folder.
+  rust-fe-calculator truss <type> <span> <height> <panels> <chord material> <web material> [output directory]
+                                     Generate a pratt, howe, warren or k truss.
+  rust-fe-calculator building <bays> <bay width> <storeys> <storey height> <bracing> <material> <floor load> <storey load> [output directory]
+                                     Generate a building frame with none, diagonal, x or chevron bracing in the outer bays.";
+
+// Running the linear static analysis of a 3D model, from the input files in the input directory.
+// Orientation vectors and end releases are only used by frame elements.
+fn run_space_analysis(input_directory:&str, output_prefix:&str, section_list:&[Section], mat_list:&[Material]) {
+    let mut space_kp_list = parse_space_keypoint(&format!("{}/keypoints.txt", input_directory));
+    let mut space_conn_list = parse_connection(&format!("{}/connections.txt", input_directory));
+    assign_section_properties(&mut space_conn_list, section_list, MATERIAL_AREA, MATERIAL_MOMENT_OF_INERTIA);
+    assign_material_properties(&mut space_conn_list, mat_list);
+    assign_orientations(&mut space_conn_list, &parse_orientation(&format!("{}/orientations.txt", input_directory)));
+    assign_releases(&mut space_conn_list, &parse_release(&format!("{}/releases.txt", input_directory)));
+    let space_bc_list = parse_boundary_condition(&format!("{}/bcs.txt", input_directory));
+    let space_pl_list = parse_space_pointload(&format!("{}/pointloads.txt", input_directory));
+
+    if space_kp_list.is_empty() {
+        return;
+    }
+
+    let _ = space_geometry_plot(&space_kp_list,
+                                &space_conn_list,
+                                &space_bc_list,
+                                &space_pl_list,
+                                &plot_settings(&format!("{}_geometry_plot.png", output_prefix),
+                                               PLOT_SPACE_DIMENSION,
+                                               "3D Geometry Plot"));
+
+    let space_dofs = space_dofs_per_keypoint(&space_conn_list);
+    let (space_displacement_vector, space_force_vector) = solve_space_linear_static(&space_kp_list, &space_conn_list,
+                                                                                   &space_bc_list, &space_pl_list,
+                                                                                   MATERIAL_POISSON_RATIO);
+    generate_result_space_keypoint(&mut space_kp_list, &space_force_vector, &space_displacement_vector, space_dofs);
+    let member_end_forces = generate_result_space_connection(&mut space_conn_list, &space_kp_list, &space_displacement_vector,
+                                                             space_dofs, MATERIAL_POISSON_RATIO);
+    println!("Resulting 3D keypoint forces and displacements of {}:\n{:#?}", input_directory, space_kp_list);
+
+    let _ = space_deformed_shape_plot(&space_kp_list,
+                                      &space_conn_list,
+                                      &space_bc_list,
+                                      &plot_settings(&format!("{}_deformed_shape_plot.png", output_prefix),
+                                                     PLOT_SPACE_DIMENSION,
+                                                     "3D Deformed Shape Plot"));
+    eksport_space_keypoint_structs(&space_kp_list, &format!("{}_keypoint_result_data.json", output_prefix));
+    eksport_connection_structs(&space_conn_list, &format!("{}_connection_result_data.json", output_prefix));
+    eksport_space_member_end_forces(&member_end_forces, &format!("{}_member_end_forces.json", output_prefix));
+}
+
+// Running a generator command from the command line arguments.
+// The generated model is written as input files and plotted, without running the analysis.
+fn run_generator_command(args:&[String]) {
+    let (kp_list, conn_list, bc_list, pl_list, output_directory) = match args[0].as_str() {
+        "truss" if args.len() == 7 || args.len() == 8 => {
+            let truss_type:&str = &args[1];
+            if !TRUSS_TYPES.contains(&truss_type) {
+                println!("Unknown truss type {}. Available types: {:?}", truss_type, TRUSS_TYPES);
+                return;
+            }
+            let span:f64 = args[2].parse().expect("The span must be a number");
+            let height:f64 = args[3].parse().expect("The height must be a number");
+            let panels:usize = args[4].parse().expect("The number of panels must be a whole number");
+            let (kp_list, conn_list) = generate_truss(truss_type, span, height, panels, &args[5], &args[6]);
+            let bc_list = generate_truss_supports(panels);
+            (kp_list, conn_list, bc_list, Vec::new(), args.get(7).map_or(GENERATOR_OUTPUT_DIRECTORY, |arg| arg.as_str()))
+        },
+        "building" if args.len() == 9 || args.len() == 10 => {
+            let bays:usize = args[1].parse().expect("The number of bays must be a whole number");
+            let bay_width:f64 = args[2].parse().expect("The bay width must be a number");
+            let storeys:usize = args[3].parse().expect("The number of storeys must be a whole number");
+            let storey_height:f64 = args[4].parse().expect("The storey height must be a number");
+            let bracing:&str = &args[5];
+            if !BRACING_PATTERNS.contains(&bracing) {
+                println!("Unknown bracing pattern {}. Available patterns: {:?}", bracing, BRACING_PATTERNS);
+                return;
+            }
+            let floor_load:f64 = args[7].parse().expect("The floor load must be a number");
+            let storey_load:f64 = args[8].parse().expect("The storey load must be a number");
+
+            let bay_widths:Vec<f64> = vec![bay_width; bays];
+            let storey_heights:Vec<f64> = vec![storey_height; storeys];
+            let mut braced_bays:Vec<usize> = vec![0, bays.saturating_sub(1)];
+            braced_bays.dedup();
+
+            let (kp_list, conn_list) = generate_building_frame(&bay_widths, &storey_heights, bracing, &braced_bays, &args[6], &args[6], &args[6]);
+            let bc_list = generate_building_supports(&bay_widths);
+            let pl_list = generate_storey_loads(&bay_widths, &storey_heights, floor_load, storey_load);
+            (kp_list, conn_list, bc_list, pl_list, args.get(9).map_or(GENERATOR_OUTPUT_DIRECTORY, |arg| arg.as_str()))
+        },
+        _ => {
+            println!("{}", GENERATOR_USAGE);
+            return;
+        }
+    };
+
+    write_model_input_files(&kp_list, &conn_list, &bc_list, &pl_list, output_directory);
+    let _ = geometry_plot(&kp_list,
+                          &conn_list,
+                          &bc_list,
+                          &pl_list,
+                          &plot_settings(&format!("{}/geometry_plot.png", output_directory),
+                                         PLOT_DIMENSION,
+                                         "Geometry Plot"));
+    println!("Generated {} keypoints, {} connections, {} boundary conditions and {} pointloads in {}.",
+             kp_list.len(), conn_list.len(), bc_list.len(), pl_list.len(), output_directory);
+}
+
+fn run_plane_analysis(input_directory:&str, output_prefix:&str, section_list:&[Section], mat_list:&[Material]) {
+    let mut plane_kp_list = parse_keypoint(&format!("{}/keypoints.txt", input_directory));
+    let mut plane_conn_list = parse_connection(&format!("{}/connections.txt", input_directory));
+    assign_section_properties(&mut plane_conn_list, section_list, MATERIAL_AREA, MATERIAL_MOMENT_OF_INERTIA);
+    assign_material_properties(&mut plane_conn_list, mat_list);
+    assign_releases(&mut plane_conn_list, &parse_release(&format!("{}/releases.txt", input_directory)));
+    let mut surface_list = parse_surface_element(&format!("{}/surfaces.txt", input_directory));
+    let mut plane_bc_list = parse_boundary_condition(&format!("{}/bcs.txt", input_directory));
+    let plane_pl_list = parse_pointload(&format!("{}/pointloads.txt", input_directory));
+
+    // Meshing the regions, and supporting the mesh keypoints along the edge bcs.
+    let mesh_refinements = parse_mesh_refinement(&format!("{}/refinements.txt", input_directory));
+    for region in parse_mesh_region(&format!("{}/regions.txt", input_directory)) {
+        let (mesh_kp_list, mesh_surface_list) = generate_mesh(&region, &plane_kp_list, &mesh_refinements);
+        add_mesh_to_model(&mut plane_kp_list, &mut surface_list, mesh_kp_list, mesh_surface_list);
+    }
+    assign_surface_material_properties(&mut surface_list, mat_list);
+    let edge_bc_list = parse_edge_boundary_condition(&format!("{}/edgebcs.txt", input_directory));
+    plane_bc_list.extend(generate_edge_boundary_conditions(&edge_bc_list, &plane_kp_list));
+
+    if plane_kp_list.is_empty() {
+        return;
+    }
+
+    let _ = surface_geometry_plot(&plane_kp_list,
+                                  &plane_conn_list,
+                                  &surface_list,
+                                  &plane_bc_list,
+                                  &plane_pl_list,
+                                  &plot_settings(&format!("{}_geometry_plot.png", output_prefix),
+                                                 PLOT_PLANE_DIMENSION,
+                                                 "Plane Geometry Plot"));
+
+    let plane_dofs = dofs_per_keypoint(&plane_conn_list);
+    let (plane_displacement_vector, plane_force_vector) = solve_plane_linear_static(&plane_kp_list, &plane_conn_list,
+                                                                                   &surface_list, &plane_bc_list,
+                                                                                   &plane_pl_list);
+    generate_result_keypoint(&mut plane_kp_list, &plane_force_vector, &plane_displacement_vector, plane_dofs);
+    generate_result_surface_element(&mut surface_list, &plane_kp_list, &plane_displacement_vector, plane_dofs);
+    let nodal_stresses = generate_result_nodal_stress(&plane_kp_list, &surface_list, &plane_displacement_vector,
+                                                      plane_dofs);
+    println!("Resulting surface element stresses of {}:\n{:#?}", input_directory, surface_list);
+
+    for quantity in PLANE_CONTOUR_QUANTITIES {
+        let _ = contour_plot(&plane_kp_list,
+                             &plane_conn_list,
+                             &surface_list,
+                             &nodal_stresses,
+                             quantity,
+                             &plane_bc_list,
+                             &plot_settings(&format!("{}_{}_contour_plot.png", output_prefix, quantity),
+                                            PLOT_PLANE_DIMENSION,
+                                            &format!("Contour Plot {}", quantity)));
+    }
+    eksport_keypoint_structs(&plane_kp_list, &format!("{}_keypoint_result_data.json", output_prefix));
+    eksport_surface_element_structs(&surface_list, &format!("{}_surface_result_data.json", output_prefix));
+    eksport_nodal_stresses(&nodal_stresses, &format!("{}_nodal_stress_data.json", output_prefix));
+}
 
 fn main() {
+    // Generator commands write a model to input files instead of analysing the inputs folder.
+    let args:Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        run_generator_command(&args);
+        return;
+    }
+
     let mut kp_list = parse_keypoint("inputs/keypoints.txt");
-    let conn_list = parse_connection("inputs/connections.txt");
+    let mut conn_list = parse_connection("inputs/connections.txt");
+    let section_list = parse_section("inputs/sections.csv");
+    assign_section_properties(&mut conn_list, &section_list, MATERIAL_AREA, MATERIAL_MOMENT_OF_INERTIA);
+    let release_list = parse_release("inputs/releases.txt");
+    assign_releases(&mut conn_list, &release_list);
     let bc_list = parse_boundary_condition("inputs/bcs.txt");
     let pl_list = parse_pointload("inputs/pointloads.txt");
-    //let mat_list = parse_material("inputs/materials.txt");
+    let dl_list = parse_distributed_load("inputs/distributedloads.txt");
+    let tl_list = parse_time_load("inputs/timeloads.txt");
+    let spectrum = parse_spectrum("inputs/spectrum.txt");
+    let deck_paths = parse_deck_path("inputs/deckpaths.txt");
+    let il_list = parse_influence_line("inputs/influencelines.txt");
+    let vehicle_list = parse_vehicle("inputs/vehicles.txt");
+    let steel_member_list = parse_steel_member("inputs/steelmembers.txt");
+    let timber_class_list = parse_timber_class("inputs/timberclasses.txt");
+    let timber_member_list = parse_timber_member("inputs/timbermembers.txt");
+    let sizing_group_list = parse_sizing_group("inputs/sizinggroups.txt");
+    let sizing_candidate_list = parse_sizing_candidates("inputs/sizingcandidates.txt");
+    let sweep_parameter_list = parse_sweep_parameter("inputs/sweeps.txt");
+    let sweep_output_list = parse_sweep_output("inputs/sweepoutputs.txt");
+    let random_variable_list = parse_random_variable("inputs/randomvariables.txt");
+    let reliability_output_list = parse_reliability_output("inputs/reliabilityoutputs.txt");
+    let sensitivity_response_list = parse_sensitivity_response("inputs/sensitivities.txt");
+    let mat_list = parse_material("inputs/materials.txt");
+    let lc_list = parse_load_case("inputs/loadcases.txt");
+    assign_material_properties(&mut conn_list, &mat_list);
+    check_timber_materials(&conn_list, &mat_list, &timber_member_list, &timber_class_list);
 
     println!("Parsed Keypoints:\n{:#?}", kp_list);
     println!("Parsed Connections:\n{:#?}", conn_list);
+    println!("Parsed Sections:\n{:#?}", section_list);
+    println!("Parsed Releases:\n{:#?}", release_list);
     println!("Parsed Boundary Conditions:\n{:#?}", bc_list);
     println!("Parsed Pointloads:\n{:#?}", pl_list);
-    //println!("Parsed Materials:\n{:#?}", mat_list);
+    println!("Parsed Distributed Loads:\n{:#?}", dl_list);
+    println!("Parsed Time Loads:\n{:#?}", tl_list);
+    println!("Parsed Response Spectrum:\n{:#?}", spectrum);
+    println!("Parsed Deck Paths:\n{:#?}", deck_paths);
+    println!("Parsed Influence Lines:\n{:#?}", il_list);
+    println!("Parsed Vehicles:\n{:#?}", vehicle_list);
+    println!("Parsed Steel Members:\n{:#?}", steel_member_list);
+    println!("Parsed Timber Classes:\n{:#?}", timber_class_list);
+    println!("Parsed Timber Members:\n{:#?}", timber_member_list);
+    println!("Parsed Sizing Groups:\n{:#?}", sizing_group_list);
+    println!("Parsed Sizing Candidates:\n{:#?}", sizing_candidate_list);
+    println!("Parsed Sweep Parameters:\n{:#?}", sweep_parameter_list);
+    println!("Parsed Sweep Outputs:\n{:#?}", sweep_output_list);
+    println!("Parsed Random Variables:\n{:#?}", random_variable_list);
+    println!("Parsed Reliability Outputs:\n{:#?}", reliability_output_list);
+    println!("Parsed Sensitivity Responses:\n{:#?}", sensitivity_response_list);
+    println!("Parsed Materials:\n{:#?}", mat_list);
+    println!("Parsed Load Cases:\n{:#?}", lc_list);
 
     let _ = geometry_plot(&kp_list, 
                           &conn_list, 
                           &bc_list, 
                           &pl_list, 
-                          PLOT_FEATURE_SIZE,
-                          &PLOT_GEOMETRY_OUTPUT_PATH,
-                          PLOT_DIMENSION,
-                          &"Geometry Plot");
+                          &plot_settings(PLOT_GEOMETRY_OUTPUT_PATH,
+                                         PLOT_DIMENSION,
+                                         "Geometry Plot"));
 
-    let global_stiffness_matrix = create_global_stiffness_matrix(&kp_list, &conn_list, MATERIAL_E_MODULE, MATERIAL_AREA);
+    // Models with frame elements have a rotational dof in each keypoint.
+    let dofs = dofs_per_keypoint(&conn_list);
+
+    let global_stiffness_matrix = create_global_stiffness_matrix(&kp_list, &conn_list);
     println!("Global stiffness matrix:\n{}", global_stiffness_matrix);
 
-    let dof_filter_vector = create_dof_filter_vector(&kp_list, &bc_list);
+    let dof_filter_vector = create_dof_filter_vector(&kp_list, &conn_list, &bc_list);
     println!("DOF filter vector:\n{}", dof_filter_vector);
 
-    let force_vector = create_force_vector(&kp_list, &pl_list);
+    let load_case = lc_list.iter().find(|lc| lc.name == ACTIVE_LOAD_CASE).unwrap();
+
+    let pointload_vector = create_force_vector(&kp_list, &pl_list, dofs);
+    let self_weight_vector = create_self_weight_vector(&kp_list, &conn_list, &mat_list, load_case, dofs);
+    let distributed_load_vector = create_distributed_load_vector(&kp_list, &conn_list, &dl_list, dofs);
+    let force_vector = &pointload_vector + &self_weight_vector + &distributed_load_vector;
     println!("Force vector:\n{}", force_vector);
 
+    let (pointload_x, pointload_y) = calculate_total_load(&pointload_vector, dofs);
+    let (self_weight_x, self_weight_y) = calculate_total_load(&self_weight_vector, dofs);
+    let (distributed_load_x, distributed_load_y) = calculate_total_load(&distributed_load_vector, dofs);
+    let (total_x, total_y) = calculate_total_load(&force_vector, dofs);
+    println!("Load summary for load case {}:", load_case.name);
+    println!("  Pointloads:  ({:.2}, {:.2})", pointload_x, pointload_y);
+    println!("  Self-weight: ({:.2}, {:.2})", self_weight_x, self_weight_y);
+    println!("  Distributed: ({:.2}, {:.2})", distributed_load_x, distributed_load_y);
+    println!("  Total:       ({:.2}, {:.2})", total_x, total_y);
+
     let modified_global_stiffness_matrix = apply_boundary_conditions(&global_stiffness_matrix, &dof_filter_vector);
     println!("Modified global stiffness matrix:\n{}", modified_global_stiffness_matrix);
 
@@ -98,19 +455,257 @@ fn main() {
     let resulting_force_vector = calculate_resulting_force_vector(&global_stiffness_matrix, &resulting_displacement_vector);
     println!("Resulting Force Vector:\n{}", resulting_force_vector);
 
-    generate_result_keypoint(&mut kp_list, &resulting_force_vector, &resulting_displacement_vector);
+    generate_result_keypoint(&mut kp_list, &resulting_force_vector, &resulting_displacement_vector, dofs);
     println!("Resulting keypoint forces and displacements:\n{:#?}", kp_list);
 
+    generate_result_connection(&mut conn_list, &kp_list, &resulting_displacement_vector, dofs,
+                               &dl_list, DIAGRAM_SAMPLES);
+    println!("Resulting connection member forces:\n{:#?}", conn_list);
+
     let _ = reaction_plot(&kp_list, 
                           &conn_list, 
                           &bc_list, 
                           &pl_list, 
-                          PLOT_FEATURE_SIZE,
-                          &PLOT_REACTION_OUTPUT_PATH,
-                          PLOT_DIMENSION,
-                          &"Reaction Plot",
-                          PLOT_RESULT_SCALE,
-                          PLOT_RESULT_DECIMALS);
+                          &plot_settings(PLOT_REACTION_OUTPUT_PATH,
+                                         PLOT_DIMENSION,
+                                         "Reaction Plot"),
+                          PLOT_RESULT_SCALE);
+
+    for (diagram_type, output_path, chart_title) in [("N", PLOT_NORMAL_FORCE_OUTPUT_PATH, "Normal Force Plot"),
+                                                     ("V", PLOT_SHEAR_FORCE_OUTPUT_PATH, "Shear Force Plot"),
+                                                     ("M", PLOT_BENDING_MOMENT_OUTPUT_PATH, "Bending Moment Plot")] {
+        let _ = member_force_plot(&kp_list,
+                                  &conn_list,
+                                  &bc_list,
+                                  &plot_settings(output_path,
+                                                 PLOT_DIMENSION,
+                                                 chart_title),
+                                  diagram_type);
+    }
+
+    // Steel and timber member checks. The utilization is stored on the connections.
+    let steel_check_result = generate_result_steel_checks(&mut conn_list, &steel_member_list, &kp_list,
+                                                          STEEL_GAMMA_M0, STEEL_GAMMA_M1);
+    println!("Steel checks: {} of {} members passed. Max utilization {:.3} in {}.",
+             steel_check_result.checked_members - steel_check_result.failed_members.len(),
+             steel_check_result.checked_members,
+             steel_check_result.max_utilization,
+             steel_check_result.governing_connection);
+    if !steel_check_result.passed {
+        println!("Failed members: {:?}", steel_check_result.failed_members);
+    }
+    let timber_check_result = generate_result_timber_checks(&mut conn_list, &timber_member_list, &timber_class_list, &kp_list);
+    println!("Timber checks: {} of {} members passed. Max utilization {:.3} in {}.",
+             timber_check_result.checked_members - timber_check_result.failed_members.len(),
+             timber_check_result.checked_members,
+             timber_check_result.max_utilization,
+             timber_check_result.governing_connection);
+    if !timber_check_result.passed {
+        println!("Failed members: {:?}", timber_check_result.failed_members);
+    }
+    let _ = utilization_plot(&kp_list,
+                             &conn_list,
+                             &bc_list,
+                             &plot_settings(PLOT_UTILIZATION_OUTPUT_PATH,
+                                            PLOT_DIMENSION,
+                                            "Utilization Plot"));
+    eksport_steel_check_results(&steel_check_result, SAVE_STEEL_CHECK_RESULTS_PATH);
+    eksport_timber_check_results(&timber_check_result, SAVE_TIMBER_CHECK_RESULTS_PATH);
 
     eksport_keypoint_structs(&kp_list, SAVE_KEYPOINT_STRUCTS_PATH);
-}
\ No newline at end of file
+    eksport_connection_structs(&conn_list, SAVE_CONNECTION_STRUCTS_PATH);
+
+    // Modal analysis
+    let global_mass_matrix = create_global_mass_matrix(&kp_list, &conn_list, &mat_list, MASS_FORMULATION);
+    println!("Global mass matrix:\n{}", global_mass_matrix);
+
+    let modes = solve_modal_analysis(&modified_global_stiffness_matrix, &global_mass_matrix, &dof_filter_vector, MODAL_NUMBER_OF_MODES);
+    for mode in &modes {
+        println!("Mode {}: f = {:.4} Hz, T = {:.4} s", mode.number, mode.frequency, mode.period);
+
+        let mode_kp_list = generate_displaced_keypoints(&kp_list, &mode.shape, dofs);
+        let _ = deformed_shape_plot(&mode_kp_list,
+                                    &conn_list,
+                                    &bc_list,
+                                    &plot_settings(&format!("{}_{}.png", PLOT_MODE_SHAPE_OUTPUT_PATH, mode.number),
+                                                   PLOT_DIMENSION,
+                                                   &format!("Mode Shape {} (f = {:.2} Hz)", mode.number, mode.frequency)));
+    }
+
+    let mode_results = generate_result_modes(&kp_list, &modes, dofs);
+    eksport_mode_results(&mode_results, SAVE_MODE_RESULTS_PATH);
+
+    // Linear buckling analysis, based on the normal forces of the linear analysis.
+    let global_geometric_stiffness_matrix = create_global_geometric_stiffness_matrix(&kp_list, &conn_list);
+    println!("Global geometric stiffness matrix:\n{}", global_geometric_stiffness_matrix);
+
+    let buckling_modes = solve_buckling_analysis(&modified_global_stiffness_matrix, &global_geometric_stiffness_matrix, &dof_filter_vector, BUCKLING_NUMBER_OF_MODES);
+    for mode in &buckling_modes {
+        println!("Buckling mode {}: load factor = {:.4}", mode.number, mode.load_factor);
+
+        let mode_kp_list = generate_displaced_keypoints(&kp_list, &mode.shape, dofs);
+        let _ = deformed_shape_plot(&mode_kp_list,
+                                    &conn_list,
+                                    &bc_list,
+                                    &plot_settings(&format!("{}_{}.png", PLOT_BUCKLING_MODE_OUTPUT_PATH, mode.number),
+                                                   PLOT_DIMENSION,
+                                                   &format!("Buckling Mode {} (load factor = {:.2})", mode.number, mode.load_factor)));
+    }
+
+    let buckling_mode_results = generate_result_buckling_modes(&kp_list, &buckling_modes, dofs);
+    eksport_buckling_mode_results(&buckling_mode_results, SAVE_BUCKLING_RESULTS_PATH);
+
+    // Transient analysis, driven by the time-varying pointloads.
+    let global_damping_matrix = create_rayleigh_damping_matrix(&global_mass_matrix, &modified_global_stiffness_matrix,
+                                                               RAYLEIGH_MASS_COEFFICIENT, RAYLEIGH_STIFFNESS_COEFFICIENT);
+    let time_steps = solve_transient_analysis(&global_stiffness_matrix,
+                                              &modified_global_stiffness_matrix,
+                                              &global_mass_matrix,
+                                              &global_damping_matrix,
+                                              &dof_filter_vector,
+                                              |time| create_time_load_vector(&kp_list, &tl_list, time, dofs),
+                                              &TimeIntegration {time_step: TRANSIENT_TIME_STEP,
+                                                                end_time: TRANSIENT_END_TIME,
+                                                                hht_alpha: HHT_ALPHA});
+    println!("Transient analysis: {} time steps solved", time_steps.len());
+
+    let keypoint_histories = generate_result_keypoint_histories(&kp_list, &time_steps, dofs);
+    eksport_keypoint_histories(&keypoint_histories, SAVE_TRANSIENT_RESULTS_PATH);
+    eksport_keypoint_histories_csv(&keypoint_histories, SAVE_TRANSIENT_RESULTS_CSV_PATH);
+
+    // Harmonic analysis, with the pointloads as load amplitudes.
+    let frequencies:Vec<f64> = (0..HARMONIC_NUMBER_OF_FREQUENCIES)
+        .map(|i| HARMONIC_START_FREQUENCY + (HARMONIC_END_FREQUENCY - HARMONIC_START_FREQUENCY) * i as f64 / (HARMONIC_NUMBER_OF_FREQUENCIES - 1) as f64)
+        .collect();
+    let frequency_responses = solve_harmonic_analysis(&modified_global_stiffness_matrix,
+                                                      &global_mass_matrix,
+                                                      &global_damping_matrix,
+                                                      &dof_filter_vector,
+                                                      &pointload_vector,
+                                                      &frequencies);
+
+    let keypoint_frequency_responses = generate_result_frequency_responses(&kp_list, &frequency_responses, dofs);
+    eksport_frequency_responses(&keypoint_frequency_responses, SAVE_HARMONIC_RESULTS_PATH);
+
+    if let Some(keypoint_response) = keypoint_frequency_responses.iter().find(|response| response.name == HARMONIC_PLOT_KEYPOINT) {
+        let _ = frequency_response_plot(keypoint_response,
+                                        PLOT_FREQUENCY_RESPONSE_OUTPUT_PATH,
+                                        PLOT_DIMENSION,
+                                        &format!("Frequency Response {}", HARMONIC_PLOT_KEYPOINT));
+    }
+
+    // Response spectrum analysis, based on the modes of the modal analysis.
+    let influence_vector = create_influence_vector(&dof_filter_vector, dofs, SPECTRUM_DIRECTION_X, SPECTRUM_DIRECTION_Y);
+    let total_mass = (influence_vector.transpose() * &global_mass_matrix * &influence_vector)[(0, 0)];
+    for mode in &modes {
+        let gamma = participation_factor(mode, &global_mass_matrix, &influence_vector);
+        println!("Mode {}: participation factor = {:.4}, effective mass ratio = {:.4}", mode.number, gamma, gamma * gamma / total_mass);
+    }
+
+    let modal_displacements = calculate_modal_displacements(&modes, &global_mass_matrix, &influence_vector, &spectrum);
+    let response_spectrum_results:Vec<_> = ["srss", "cqc"].iter().map(|method| {
+        generate_result_response_spectrum(&kp_list, &conn_list, &bc_list, &modes, &modal_displacements,
+                                          SPECTRUM_DAMPING_RATIO, method)
+    }).collect();
+    eksport_response_spectrum_results(&response_spectrum_results, SAVE_RESPONSE_SPECTRUM_RESULTS_PATH);
+
+    // Influence lines for a downward unit load moving along the deck paths.
+    let influence_line_results = generate_result_influence_lines(&il_list, &deck_paths, &kp_list, &conn_list, &bc_list,
+                                                                 INFLUENCE_LINE_STEPS_PER_SEGMENT);
+    for influence_line_result in &influence_line_results {
+        let _ = influence_line_plot(influence_line_result,
+                                    &format!("{}_{}.png", PLOT_INFLUENCE_LINE_OUTPUT_PATH, influence_line_result.name),
+                                    PLOT_DIMENSION,
+                                    &format!("Influence Line {}", influence_line_result.name));
+    }
+    eksport_influence_line_results(&influence_line_results, SAVE_INFLUENCE_LINE_RESULTS_PATH);
+
+    // Envelopes of vehicles moving along the deck paths.
+    let moving_load_results = generate_result_moving_loads(&vehicle_list, &deck_paths, &kp_list, &conn_list, &bc_list,
+                                                           VEHICLE_STEP);
+    eksport_moving_load_results(&moving_load_results, SAVE_MOVING_LOAD_RESULTS_PATH);
+
+    // Member sizing. Re-solving the model with the lightest passing sections of each group.
+    let model = Model {
+        kp_list: &kp_list,
+        conn_list: &conn_list,
+        bc_list: &bc_list,
+        pl_list: &pl_list,
+        dl_list: &dl_list,
+        mat_list: &mat_list,
+        load_case,
+    };
+    let member_design = MemberDesign {
+        steel_members: &steel_member_list,
+        timber_members: &timber_member_list,
+        timber_classes: &timber_class_list,
+        gamma_m0: STEEL_GAMMA_M0,
+        gamma_m1: STEEL_GAMMA_M1,
+    };
+    let sizing_result = generate_result_member_sizing(&model, &section_list, &sizing_group_list, &sizing_candidate_list,
+                                                      &member_design, SIZING_MAX_ITERATIONS, DIAGRAM_SAMPLES);
+    for iteration in &sizing_result.history {
+        println!("Sizing iteration {}: weight {:.3}, max utilization {:.3}, sections {:?}",
+                 iteration.iteration, iteration.total_weight, iteration.max_utilization,
+                 iteration.sections.iter().map(|section| format!("{}={}", section.group, section.section)).collect::<Vec<String>>());
+    }
+    println!("Member sizing converged: {}. Total weight {:.3}.", sizing_result.converged, sizing_result.total_weight);
+    eksport_sizing_results(&sizing_result, SAVE_SIZING_RESULTS_PATH);
+
+    // Parameter sweep. All combinations of the sweep parameter values are solved as variants of the model.
+    let sweep_result = generate_result_parameter_sweep(&model, &sweep_parameter_list, &sweep_output_list);
+    println!("Parameter sweep: {} variants solved.", sweep_result.variants.len());
+    eksport_parameter_sweep_csv(&sweep_result, SAVE_PARAMETER_SWEEP_RESULTS_PATH);
+
+    // Monte Carlo reliability analysis with random material, section and load parameters.
+    let reliability_result = generate_result_reliability(&model, &random_variable_list, &reliability_output_list,
+                                                         MONTE_CARLO_SAMPLES, MONTE_CARLO_SEED, MONTE_CARLO_HISTOGRAM_BINS);
+    for output_result in &reliability_result.outputs {
+        println!("Reliability {}: mean {:.4}, std {:.4}, P(|value| > {}) = {:.4}",
+                 output_result.name, output_result.mean, output_result.standard_deviation,
+                 output_result.threshold, output_result.exceedance_probability);
+        let _ = histogram_plot(output_result,
+                               &format!("{}_{}.png", PLOT_HISTOGRAM_OUTPUT_PATH, output_result.name),
+                               PLOT_DIMENSION,
+                               &format!("Histogram {}", output_result.name));
+    }
+    eksport_reliability_results(&reliability_result, SAVE_RELIABILITY_RESULTS_PATH);
+
+    // Design sensitivities of displacements to the connection areas and keypoint coordinates.
+    let sensitivity_results = generate_result_sensitivities(&model, &sensitivity_response_list);
+    for sensitivity_result in &sensitivity_results {
+        println!("Sensitivity {}: {} design variables, displacement {:.6e}",
+                 sensitivity_result.name, sensitivity_result.sensitivities.len(), sensitivity_result.displacement);
+    }
+    eksport_sensitivity_results(&sensitivity_results, SAVE_SENSITIVITY_RESULTS_PATH);
+    eksport_sensitivity_tables_csv(&sensitivity_results, SAVE_SENSITIVITY_TABLES_PATH);
+
+    // Topology optimisation of a ground structure connecting all keypoints.
+    let topology_result = generate_result_topology_optimisation(&kp_list, &bc_list, &pl_list, &section_list,
+                                                                TOPOLOGY_MATERIAL, TOPOLOGY_ALLOWABLE_STRESS, TOPOLOGY_REMOVAL_RATIO);
+    println!("Topology optimisation: {} of {} ground structure bars kept. Volume {:.6}.",
+             topology_result.members.len(), topology_result.ground_structure_bars, topology_result.volume);
+    if !topology_result.feasible {
+        println!("Topology optimisation: the ground structure cannot carry the pointloads.");
+    }
+    let _ = topology_plot(&kp_list,
+                          &topology_result,
+                          &bc_list,
+                          &pl_list,
+                          &plot_settings(PLOT_TOPOLOGY_OUTPUT_PATH,
+                                         PLOT_DIMENSION,
+                                         "Topology Plot"));
+    eksport_topology_results(&topology_result, SAVE_TOPOLOGY_RESULTS_PATH);
+    write_topology_input_files(&topology_result, &kp_list, &bc_list,
+                               SAVE_TOPOLOGY_KEYPOINTS_PATH, SAVE_TOPOLOGY_CONNECTIONS_PATH, SAVE_TOPOLOGY_BCS_PATH);
+
+    // Linear static analysis of the 3D models, plotted in an axonometric projection.
+    for (input_directory, output_prefix) in SPACE_MODELS {
+        run_space_analysis(input_directory, output_prefix, &section_list, &mat_list);
+    }
+
+    // Linear static analysis of the plane stress/strain models, with stress contour plots.
+    for (input_directory, output_prefix) in PLANE_MODELS {
+        run_plane_analysis(input_directory, output_prefix, &section_list, &mat_list);
+    }
+}
@@ -0,0 +1,88 @@
+use nalgebra::DMatrix;
+use crate::input::keypoint::Keypoint;
+use crate::material_formulation::local_stiffness_matrix_frame::{element_geometry, frame_transformation_matrix, frame_matrix_local_axes};
+use crate::material_formulation::static_condensation::condense_released_geometric_matrix;
+
+
+/// Calculates the geometric stiffness matrix for a 2D bar element.
+///
+/// # Arguments
+/// * `kp_1` - First keypoint of the element
+/// * `kp_2` - Second keypoint of the element
+/// * `axial_force` - Normal force in the element. Tension is positive.
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 4x4 geometric stiffness matrix in global coordinates
+pub fn local_bar_geometric_matrix(kp_1:&Keypoint, kp_2:&Keypoint, axial_force:f64) -> DMatrix<f64> {
+    let (length, c, s) = element_geometry(kp_1, kp_2);
+
+    // Constructing the geometric matrix for a bar. Only the transverse direction is affected.
+    let mut k = DMatrix::<f64>::zeros(4, 4);
+
+    k[(0, 0)] =  s * s;
+    k[(0, 1)] = -c * s;
+    k[(0, 2)] = -s * s;
+    k[(0, 3)] =  c * s;
+
+    k[(1, 0)] = -c * s;
+    k[(1, 1)] =  c * c;
+    k[(1, 2)] =  c * s;
+    k[(1, 3)] = -c * c;
+
+    k[(2, 0)] = -s * s;
+    k[(2, 1)] =  c * s;
+    k[(2, 2)] =  s * s;
+    k[(2, 3)] = -c * s;
+
+    k[(3, 0)] =  c * s;
+    k[(3, 1)] = -c * c;
+    k[(3, 2)] = -c * s;
+    k[(3, 3)] =  c * c;
+
+    k * axial_force / length
+}
+
+/// Calculates the consistent geometric stiffness matrix for a 2D frame element.
+///
+/// # Arguments
+/// * `kp_1` - First keypoint of the element
+/// * `kp_2` - Second keypoint of the element
+/// * `axial_force` - Normal force in the element. Tension is positive.
+/// * `released_dofs` - Released local dofs of both element ends, condensed as in the elastic stiffness matrix
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 6x6 geometric stiffness matrix in global coordinates
+pub fn local_frame_geometric_matrix(kp_1:&Keypoint, kp_2:&Keypoint, axial_force:f64, released_dofs:&[usize]) -> DMatrix<f64> {
+    let (length, _, _) = element_geometry(kp_1, kp_2);
+    let l2:f64 = length * length;
+
+    let mut k = DMatrix::<f64>::zeros(6, 6);
+
+    k[(1, 1)] =  36.0;
+    k[(1, 2)] =   3.0 * length;
+    k[(1, 4)] = -36.0;
+    k[(1, 5)] =   3.0 * length;
+
+    k[(2, 1)] =   3.0 * length;
+    k[(2, 2)] =   4.0 * l2;
+    k[(2, 4)] =  -3.0 * length;
+    k[(2, 5)] =  -l2;
+
+    k[(4, 1)] = -36.0;
+    k[(4, 2)] =  -3.0 * length;
+    k[(4, 4)] =  36.0;
+    k[(4, 5)] =  -3.0 * length;
+
+    k[(5, 1)] =   3.0 * length;
+    k[(5, 2)] =  -l2;
+    k[(5, 4)] =  -3.0 * length;
+    k[(5, 5)] =   4.0 * l2;
+
+    // The released rotations only couple to the bending terms of the elastic matrix, so the condensation
+    // does not depend on the section and a unit section is used.
+    let k_local = condense_released_geometric_matrix(&frame_matrix_local_axes(length, 1.0, 1.0, 1.0),
+                                                     &(k * axial_force / (30.0 * length)), released_dofs);
+    let t = frame_transformation_matrix(kp_1, kp_2);
+
+    t.transpose() * k_local * t
+}
@@ -0,0 +1,40 @@
+use nalgebra::DMatrix;
+use crate::input::keypoint::Keypoint;
+use crate::material_formulation::local_stiffness_matrix_frame::element_geometry;
+
+
+/// Calculates the mass matrix for a 2D bar element.
+/// The translational mass matrix is invariant to rotation, and is therefore equal in local and global coordinates.
+///
+/// # Arguments
+/// * `kp_1` - First keypoint of the element
+/// * `kp_2` - Second keypoint of the element
+/// * `density` - Density of the material
+/// * `area` - Cross-sectional area of the element
+/// * `formulation` - "lumped" or "consistent"
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 4x4 mass matrix in global coordinates
+pub fn local_bar_mass_matrix(kp_1:&Keypoint, kp_2:&Keypoint, density:f64, area:f64, formulation:&str) -> DMatrix<f64> {
+    let (length, _, _) = element_geometry(kp_1, kp_2);
+    let mass:f64 = density * area * length;
+
+    let mut m = DMatrix::<f64>::zeros(4, 4);
+
+    if formulation == "consistent" {
+        // Linear shape functions, m = rho*A*L/6 * [2 1; 1 2] in each direction.
+        for i in 0..2 {
+            m[(i, i)] = 2.0;
+            m[(i, i+2)] = 1.0;
+            m[(i+2, i)] = 1.0;
+            m[(i+2, i+2)] = 2.0;
+        }
+        m * mass / 6.0
+    } else {
+        // Half of the bar mass in each keypoint.
+        for i in 0..4 {
+            m[(i, i)] = 1.0;
+        }
+        m * mass / 2.0
+    }
+}
@@ -55,3 +55,54 @@ pub fn local_bar_matrix(kp_1:&Keypoint, kp_2:&Keypoint, e_module:f64, area:f64)
 
     k * e_module * area / length
 }
+
+/// Calculates the derivative of the local stiffness matrix for a 2D bar element
+/// with respect to one of the keypoint coordinates.
+///
+/// With n = (cos(theta), sin(theta)) and N = n * n^T, the bar matrix is K = E * A / L * [N, -N; -N, N].
+/// Moving a keypoint coordinate changes the element vector by d_delta, and
+/// dL = n . d_delta,  dn = (d_delta - n * dL) / L,  dN = dn * n^T + n * dn^T.
+///
+/// # Arguments
+/// * `kp_1` - First keypoint of the element
+/// * `kp_2` - Second keypoint of the element
+/// * `e_module` - Young's modulus of the material
+/// * `area` - Cross-sectional area of the element
+/// * `coordinate` - Element dof of the coordinate. 0 = x of kp_1, 1 = y of kp_1, 2 = x of kp_2, 3 = y of kp_2
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 4x4 matrix, dK/d(coordinate) in global coordinates
+pub fn local_bar_matrix_coordinate_derivative(kp_1:&Keypoint, kp_2:&Keypoint, e_module:f64, area:f64, coordinate:usize) -> DMatrix<f64> {
+    let vec_delta:Vector2<f64> = Vector2::new(kp_2.x, kp_2.y) - Vector2::new(kp_1.x, kp_1.y);
+    let length:f64 = vec_delta.norm();
+    let n:Vector2<f64> = vec_delta / length;
+
+    // Change of the element vector for a unit change of the coordinate.
+    let d_delta:Vector2<f64> = match coordinate {
+        0 => Vector2::new(-1.0, 0.0),
+        1 => Vector2::new(0.0, -1.0),
+        2 => Vector2::new(1.0, 0.0),
+        3 => Vector2::new(0.0, 1.0),
+        _ => panic!("Unknown bar element coordinate {}", coordinate),
+    };
+
+    let d_length:f64 = n.dot(&d_delta);
+    let d_n:Vector2<f64> = (d_delta - n * d_length) / length;
+
+    let n_matrix = n * n.transpose();
+    let d_n_matrix = d_n * n.transpose() + n * d_n.transpose();
+
+    // d(N / L) = dN / L - N * dL / L^2
+    let d_block = (d_n_matrix / length - n_matrix * d_length / (length * length)) * e_module * area;
+
+    let mut dk = DMatrix::<f64>::zeros(4, 4);
+    for (row, col, sign) in [(0, 0, 1.0), (0, 2, -1.0), (2, 0, -1.0), (2, 2, 1.0)] {
+        for i in 0..2 {
+            for j in 0..2 {
+                dk[(row + i, col + j)] = sign * d_block[(i, j)];
+            }
+        }
+    }
+
+    dk
+}
@@ -0,0 +1,71 @@
+use nalgebra::{DMatrix, DVector, Vector3};
+use crate::input::keypoint::Keypoint;
+use crate::material_formulation::plane_elasticity::plane_elasticity_matrix;
+
+
+/// Calculates the strain-displacement matrix of a constant strain triangle (CST).
+///
+/// With x_ij = x_i - x_j and y_ij = y_i - y_j, the strains are constant over the element:
+/// B = 1 / (2A) · [y23, 0, y31, 0, y12, 0; 0, x32, 0, x13, 0, x21; x32, y23, x13, y31, x21, y12]
+///
+/// # Arguments
+/// * `keypoints` - The 3 keypoints of the element, ordered counter-clockwise
+///
+/// # Returns
+/// * `(DMatrix<f64>, f64)` - The 3x6 strain-displacement matrix and the element area
+pub fn cst_strain_displacement_matrix(keypoints:&[&Keypoint]) -> (DMatrix<f64>, f64) {
+    let (x1, y1) = (keypoints[0].x, keypoints[0].y);
+    let (x2, y2) = (keypoints[1].x, keypoints[1].y);
+    let (x3, y3) = (keypoints[2].x, keypoints[2].y);
+
+    let twice_area:f64 = (x2 - x1) * (y3 - y1) - (x3 - x1) * (y2 - y1);
+    if twice_area <= 0.0 {
+        panic!("Keypoints of CST element {}, {}, {} must be ordered counter-clockwise",
+               keypoints[0].name, keypoints[1].name, keypoints[2].name);
+    }
+
+    let b = DMatrix::from_row_slice(3, 6, &[
+        y2 - y3, 0.0, y3 - y1, 0.0, y1 - y2, 0.0,
+        0.0, x3 - x2, 0.0, x1 - x3, 0.0, x2 - x1,
+        x3 - x2, y2 - y3, x1 - x3, y3 - y1, x2 - x1, y1 - y2,
+    ]) / twice_area;
+
+    (b, twice_area / 2.0)
+}
+
+/// Calculates the stiffness matrix of a constant strain triangle (CST).
+/// K = t · A · Bᵀ · D · B
+///
+/// # Arguments
+/// * `keypoints` - The 3 keypoints of the element, ordered counter-clockwise
+/// * `e_module` - Young's modulus of the material
+/// * `poisson_ratio` - Poisson's ratio of the material
+/// * `thickness` - Thickness of the element
+/// * `formulation` - "plane_stress" or "plane_strain"
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 6x6 stiffness matrix in global coordinates, dofs (x1, y1, x2, y2, x3, y3)
+pub fn local_cst_matrix(keypoints:&[&Keypoint], e_module:f64, poisson_ratio:f64, thickness:f64, formulation:&str) -> DMatrix<f64> {
+    let (b, area) = cst_strain_displacement_matrix(keypoints);
+    let d:DMatrix<f64> = DMatrix::from_column_slice(3, 3, plane_elasticity_matrix(e_module, poisson_ratio, formulation).as_slice());
+
+    b.transpose() * d * &b * thickness * area
+}
+
+/// Calculates the constant stresses of a CST element, σ = D · B · u.
+///
+/// # Arguments
+/// * `keypoints` - The 3 keypoints of the element, ordered counter-clockwise
+/// * `u_element` - Element displacements (x1, y1, x2, y2, x3, y3)
+/// * `e_module` - Young's modulus of the material
+/// * `poisson_ratio` - Poisson's ratio of the material
+/// * `formulation` - "plane_stress" or "plane_strain"
+///
+/// # Returns
+/// * `Vector3<f64>` - The stresses (σx, σy, τxy)
+pub fn cst_stress(keypoints:&[&Keypoint], u_element:&DVector<f64>, e_module:f64, poisson_ratio:f64, formulation:&str) -> Vector3<f64> {
+    let (b, _) = cst_strain_displacement_matrix(keypoints);
+    let strain = b * u_element;
+
+    plane_elasticity_matrix(e_module, poisson_ratio, formulation) * Vector3::new(strain[0], strain[1], strain[2])
+}
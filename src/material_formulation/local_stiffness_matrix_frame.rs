@@ -0,0 +1,110 @@
+use nalgebra::{DMatrix, Vector2};
+use crate::input::keypoint::Keypoint;
+use crate::material_formulation::static_condensation::condense_released_dofs;
+
+
+/// Calculates the length, cosinus and sinus of the element axis between two keypoints.
+///
+/// # Returns
+/// * `(f64, f64, f64)` - Length, cos(theta) and sin(theta)
+pub fn element_geometry(kp_1:&Keypoint, kp_2:&Keypoint) -> (f64, f64, f64) {
+    let vec_delta:Vector2<f64> = Vector2::new(kp_2.x, kp_2.y) - Vector2::new(kp_1.x, kp_1.y);
+    let length:f64 = vec_delta.norm();
+
+    (length, vec_delta.x / length, vec_delta.y / length)
+}
+
+/// Calculates the transformation matrix from global to local coordinates for a 2D frame element.
+///
+/// # Arguments
+/// * `kp_1` - First keypoint of the element
+/// * `kp_2` - Second keypoint of the element
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 6x6 transformation matrix. u_local = T * u_global
+pub fn frame_transformation_matrix(kp_1:&Keypoint, kp_2:&Keypoint) -> DMatrix<f64> {
+    let (_, c, s) = element_geometry(kp_1, kp_2);
+
+    let mut t = DMatrix::<f64>::zeros(6, 6);
+
+    for i in [0, 3] {
+        t[(i, i)] = c;
+        t[(i, i+1)] = s;
+        t[(i+1, i)] = -s;
+        t[(i+1, i+1)] = c;
+        t[(i+2, i+2)] = 1.0;
+    }
+
+    t
+}
+
+/// Calculates the stiffness matrix for a 2D frame element in local coordinates.
+/// Setting the moment of inertia to zero returns the axial stiffness of a bar.
+///
+/// # Arguments
+/// * `length` - Element length
+/// * `e_module` - Young's modulus of the material
+/// * `area` - Cross-sectional area of the element
+/// * `inertia` - Second moment of area of the element
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 6x6 stiffness matrix in local coordinates
+pub fn frame_matrix_local_axes(length:f64, e_module:f64, area:f64, inertia:f64) -> DMatrix<f64> {
+    let ea:f64 = e_module * area / length;
+    let ei:f64 = e_module * inertia;
+    let l2:f64 = length * length;
+    let l3:f64 = l2 * length;
+
+    let mut k = DMatrix::<f64>::zeros(6, 6);
+
+    // Axial stiffness
+    k[(0, 0)] =  ea;
+    k[(0, 3)] = -ea;
+    k[(3, 0)] = -ea;
+    k[(3, 3)] =  ea;
+
+    // Bending stiffness
+    k[(1, 1)] =  12.0 * ei / l3;
+    k[(1, 2)] =   6.0 * ei / l2;
+    k[(1, 4)] = -12.0 * ei / l3;
+    k[(1, 5)] =   6.0 * ei / l2;
+
+    k[(2, 1)] =   6.0 * ei / l2;
+    k[(2, 2)] =   4.0 * ei / length;
+    k[(2, 4)] =  -6.0 * ei / l2;
+    k[(2, 5)] =   2.0 * ei / length;
+
+    k[(4, 1)] = -12.0 * ei / l3;
+    k[(4, 2)] =  -6.0 * ei / l2;
+    k[(4, 4)] =  12.0 * ei / l3;
+    k[(4, 5)] =  -6.0 * ei / l2;
+
+    k[(5, 1)] =   6.0 * ei / l2;
+    k[(5, 2)] =   2.0 * ei / length;
+    k[(5, 4)] =  -6.0 * ei / l2;
+    k[(5, 5)] =   4.0 * ei / length;
+
+    k
+}
+
+/// Calculates the local stiffness matrix for a 2D frame element.
+/// Released dofs (end hinges) are condensed out in local coordinates before the transformation.
+///
+/// # Arguments
+/// * `kp_1` - First keypoint of the element
+/// * `kp_2` - Second keypoint of the element
+/// * `e_module` - Young's modulus of the material
+/// * `area` - Cross-sectional area of the element
+/// * `inertia` - Second moment of area of the element
+/// * `released_dofs` - Local element dofs with zero end force, e.g. 2 for a hinge at kp_1 and 5 for a hinge at kp_2
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 6x6 local stiffness matrix in global coordinates
+pub fn local_frame_matrix(kp_1:&Keypoint, kp_2:&Keypoint, e_module:f64, area:f64, inertia:f64, released_dofs:&[usize]) -> DMatrix<f64> {
+    let (length, _, _) = element_geometry(kp_1, kp_2);
+
+    let k_local = condense_released_dofs(&frame_matrix_local_axes(length, e_module, area, inertia), released_dofs);
+    let t = frame_transformation_matrix(kp_1, kp_2);
+
+    t.transpose() * k_local * t
+}
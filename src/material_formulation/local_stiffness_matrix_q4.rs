@@ -0,0 +1,108 @@
+use nalgebra::{DMatrix, DVector, Matrix2, Vector3};
+use crate::input::keypoint::Keypoint;
+use crate::material_formulation::plane_elasticity::plane_elasticity_matrix;
+
+// Natural coordinates (ξ, η) of the 4 keypoints, ordered counter-clockwise.
+pub const Q4_NATURAL_COORDINATES: [(f64, f64); 4] = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)];
+
+
+/// Calculates the bilinear shape functions of a Q4 element.
+/// N_i = (1 + ξ·ξ_i)(1 + η·η_i) / 4
+///
+/// # Arguments
+/// * `xi`, `eta` - Natural coordinates, between -1 and 1
+///
+/// # Returns
+/// * `[f64; 4]` - The shape function values at the 4 keypoints
+pub fn q4_shape_functions(xi:f64, eta:f64) -> [f64; 4] {
+    Q4_NATURAL_COORDINATES.map(|(xi_i, eta_i)| (1.0 + xi * xi_i) * (1.0 + eta * eta_i) / 4.0)
+}
+
+/// Calculates the strain-displacement matrix of a Q4 element at a point (ξ, η).
+///
+/// The shape function derivatives are mapped to global coordinates with the Jacobian,
+/// J = [Σ ∂N/∂ξ·x, Σ ∂N/∂ξ·y; Σ ∂N/∂η·x, Σ ∂N/∂η·y] and [∂N/∂x; ∂N/∂y] = J⁻¹ · [∂N/∂ξ; ∂N/∂η].
+///
+/// # Arguments
+/// * `keypoints` - The 4 keypoints of the element, ordered counter-clockwise
+/// * `xi`, `eta` - Natural coordinates, between -1 and 1
+///
+/// # Returns
+/// * `(DMatrix<f64>, f64)` - The 3x8 strain-displacement matrix and the Jacobian determinant
+pub fn q4_strain_displacement_matrix(keypoints:&[&Keypoint], xi:f64, eta:f64) -> (DMatrix<f64>, f64) {
+    let d_xi:Vec<f64> = Q4_NATURAL_COORDINATES.iter().map(|(xi_i, eta_i)| xi_i * (1.0 + eta * eta_i) / 4.0).collect();
+    let d_eta:Vec<f64> = Q4_NATURAL_COORDINATES.iter().map(|(xi_i, eta_i)| eta_i * (1.0 + xi * xi_i) / 4.0).collect();
+
+    let mut jacobian = Matrix2::<f64>::zeros();
+    for (i, kp) in keypoints.iter().enumerate() {
+        jacobian[(0, 0)] += d_xi[i] * kp.x;
+        jacobian[(0, 1)] += d_xi[i] * kp.y;
+        jacobian[(1, 0)] += d_eta[i] * kp.x;
+        jacobian[(1, 1)] += d_eta[i] * kp.y;
+    }
+
+    let det_j:f64 = jacobian.determinant();
+    if det_j <= 0.0 {
+        panic!("Keypoints of Q4 element {}, {}, {}, {} must be ordered counter-clockwise, forming a convex quadrilateral",
+               keypoints[0].name, keypoints[1].name, keypoints[2].name, keypoints[3].name);
+    }
+    let inverse_jacobian = jacobian.try_inverse().unwrap();
+
+    let mut b = DMatrix::<f64>::zeros(3, 8);
+    for i in 0..4 {
+        let d_x:f64 = inverse_jacobian[(0, 0)] * d_xi[i] + inverse_jacobian[(0, 1)] * d_eta[i];
+        let d_y:f64 = inverse_jacobian[(1, 0)] * d_xi[i] + inverse_jacobian[(1, 1)] * d_eta[i];
+
+        b[(0, 2*i)] = d_x;
+        b[(1, 2*i+1)] = d_y;
+        b[(2, 2*i)] = d_y;
+        b[(2, 2*i+1)] = d_x;
+    }
+
+    (b, det_j)
+}
+
+/// Calculates the stiffness matrix of a bilinear quadrilateral (Q4) element.
+/// K = t · Σ Bᵀ · D · B · det(J), integrated with 2x2 Gauss points at ξ, η = ±1/√3 (unit weights).
+///
+/// # Arguments
+/// * `keypoints` - The 4 keypoints of the element, ordered counter-clockwise
+/// * `e_module` - Young's modulus of the material
+/// * `poisson_ratio` - Poisson's ratio of the material
+/// * `thickness` - Thickness of the element
+/// * `formulation` - "plane_stress" or "plane_strain"
+///
+/// # Returns
+/// * `DMatrix<f64>` - An 8x8 stiffness matrix in global coordinates, dofs (x1, y1, ..., x4, y4)
+pub fn local_q4_matrix(keypoints:&[&Keypoint], e_module:f64, poisson_ratio:f64, thickness:f64, formulation:&str) -> DMatrix<f64> {
+    let d:DMatrix<f64> = DMatrix::from_column_slice(3, 3, plane_elasticity_matrix(e_module, poisson_ratio, formulation).as_slice());
+    let gauss_point:f64 = 1.0 / 3.0_f64.sqrt();
+
+    let mut k = DMatrix::<f64>::zeros(8, 8);
+    for (xi_i, eta_i) in Q4_NATURAL_COORDINATES {
+        let (b, det_j) = q4_strain_displacement_matrix(keypoints, xi_i * gauss_point, eta_i * gauss_point);
+        k += b.transpose() * &d * &b * det_j;
+    }
+
+    k * thickness
+}
+
+/// Calculates the stresses of a Q4 element at a point (ξ, η), σ = D · B(ξ, η) · u.
+///
+/// # Arguments
+/// * `keypoints` - The 4 keypoints of the element, ordered counter-clockwise
+/// * `u_element` - Element displacements (x1, y1, ..., x4, y4)
+/// * `e_module` - Young's modulus of the material
+/// * `poisson_ratio` - Poisson's ratio of the material
+/// * `formulation` - "plane_stress" or "plane_strain"
+/// * `xi`, `eta` - Natural coordinates, between -1 and 1
+///
+/// # Returns
+/// * `Vector3<f64>` - The stresses (σx, σy, τxy)
+pub fn q4_stress(keypoints:&[&Keypoint], u_element:&DVector<f64>, e_module:f64, poisson_ratio:f64, formulation:&str,
+                 xi:f64, eta:f64) -> Vector3<f64> {
+    let (b, _) = q4_strain_displacement_matrix(keypoints, xi, eta);
+    let strain = b * u_element;
+
+    plane_elasticity_matrix(e_module, poisson_ratio, formulation) * Vector3::new(strain[0], strain[1], strain[2])
+}
@@ -0,0 +1,38 @@
+use nalgebra::{DMatrix, Vector3};
+use crate::input::space_keypoint::SpaceKeypoint;
+
+/// Calculates the length and the unit direction vector of a 3D element, from kp_1 to kp_2.
+pub fn space_element_geometry(kp_1:&SpaceKeypoint, kp_2:&SpaceKeypoint) -> (f64, Vector3<f64>) {
+    let vec_delta:Vector3<f64> = Vector3::new(kp_2.x - kp_1.x, kp_2.y - kp_1.y, kp_2.z - kp_1.z);
+    let length:f64 = vec_delta.norm();
+
+    (length, vec_delta / length)
+}
+
+/// Calculates the local stiffness matrix for a 3D bar element.
+///
+/// With the direction cosines n = (cx, cy, cz) and N = n * n^T, the matrix is K = E * A / L * [N, -N; -N, N].
+///
+/// # Arguments
+/// * `kp_1` - First keypoint of the element
+/// * `kp_2` - Second keypoint of the element
+/// * `e_module` - Young's modulus of the material
+/// * `area` - Cross-sectional area of the element
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 6x6 local stiffness matrix in global coordinates
+pub fn local_space_bar_matrix(kp_1:&SpaceKeypoint, kp_2:&SpaceKeypoint, e_module:f64, area:f64) -> DMatrix<f64> {
+    let (length, n) = space_element_geometry(kp_1, kp_2);
+    let n_matrix = n * n.transpose();
+
+    let mut k = DMatrix::<f64>::zeros(6, 6);
+    for (row, col, sign) in [(0, 0, 1.0), (0, 3, -1.0), (3, 0, -1.0), (3, 3, 1.0)] {
+        for i in 0..3 {
+            for j in 0..3 {
+                k[(row + i, col + j)] = sign * n_matrix[(i, j)];
+            }
+        }
+    }
+
+    k * e_module * area / length
+}
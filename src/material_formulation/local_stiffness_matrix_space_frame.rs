@@ -0,0 +1,130 @@
+use nalgebra::{DMatrix, Matrix3, Vector3};
+use crate::input::space_keypoint::SpaceKeypoint;
+use crate::input::connection::Connection;
+use crate::material_formulation::local_stiffness_matrix_space_bar::space_element_geometry;
+use crate::material_formulation::static_condensation::condense_released_dofs;
+
+/// Calculates the rotation matrix of a 3D frame element. The rows are the local x, y and z-axes in global coordinates.
+///
+/// The local x-axis points from kp_1 to kp_2. The local z-axis is the part of the orientation vector
+/// perpendicular to the member, and the local y-axis completes the right-handed system, y = z × x.
+/// A zero orientation vector uses the global z-axis, or the global x-axis for vertical members.
+/// With the default orientation, the major axis (local y) of horizontal beams is horizontal.
+///
+/// # Arguments
+/// * `kp_1` - First keypoint of the element
+/// * `kp_2` - Second keypoint of the element
+/// * `orientation` - Orientation vector of the local z-axis
+///
+/// # Returns
+/// * `Matrix3<f64>` - The 3x3 rotation matrix from global to local coordinates
+pub fn space_frame_rotation_matrix(kp_1:&SpaceKeypoint, kp_2:&SpaceKeypoint, orientation:[f64; 3]) -> Matrix3<f64> {
+    let (_, local_x) = space_element_geometry(kp_1, kp_2);
+
+    let mut reference:Vector3<f64> = Vector3::new(orientation[0], orientation[1], orientation[2]);
+    if reference.norm() == 0.0 {
+        reference = if local_x.z.abs() > 0.999 { Vector3::x() } else { Vector3::z() };
+    }
+
+    let perpendicular:Vector3<f64> = reference - local_x * reference.dot(&local_x);
+    if perpendicular.norm() < 1e-6 * reference.norm() {
+        panic!("Orientation vector ({}, {}, {}) is parallel to the element from {} to {}",
+               orientation[0], orientation[1], orientation[2], kp_1.name, kp_2.name);
+    }
+    let local_z:Vector3<f64> = perpendicular.normalize();
+    let local_y:Vector3<f64> = local_z.cross(&local_x);
+
+    Matrix3::from_rows(&[local_x.transpose(), local_y.transpose(), local_z.transpose()])
+}
+
+/// Calculates the transformation matrix of a 3D frame element, from global to local coordinates.
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 12x12 matrix with the rotation matrix on the diagonal blocks
+pub fn space_frame_transformation_matrix(kp_1:&SpaceKeypoint, kp_2:&SpaceKeypoint, orientation:[f64; 3]) -> DMatrix<f64> {
+    let rotation = space_frame_rotation_matrix(kp_1, kp_2, orientation);
+
+    let mut t = DMatrix::<f64>::zeros(12, 12);
+    for block in 0..4 {
+        for i in 0..3 {
+            for j in 0..3 {
+                t[(3 * block + i, 3 * block + j)] = rotation[(i, j)];
+            }
+        }
+    }
+
+    t
+}
+
+/// Calculates the stiffness matrix of a 3D frame element in local coordinates.
+///
+/// Dof layout at each end: (x, y, z, rx, ry, rz). Bending in the local x-y plane uses the minor axis
+/// inertia (about local z), and bending in the local x-z plane uses the major axis inertia (about local y).
+///
+/// # Arguments
+/// * `length` - Element length
+/// * `e_module` - Young's modulus of the material
+/// * `shear_module` - Shear modulus of the material
+/// * `area` - Cross-sectional area
+/// * `inertia_y` - Second moment of area about the local y-axis
+/// * `inertia_z` - Second moment of area about the local z-axis
+/// * `torsion_constant` - St. Venant torsion constant
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 12x12 local stiffness matrix in local coordinates
+pub fn space_frame_matrix_local_axes(length:f64, e_module:f64, shear_module:f64, area:f64,
+                                     inertia_y:f64, inertia_z:f64, torsion_constant:f64) -> DMatrix<f64> {
+    let l:f64 = length;
+    let axial:f64 = e_module * area / l;
+    let torsion:f64 = shear_module * torsion_constant / l;
+    let (bz_12, bz_6, bz_4, bz_2) = (12.0 * e_module * inertia_z / l.powi(3), 6.0 * e_module * inertia_z / l.powi(2),
+                                     4.0 * e_module * inertia_z / l, 2.0 * e_module * inertia_z / l);
+    let (by_12, by_6, by_4, by_2) = (12.0 * e_module * inertia_y / l.powi(3), 6.0 * e_module * inertia_y / l.powi(2),
+                                     4.0 * e_module * inertia_y / l, 2.0 * e_module * inertia_y / l);
+
+    // Upper triangle of the matrix.
+    let entries = [
+        (0, 0, axial), (0, 6, -axial), (6, 6, axial),
+        (3, 3, torsion), (3, 9, -torsion), (9, 9, torsion),
+        // Bending in the local x-y plane, y and rz.
+        (1, 1, bz_12), (1, 5, bz_6), (1, 7, -bz_12), (1, 11, bz_6),
+        (5, 5, bz_4), (5, 7, -bz_6), (5, 11, bz_2),
+        (7, 7, bz_12), (7, 11, -bz_6), (11, 11, bz_4),
+        // Bending in the local x-z plane, z and ry.
+        (2, 2, by_12), (2, 4, -by_6), (2, 8, -by_12), (2, 10, -by_6),
+        (4, 4, by_4), (4, 8, by_6), (4, 10, by_2),
+        (8, 8, by_12), (8, 10, by_6), (10, 10, by_4),
+    ];
+
+    let mut k = DMatrix::<f64>::zeros(12, 12);
+    for (i, j, value) in entries {
+        k[(i, j)] = value;
+        k[(j, i)] = value;
+    }
+
+    k
+}
+
+/// Calculates the local stiffness matrix of a 3D frame element in global coordinates.
+/// Released dofs are condensed out in local coordinates before the transformation.
+///
+/// # Arguments
+/// * `kp_1` - First keypoint of the element
+/// * `kp_2` - Second keypoint of the element
+/// * `conn` - The connection, with the section properties and the orientation vector
+/// * `e_module` - Young's modulus of the material
+/// * `shear_module` - Shear modulus of the material
+/// * `released_dofs` - Released local dofs of both element ends
+///
+/// # Returns
+/// * `DMatrix<f64>` - A 12x12 local stiffness matrix in global coordinates
+pub fn local_space_frame_matrix(kp_1:&SpaceKeypoint, kp_2:&SpaceKeypoint, conn:&Connection, e_module:f64, shear_module:f64,
+                                released_dofs:&[usize]) -> DMatrix<f64> {
+    let (length, _) = space_element_geometry(kp_1, kp_2);
+    let k_local = space_frame_matrix_local_axes(length, e_module, shear_module, conn.area, conn.inertia, conn.inertia_minor,
+                                                conn.torsion_constant);
+    let k_condensed = condense_released_dofs(&k_local, released_dofs);
+    let t = space_frame_transformation_matrix(kp_1, kp_2, conn.orientation);
+
+    t.transpose() * k_condensed * t
+}
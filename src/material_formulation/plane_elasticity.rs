@@ -0,0 +1,49 @@
+use nalgebra::Matrix3;
+
+
+/// Calculates the elasticity matrix of a plane stress or plane strain element.
+/// The stress and strain vectors are (σx, σy, τxy) and (εx, εy, γxy).
+///
+/// Plane stress:  D = E / (1 - ν²) · [1, ν, 0; ν, 1, 0; 0, 0, (1 - ν) / 2]
+/// Plane strain:  D = E / ((1 + ν)(1 - 2ν)) · [1 - ν, ν, 0; ν, 1 - ν, 0; 0, 0, (1 - 2ν) / 2]
+///
+/// # Arguments
+/// * `e_module` - Young's modulus of the material
+/// * `poisson_ratio` - Poisson's ratio of the material
+/// * `formulation` - "plane_stress" or "plane_strain"
+///
+/// # Returns
+/// * `Matrix3<f64>` - A 3x3 elasticity matrix
+pub fn plane_elasticity_matrix(e_module:f64, poisson_ratio:f64, formulation:&str) -> Matrix3<f64> {
+    let nu:f64 = poisson_ratio;
+
+    match formulation {
+        "plane_stress" => Matrix3::new(
+            1.0, nu, 0.0,
+            nu, 1.0, 0.0,
+            0.0, 0.0, (1.0 - nu) / 2.0,
+        ) * e_module / (1.0 - nu * nu),
+        "plane_strain" => Matrix3::new(
+            1.0 - nu, nu, 0.0,
+            nu, 1.0 - nu, 0.0,
+            0.0, 0.0, (1.0 - 2.0 * nu) / 2.0,
+        ) * e_module / ((1.0 + nu) * (1.0 - 2.0 * nu)),
+        _ => panic!("Unknown plane formulation {}", formulation),
+    }
+}
+
+/// Calculates the von Mises stress from the in-plane stresses.
+/// The out-of-plane stress is zero for plane stress, and σz = ν(σx + σy) for plane strain.
+///
+/// # Arguments
+/// * `sx`, `sy`, `txy` - In-plane stresses
+/// * `poisson_ratio` - Poisson's ratio of the material
+/// * `formulation` - "plane_stress" or "plane_strain"
+///
+/// # Returns
+/// * `f64` - The von Mises stress
+pub fn von_mises_stress(sx:f64, sy:f64, txy:f64, poisson_ratio:f64, formulation:&str) -> f64 {
+    let sz:f64 = if formulation == "plane_strain" { poisson_ratio * (sx + sy) } else { 0.0 };
+
+    (0.5 * ((sx - sy).powi(2) + (sy - sz).powi(2) + (sz - sx).powi(2)) + 3.0 * txy * txy).sqrt()
+}
@@ -0,0 +1,120 @@
+use nalgebra::{DMatrix, DVector};
+
+/// Condenses released dofs out of an element stiffness matrix.
+///
+/// With the retained dofs c and the released dofs r, the released dofs carry no force, which gives
+/// K* = K_cc - K_cr * K_rr^-1 * K_rc.
+/// The condensed matrix is returned in the original size, with zero rows and columns at the released dofs.
+///
+/// # Arguments
+/// * `k` - Element stiffness matrix in local coordinates
+/// * `released_dofs` - Element dofs with zero end force
+///
+/// # Returns
+/// * `DMatrix<f64>` - The condensed element stiffness matrix, same size as `k`
+pub fn condense_released_dofs(k:&DMatrix<f64>, released_dofs:&[usize]) -> DMatrix<f64> {
+    if released_dofs.is_empty() {
+        return k.clone();
+    }
+
+    let size:usize = k.nrows();
+    let retained_dofs:Vec<usize> = (0..size).filter(|dof| !released_dofs.contains(dof)).collect();
+
+    let k_rr = DMatrix::from_fn(released_dofs.len(), released_dofs.len(), |i, j| k[(released_dofs[i], released_dofs[j])]);
+    let k_rc = DMatrix::from_fn(released_dofs.len(), retained_dofs.len(), |i, j| k[(released_dofs[i], retained_dofs[j])]);
+    let k_cc = DMatrix::from_fn(retained_dofs.len(), retained_dofs.len(), |i, j| k[(retained_dofs[i], retained_dofs[j])]);
+
+    let k_rr_inverse = k_rr.try_inverse().expect("Released dofs of an element have no stiffness");
+    let k_condensed = &k_cc - k_rc.transpose() * k_rr_inverse * &k_rc;
+
+    let mut k_star = DMatrix::<f64>::zeros(size, size);
+    for (i, dof_i) in retained_dofs.iter().enumerate() {
+        for (j, dof_j) in retained_dofs.iter().enumerate() {
+            k_star[(*dof_i, *dof_j)] = k_condensed[(i, j)];
+        }
+    }
+
+    k_star
+}
+
+/// Condenses released dofs out of an element load vector, consistent with `condense_released_dofs`.
+///
+/// f*_c = f_c - K_cr * K_rr^-1 * f_r, and the released dofs get zero load.
+///
+/// # Arguments
+/// * `k` - Element stiffness matrix in local coordinates, before condensation
+/// * `f` - Element load vector in local coordinates
+/// * `released_dofs` - Element dofs with zero end force
+///
+/// # Returns
+/// * `DVector<f64>` - The condensed element load vector, same size as `f`
+pub fn condense_released_load_vector(k:&DMatrix<f64>, f:&DVector<f64>, released_dofs:&[usize]) -> DVector<f64> {
+    if released_dofs.is_empty() {
+        return f.clone();
+    }
+
+    let size:usize = k.nrows();
+    let retained_dofs:Vec<usize> = (0..size).filter(|dof| !released_dofs.contains(dof)).collect();
+
+    let k_rr = DMatrix::from_fn(released_dofs.len(), released_dofs.len(), |i, j| k[(released_dofs[i], released_dofs[j])]);
+    let k_rc = DMatrix::from_fn(released_dofs.len(), retained_dofs.len(), |i, j| k[(released_dofs[i], retained_dofs[j])]);
+    let f_r = DVector::from_fn(released_dofs.len(), |i, _| f[released_dofs[i]]);
+
+    let k_rr_inverse = k_rr.try_inverse().expect("Released dofs of an element have no stiffness");
+    let f_correction = k_rc.transpose() * k_rr_inverse * f_r;
+
+    let mut f_star = DVector::<f64>::zeros(size);
+    for (i, dof) in retained_dofs.iter().enumerate() {
+        f_star[*dof] = f[*dof] - f_correction[i];
+    }
+
+    f_star
+}
+
+/// Condenses released dofs out of an element geometric stiffness matrix, consistent with `condense_released_dofs`.
+///
+/// The released dofs follow the retained dofs as u_r = -K_rr^-1 * K_rc * u_c, which gives
+/// K_G* = T^T * K_G * T with T = [I; -K_rr^-1 * K_rc].
+///
+/// # Arguments
+/// * `k` - Element stiffness matrix in local coordinates, before condensation
+/// * `k_g` - Element geometric stiffness matrix in local coordinates
+/// * `released_dofs` - Element dofs with zero end force
+///
+/// # Returns
+/// * `DMatrix<f64>` - The condensed geometric stiffness matrix, same size as `k_g`
+pub fn condense_released_geometric_matrix(k:&DMatrix<f64>, k_g:&DMatrix<f64>, released_dofs:&[usize]) -> DMatrix<f64> {
+    if released_dofs.is_empty() {
+        return k_g.clone();
+    }
+
+    let size:usize = k.nrows();
+    let retained_dofs:Vec<usize> = (0..size).filter(|dof| !released_dofs.contains(dof)).collect();
+
+    let k_rr = DMatrix::from_fn(released_dofs.len(), released_dofs.len(), |i, j| k[(released_dofs[i], released_dofs[j])]);
+    let k_rc = DMatrix::from_fn(released_dofs.len(), retained_dofs.len(), |i, j| k[(released_dofs[i], retained_dofs[j])]);
+
+    let k_rr_inverse = k_rr.try_inverse().expect("Released dofs of an element have no stiffness");
+    let u_r = -k_rr_inverse * k_rc;
+
+    // Transformation from the retained dofs to all element dofs. Released dofs get zero rows and columns.
+    let mut t = DMatrix::<f64>::zeros(size, retained_dofs.len());
+    for (j, dof) in retained_dofs.iter().enumerate() {
+        t[(*dof, j)] = 1.0;
+    }
+    for (i, dof) in released_dofs.iter().enumerate() {
+        for j in 0..retained_dofs.len() {
+            t[(*dof, j)] = u_r[(i, j)];
+        }
+    }
+    let k_g_condensed = t.transpose() * k_g * t;
+
+    let mut k_g_star = DMatrix::<f64>::zeros(size, size);
+    for (i, dof_i) in retained_dofs.iter().enumerate() {
+        for (j, dof_j) in retained_dofs.iter().enumerate() {
+            k_g_star[(*dof_i, *dof_j)] = k_g_condensed[(i, j)];
+        }
+    }
+
+    k_g_star
+}
@@ -0,0 +1,11 @@
+use crate::data_formatting::generate_result_structs::BucklingModeResult;
+use std::fs::File;
+use std::io::Write;
+
+pub fn eksport_buckling_mode_results(buckling_mode_results: &[BucklingModeResult],
+									 output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(buckling_mode_results).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
@@ -2,6 +2,15 @@ use crate::input::keypoint::Keypoint;
 use plotters::prelude::*;
 use plotters::coord::types::RangedCoordf32;
 
+// Output file, size and title of a model plot, with the size of the plotted features and the decimals of the result labels.
+pub struct PlotSettings<'a> {
+    pub output_path: &'a str,
+    pub dimension: (u32, u32),
+    pub chart_title: &'a str,
+    pub plot_feature_size: f32,
+    pub plot_result_decimals: usize,
+}
+
 pub fn plot_canvas<'a>(kp_list:&[Keypoint],
                    output_path:&'a str,
                    dimension: (u32, u32),
@@ -9,10 +18,10 @@ pub fn plot_canvas<'a>(kp_list:&[Keypoint],
     
     // Setting an equal x and y scale.
     // Finding the min and max value for the keypoints. Adding +/- 1 to the values for plot edges.
-    let min_x:f32 = kp_list.iter().map(|kp| kp.x as f32).fold(f32::INFINITY, |acc, x| f32::min(acc,x)) - 1.0 ;
-    let max_x:f32 = kp_list.iter().map(|kp| kp.x as f32).fold(f32::NEG_INFINITY, |acc, x| f32::max(acc,x)) +1.0 ;
-    let min_y:f32 = kp_list.iter().map(|kp| kp.y as f32).fold(f32::INFINITY, |acc, y| f32::min(acc,y)) -1.0 ;
-    let max_y:f32 = kp_list.iter().map(|kp| kp.y as f32).fold(f32::NEG_INFINITY, |acc, y| f32::max(acc,y)) +1.0;
+    let min_x:f32 = kp_list.iter().map(|kp| kp.x as f32).fold(f32::INFINITY, f32::min) - 1.0 ;
+    let max_x:f32 = kp_list.iter().map(|kp| kp.x as f32).fold(f32::NEG_INFINITY, f32::max) +1.0 ;
+    let min_y:f32 = kp_list.iter().map(|kp| kp.y as f32).fold(f32::INFINITY, f32::min) -1.0 ;
+    let max_y:f32 = kp_list.iter().map(|kp| kp.y as f32).fold(f32::NEG_INFINITY, f32::max) +1.0;
 
     // Defining pixels for calculating scale
     let (width_px, height_px) = dimension;
@@ -0,0 +1,11 @@
+use crate::input::connection::Connection;
+use std::fs::File;
+use std::io::Write;
+
+pub fn eksport_connection_structs(conn_list: &[Connection],
+								  output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(conn_list).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
@@ -2,9 +2,14 @@ use crate::input::keypoint::Keypoint;
 use crate::input::connection::Connection;
 use crate::input::boundary_condition::BoundaryCondition;
 use crate::input::pointload::Pointload;
+use crate::input::surface_element::SurfaceElement;
+use crate::data_formatting::generate_result_structs::TopologyMember;
+use crate::fe_engine::plane_analysis::{surface_element_keypoints, surface_element_shape_functions};
+use std::collections::HashMap;
 
 use plotters::prelude::*;
 use plotters::coord::types::RangedCoordf32;
+use plotters::coord::ReverseCoordTranslate;
 
 pub fn plot_keypoint(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>, 
                  keypoint:&Keypoint, plot_feature_size:f32) {
@@ -12,7 +17,7 @@ pub fn plot_keypoint(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<
     let y = keypoint.y as f32;
     let _ = chart_context.draw_series(std::iter::once(Circle::new((x,y), plot_feature_size, ShapeStyle::from(&BLACK).filled())));
 
-    let label = format!("{}", keypoint.name);
+    let label = keypoint.name.to_string();
         plot_label(label, x, y, plot_feature_size, chart_context);
     }
 
@@ -43,6 +48,209 @@ pub fn plot_connection(chart_context:&mut ChartContext<BitMapBackend, Cartesian2
     let _ = chart_context.draw_series(LineSeries::new(vec![(kp1_x, kp1_y), (kp2_x, kp2_y)],&BLACK));
     }
 
+// Plotting released connection ends (hinges) as open circles, placed on the connection next to the keypoint.
+pub fn plot_connection_releases(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>, 
+                 connection:&Connection, kp_list:&[Keypoint], plot_feature_size:f32) {
+
+    let kp1 = kp_list.iter().find(|kp| kp.name == connection.kp_1).unwrap();
+    let kp2 = kp_list.iter().find(|kp| kp.name == connection.kp_2).unwrap();
+
+    // The circle is placed in pixels, so the offset and the radius are independent of the model size.
+    let pixel_1 = chart_context.backend_coord(&(kp1.x as f32, kp1.y as f32));
+    let pixel_2 = chart_context.backend_coord(&(kp2.x as f32, kp2.y as f32));
+    let dx = (pixel_2.0 - pixel_1.0) as f32;
+    let dy = (pixel_2.1 - pixel_1.1) as f32;
+    let length = (dx * dx + dy * dy).sqrt();
+    if length == 0.0 {
+        return;
+    }
+
+    // Distance from the keypoint to the circle centre, clear of the keypoint marker and
+    // limited to a quarter of the connection length.
+    let radius = plot_feature_size * 2.0;
+    let offset = (radius + plot_feature_size).min(length / 4.0);
+
+    for (release, pixel, direction) in [(&connection.release_1, pixel_1, 1.0), (&connection.release_2, pixel_2, -1.0)] {
+        if release.is_empty() {
+            continue;
+        }
+        let centre = (pixel.0 + (direction * offset * dx / length).round() as i32,
+                      pixel.1 + (direction * offset * dy / length).round() as i32);
+        if let Some((x, y)) = chart_context.as_coord_spec().reverse_translate(centre) {
+            let _ = chart_context.draw_series(std::iter::once(Circle::new((x, y), radius, ShapeStyle::from(&WHITE).filled())));
+            let _ = chart_context.draw_series(std::iter::once(Circle::new((x, y), radius, ShapeStyle::from(&BLACK))));
+        }
+    }
+    }
+
+// Plotting a connection coloured by its utilization ratio, labelled with the utilization.
+// Green is unutilized, yellow is 50% utilized and red is fully utilized or failed.
+pub fn plot_connection_utilization(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>, 
+                 connection:&Connection, kp_list:&[Keypoint], plot_feature_size:f32, plot_result_decimals:usize) {
+
+    // Finding the keypoint coordinates through the keypoint struct
+    let kp1 = kp_list.iter().find(|kp| kp.name == connection.kp_1).unwrap();
+    let kp2 = kp_list.iter().find(|kp| kp.name == connection.kp_2).unwrap();
+    let kp1_x = kp1.x as f32;
+    let kp1_y = kp1.y as f32;
+    let kp2_x = kp2.x as f32;
+    let kp2_y = kp2.y as f32;
+
+    let utilization = connection.utilization.clamp(0.0, 1.0);
+    let colour = if utilization < 0.5 {
+        RGBColor((510.0 * utilization) as u8, 200, 0)
+    } else {
+        RGBColor(255, (200.0 * (2.0 - 2.0 * utilization)) as u8, 0)
+    };
+
+    let style = ShapeStyle::from(&colour).stroke_width(plot_feature_size as u32 * 2);
+    let _ = chart_context.draw_series(LineSeries::new(vec![(kp1_x, kp1_y), (kp2_x, kp2_y)], style));
+
+    let label = format!("{:.1$}", connection.utilization, plot_result_decimals);
+    plot_label(label, (kp1_x + kp2_x) / 2.0, (kp1_y + kp2_y) / 2.0, plot_feature_size, chart_context);
+    }
+
+// Plotting a bar of an optimised truss with line width scaled by its area. Tension is blue and compression is red.
+// Plotting the outline of a surface element, with a light grey fill.
+pub fn plot_surface_element(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>, 
+                 surface:&SurfaceElement, kp_list:&[Keypoint]) {
+
+    let corners:Vec<(f32, f32)> = surface_element_keypoints(surface, kp_list).iter().map(|kp| (kp.x as f32, kp.y as f32)).collect();
+
+    let _ = chart_context.draw_series(std::iter::once(Polygon::new(corners.clone(), RGBColor(225, 225, 225).filled())));
+    plot_surface_outline(chart_context, &corners);
+    }
+
+// Plotting the contour of a result over a surface element.
+// The element is divided into sub-cells in natural coordinates. The keypoint values are interpolated
+// with the element shape functions, and each sub-cell is filled with the colour of its centre value.
+pub fn plot_surface_contour(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>, 
+                 surface:&SurfaceElement, kp_list:&[Keypoint], keypoint_values:&HashMap<String, f64>,
+                 min_value:f64, max_value:f64, subdivisions:usize) {
+
+    let keypoints = surface_element_keypoints(surface, kp_list);
+    let values:Vec<f64> = surface.keypoints.iter().map(|name| keypoint_values[name]).collect();
+
+    // Interpolating the coordinates and the value at a point in natural coordinates.
+    let interpolate = |xi:f64, eta:f64| {
+        let shape_functions = surface_element_shape_functions(surface, xi, eta);
+        let x:f64 = shape_functions.iter().zip(&keypoints).map(|(n, kp)| n * kp.x).sum();
+        let y:f64 = shape_functions.iter().zip(&keypoints).map(|(n, kp)| n * kp.y).sum();
+        let value:f64 = shape_functions.iter().zip(&values).map(|(n, value)| n * value).sum();
+        ((x as f32, y as f32), value)
+    };
+
+    // Sub-cells in natural coordinates. Q4 elements use an n x n grid over -1..1.
+    // CST elements use n² sub-triangles of the area coordinates.
+    let step:f64 = 1.0 / subdivisions as f64;
+    let mut cells:Vec<Vec<(f64, f64)>> = Vec::new();
+    for i in 0..subdivisions {
+        for j in 0..subdivisions {
+            let a:f64 = i as f64 * step;
+            let b:f64 = j as f64 * step;
+            if surface.keypoints.len() == 4 {
+                let (xi_0, eta_0) = (2.0 * a - 1.0, 2.0 * b - 1.0);
+                let (xi_1, eta_1) = (xi_0 + 2.0 * step, eta_0 + 2.0 * step);
+                cells.push(vec![(xi_0, eta_0), (xi_1, eta_0), (xi_1, eta_1), (xi_0, eta_1)]);
+            } else if i + j < subdivisions {
+                cells.push(vec![(a, b), (a + step, b), (a, b + step)]);
+                if i + j + 1 < subdivisions {
+                    cells.push(vec![(a + step, b), (a + step, b + step), (a, b + step)]);
+                }
+            }
+        }
+    }
+
+    for cell in cells {
+        let n = cell.len() as f64;
+        let centre_xi:f64 = cell.iter().map(|(xi, _)| xi).sum::<f64>() / n;
+        let centre_eta:f64 = cell.iter().map(|(_, eta)| eta).sum::<f64>() / n;
+        let (_, value) = interpolate(centre_xi, centre_eta);
+
+        let corners:Vec<(f32, f32)> = cell.iter().map(|(xi, eta)| interpolate(*xi, *eta).0).collect();
+        let colour = contour_colour(value, min_value, max_value);
+        let _ = chart_context.draw_series(std::iter::once(Polygon::new(corners, colour.filled())));
+    }
+
+    let corners:Vec<(f32, f32)> = keypoints.iter().map(|kp| (kp.x as f32, kp.y as f32)).collect();
+    plot_surface_outline(chart_context, &corners);
+    }
+
+fn plot_surface_outline(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+                        corners:&[(f32, f32)]) {
+    let mut outline = corners.to_vec();
+    outline.push(corners[0]);
+    let _ = chart_context.draw_series(LineSeries::new(outline, &BLACK));
+    }
+
+// Colour scale of the contour plots, from blue (min) over cyan, green and yellow to red (max).
+pub fn contour_colour(value:f64, min_value:f64, max_value:f64) -> RGBColor {
+    let t:f64 = if max_value > min_value {
+        ((value - min_value) / (max_value - min_value)).clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+
+    let band:f64 = 4.0 * t;
+    let fraction:f64 = band - band.floor();
+    let up = (255.0 * fraction) as u8;
+    let down = (255.0 * (1.0 - fraction)) as u8;
+    match band as usize {
+        0 => RGBColor(0, up, 255),
+        1 => RGBColor(0, 255, down),
+        2 => RGBColor(up, 255, 0),
+        3 => RGBColor(255, down, 0),
+        _ => RGBColor(255, 0, 0),
+    }
+    }
+
+// Plotting the colour scale of a contour plot in the upper right corner, with the min and max values.
+pub fn plot_contour_legend(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>, 
+                 min_value:f64, max_value:f64, plot_feature_size:f32, plot_result_decimals:usize) {
+
+    let drawing_area = chart_context.plotting_area().strip_coord_spec();
+    let (width, _) = drawing_area.dim_in_pixel();
+    let bands:i32 = 10;
+    let band_height:i32 = (plot_feature_size * 6.0) as i32;
+    let band_width:i32 = (plot_feature_size * 8.0) as i32;
+    let x:i32 = width as i32 - band_width - (plot_feature_size * 40.0) as i32;
+    let y:i32 = band_height;
+
+    // The max value is at the top of the scale.
+    for band in 0..bands {
+        let value:f64 = max_value - (max_value - min_value) * (band as f64 + 0.5) / bands as f64;
+        let top:i32 = y + band * band_height;
+        let rectangle = Rectangle::new([(x, top), (x + band_width, top + band_height)], contour_colour(value, min_value, max_value).filled());
+        let _ = drawing_area.draw(&rectangle);
+    }
+    let _ = drawing_area.draw(&Rectangle::new([(x, y), (x + band_width, y + bands * band_height)], BLACK));
+
+    let text_size:i32 = (plot_feature_size * 6.0) as i32;
+    let font = ("sans-serif", text_size).into_font().color(&BLACK);
+    let label_x:i32 = x + band_width + plot_feature_size as i32 * 2;
+    let _ = drawing_area.draw(&Text::new(format!("{:.1$}", max_value, plot_result_decimals), (label_x, y), font.clone()));
+    let _ = drawing_area.draw(&Text::new(format!("{:.1$}", min_value, plot_result_decimals),
+                                         (label_x, y + bands * band_height - text_size), font));
+    }
+
+pub fn plot_topology_member(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>, 
+                 member:&TopologyMember, kp_list:&[Keypoint], max_area:f64, plot_feature_size:f32) {
+
+    // Finding the keypoint coordinates through the keypoint struct
+    let kp1 = kp_list.iter().find(|kp| kp.name == member.kp_1).unwrap();
+    let kp2 = kp_list.iter().find(|kp| kp.name == member.kp_2).unwrap();
+    let kp1_x = kp1.x as f32;
+    let kp1_y = kp1.y as f32;
+    let kp2_x = kp2.x as f32;
+    let kp2_y = kp2.y as f32;
+
+    let colour = if member.normal_force >= 0.0 { BLUE } else { RED };
+    let width = (1.0 + 4.0 * plot_feature_size * (member.area / max_area) as f32) as u32;
+
+    let style = ShapeStyle::from(&colour).stroke_width(width);
+    let _ = chart_context.draw_series(LineSeries::new(vec![(kp1_x, kp1_y), (kp2_x, kp2_y)], style));
+    }
+
 pub fn plot_connection_displaced(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>, 
                  connection:&Connection, kp_list:&[Keypoint], plot_result_scale:f32) {
 
@@ -57,6 +265,57 @@ pub fn plot_connection_displaced(chart_context:&mut ChartContext<BitMapBackend,
     let _ = chart_context.draw_series(LineSeries::new(vec![(kp1_x, kp1_y), (kp2_x, kp2_y)],&RED));
     }    
 
+// Plotting the normal force (N), shear force (V) or bending moment (M) diagram of a connection.
+// The diagram is drawn perpendicular to the connection. Bending moments are drawn on the tension side.
+// The extreme values along the connection are labelled.
+pub fn plot_member_diagram(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>, 
+                 connection:&Connection, kp_list:&[Keypoint], diagram_type:&str, diagram_scale:f32,
+                 plot_feature_size:f32, plot_result_decimals:usize) {
+
+    // Finding the keypoint coordinates through the keypoint struct
+    let kp1 = kp_list.iter().find(|kp| kp.name == connection.kp_1).unwrap();
+    let kp2 = kp_list.iter().find(|kp| kp.name == connection.kp_2).unwrap();
+    let kp1_x = kp1.x as f32;
+    let kp1_y = kp1.y as f32;
+    let length = ((kp2.x - kp1.x).powi(2) + (kp2.y - kp1.y).powi(2)).sqrt() as f32;
+    let c = (kp2.x - kp1.x) as f32 / length;
+    let s = (kp2.y - kp1.y) as f32 / length;
+
+    // Finding the diagram values and the side they are drawn on.
+    let values:Vec<f32> = connection.diagram.iter().map(|p| match diagram_type {
+        "N" => p.n as f32,
+        "V" => p.v as f32,
+        _ => -p.m as f32,
+    }).collect();
+
+    // Diagram outline, offset perpendicular to the connection.
+    let outline:Vec<(f32, f32)> = connection.diagram.iter().zip(values.iter()).map(|(p, value)| {
+        let x = kp1_x + p.position as f32 * c - value * diagram_scale * s;
+        let y = kp1_y + p.position as f32 * s + value * diagram_scale * c;
+        (x, y)
+    }).collect();
+
+    let mut polygon:Vec<(f32, f32)> = vec![(kp1_x, kp1_y)];
+    polygon.extend(outline.iter());
+    polygon.push((kp2.x as f32, kp2.y as f32));
+
+    let _ = chart_context.draw_series(std::iter::once(Polygon::new(polygon.clone(), BLUE.mix(0.2))));
+    let _ = chart_context.draw_series(std::iter::once(PathElement::new(polygon, ShapeStyle::from(&BLUE))));
+
+    // Labelling the extreme values. Diagram values are plotted with flipped sign for moments.
+    let sign:f32 = if diagram_type == "M" { -1.0 } else { 1.0 };
+    let index_max = (0..values.len()).max_by(|&i, &j| values[i].total_cmp(&values[j])).unwrap();
+    let index_min = (0..values.len()).min_by(|&i, &j| values[i].total_cmp(&values[j])).unwrap();
+
+    for index in [index_max, index_min] {
+        if values[index].abs() > f32::EPSILON && (index == index_max || values[index_min] != values[index_max]) {
+            let label = format!("{:.1$}", sign * values[index], plot_result_decimals);
+            let (x, y) = outline[index];
+            plot_label(label, x, y, plot_feature_size, chart_context);
+        }
+    }
+}
+
 pub fn plot_boundary_condition(chart_context:&mut ChartContext<BitMapBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>, 
                  boundary_condition:&BoundaryCondition, kp_list:&[Keypoint], plot_feature_size:f32,plot_reaction:bool, plot_result_decimals:usize) {
 
@@ -71,6 +330,8 @@ pub fn plot_boundary_condition(chart_context:&mut ChartContext<BitMapBackend, Ca
     // 0 = vertically fixed bc
     // 1 = laterally fixed bc
     // 2 = both vertically and laterally fixed bc
+    // 3 = rotationally fixed bc
+    // 4 = vertically, laterally and rotationally fixed bc
     if boundary_condition.fixture == "0" && boundary_condition.spring_stiffness == -1.0 {
         let triangle = PathElement::new(vec![(x, y),(x-size/2.0, y-size),(x+size/2.0, y-size),(x, y)],ShapeStyle::from(&BLACK).filled());
         chart_context.draw_series(std::iter::once(triangle)).unwrap();
@@ -85,6 +346,19 @@ pub fn plot_boundary_condition(chart_context:&mut ChartContext<BitMapBackend, Ca
         let triangle = PathElement::new(vec![(x, y),(x-size/2.0, y-size),(x+size/2.0, y-size),(x, y)],ShapeStyle::from(&BLACK).filled());
         chart_context.draw_series(std::iter::once(triangle)).unwrap();
     } 
+    else if boundary_condition.fixture == "3" && boundary_condition.spring_stiffness == -1.0 {
+        let square = PathElement::new(vec![(x-size/4.0, y-size/4.0),(x+size/4.0, y-size/4.0),
+                                           (x+size/4.0, y+size/4.0),(x-size/4.0, y+size/4.0),
+                                           (x-size/4.0, y-size/4.0)],ShapeStyle::from(&BLACK));
+        chart_context.draw_series(std::iter::once(square)).unwrap();
+    }
+    else if boundary_condition.fixture == "4" && boundary_condition.spring_stiffness == -1.0 {
+        let _ = chart_context.draw_series(LineSeries::new(vec![(x-size/2.0, y), (x+size/2.0, y)],&BLACK));
+        for i in 0..5 {
+            let x_hatch = x - size/2.0 + i as f32 * size/4.0;
+            let _ = chart_context.draw_series(LineSeries::new(vec![(x_hatch, y), (x_hatch-size/4.0, y-size/4.0)],&BLACK));
+        }
+    }
     else if boundary_condition.fixture == "0" && boundary_condition.spring_stiffness > 0.0 {
         let spring_lateral = PathElement::new(vec![(x, y),
                                              (x+size/8.0, y+size/2.0),(x+2.0*size/4.0, y-size/2.0),
@@ -113,16 +387,17 @@ pub fn plot_boundary_condition(chart_context:&mut ChartContext<BitMapBackend, Ca
         chart_context.draw_series(std::iter::once(spring_vertical)).unwrap();
     }
 
-    if plot_reaction {
-        if kp.fx != 0.0 || kp.fy != 0.0 {
-            let pixel_offset_y:i32 = (plot_feature_size*6.0) as i32;
+    if plot_reaction && (kp.fx != 0.0 || kp.fy != 0.0 || kp.mz != 0.0) {
+        let pixel_offset_y:i32 = (plot_feature_size*6.0) as i32;
 
-            // 
-            let (x_new, y_new) = offset_label_coordinates(chart_context,x,y,0,pixel_offset_y);
+        // 
+        let (x_new, y_new) = offset_label_coordinates(chart_context,x,y,0,pixel_offset_y);
 
-            let label = format!("F ({:.2$}, {:.2$})", kp.fx, kp.fy, plot_result_decimals);
-            plot_label(label, x_new, y_new, plot_feature_size, chart_context);
+        let mut label = format!("F ({:.2$}, {:.2$})", kp.fx, kp.fy, plot_result_decimals);
+        if kp.mz != 0.0 {
+            label = format!("{} M {:.2$}", label, kp.mz, plot_result_decimals);
         }
+        plot_label(label, x_new, y_new, plot_feature_size, chart_context);
     }
 }
 
@@ -183,12 +458,12 @@ fn offset_label_coordinates(chart_context: &ChartContext<BitMapBackend, Cartesia
     let units_per_pixel_y = canvas_y_range / pixels_y;
 
     // Setting unit offset based on pixels.
-    let units_offset_x = (units_per_pixel_x * offset_pixels_x as f32);
-    let units_offset_y = (units_per_pixel_y * offset_pixels_y as f32);
+    let units_offset_x = units_per_pixel_x * offset_pixels_x as f32;
+    let units_offset_y = units_per_pixel_y * offset_pixels_y as f32;
 
     // Setting the new y coordinate
-    let x_offset = x as f32 + units_offset_x;
-    let y_offset = y as f32 + units_offset_y;
+    let x_offset = x + units_offset_x;
+    let y_offset = y + units_offset_y;
 
     (x_offset, y_offset)
 }
\ No newline at end of file
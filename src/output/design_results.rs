@@ -0,0 +1,27 @@
+use crate::data_formatting::generate_result_structs::{SteelCheckResult, TimberCheckResult, SizingResult};
+use std::fs::File;
+use std::io::Write;
+
+pub fn eksport_steel_check_results(steel_check_result: &SteelCheckResult,
+								   output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(steel_check_result).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
+
+pub fn eksport_timber_check_results(timber_check_result: &TimberCheckResult,
+									output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(timber_check_result).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
+
+pub fn eksport_sizing_results(sizing_result: &SizingResult,
+							  output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(sizing_result).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
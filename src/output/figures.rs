@@ -2,39 +2,47 @@ use crate::input::keypoint::Keypoint;
 use crate::input::connection::Connection;
 use crate::input::boundary_condition::BoundaryCondition;
 use crate::input::pointload::Pointload;
-use crate::output::canvas::plot_canvas;
+use crate::data_formatting::generate_result_structs::{KeypointFrequencyResponse, InfluenceLineResult, TopologyResult, ReliabilityOutputResult};
+use crate::input::space_keypoint::SpaceKeypoint;
+use crate::input::surface_element::SurfaceElement;
+use crate::data_formatting::generate_result_structs::NodalStress;
+use std::collections::HashMap;
+use crate::input::space_pointload::SpacePointload;
+use crate::output::projection::{project_space_keypoints, project_space_pointloads, project_space_boundary_conditions};
+use crate::output::canvas::{plot_canvas, PlotSettings};
+use plotters::prelude::*;
 use crate::output::content_and_labels::
 {plot_keypoint, plot_keypoint_displaced, plot_connection, 
-plot_connection_displaced, plot_boundary_condition, plot_pointload};
+plot_connection_displaced, plot_boundary_condition, plot_pointload, plot_member_diagram, plot_connection_utilization,
+plot_topology_member, plot_connection_releases,
+plot_surface_element, plot_surface_contour, plot_contour_legend};
 
 pub fn geometry_plot(kp_list:&[Keypoint], 
                      conn_list:&[Connection], 
                      bc_list:&[BoundaryCondition], 
                      pl_list:&[Pointload], 
-                     plot_feature_size:f32,
-                     output_path:&str,
-                     dimension:(u32, u32),
-                     chart_title:&str) -> Result<(), Box<dyn std::error::Error>> {
+                     settings:&PlotSettings) -> Result<(), Box<dyn std::error::Error>> {
 
     // Creating the plotting canvas, returning the struct "chart_context"
-    let mut chart_context = plot_canvas(&kp_list, &output_path, dimension, &chart_title);
+    let mut chart_context = plot_canvas(kp_list, settings.output_path, settings.dimension, settings.chart_title);
 
     for kp in kp_list {
-        plot_keypoint(&mut chart_context, &kp, plot_feature_size);
+        plot_keypoint(&mut chart_context, kp, settings.plot_feature_size);
     }
 
     for conn in conn_list {
-        plot_connection(&mut chart_context, &conn, &kp_list);
+        plot_connection(&mut chart_context, conn, kp_list);
+        plot_connection_releases(&mut chart_context, conn, kp_list, settings.plot_feature_size);
     }
 
     let plot_reaction:bool = false;
     let plot_result_decimals:usize = 0;
     for bc in bc_list {
-        plot_boundary_condition(&mut chart_context, &bc, &kp_list, plot_feature_size,plot_reaction,plot_result_decimals);
+        plot_boundary_condition(&mut chart_context, bc, kp_list, settings.plot_feature_size,plot_reaction,plot_result_decimals);
     }
 
     for pl in pl_list {
-        plot_pointload(&mut chart_context, &pl, &kp_list, plot_feature_size);
+        plot_pointload(&mut chart_context, pl, kp_list, settings.plot_feature_size);
     }
 
     Ok(())
@@ -44,36 +52,399 @@ pub fn reaction_plot(kp_list:&[Keypoint],
                      conn_list:&[Connection], 
                      bc_list:&[BoundaryCondition], 
                      pl_list:&[Pointload], 
-                     plot_feature_size:f32,
-                     output_path:&str,
-                     dimension:(u32, u32),
-                     chart_title:&str,
-                     plot_result_scale:f32,
-                     plot_result_decimals:usize,) -> Result<(), Box<dyn std::error::Error>> {
+                     settings:&PlotSettings,
+                     plot_result_scale:f32) -> Result<(), Box<dyn std::error::Error>> {
 
     // Creating the plotting canvas, returning the struct "chart_context"
-    let mut chart_context = plot_canvas(&kp_list, &output_path, dimension, &chart_title);
+    let mut chart_context = plot_canvas(kp_list, settings.output_path, settings.dimension, settings.chart_title);
 
     for conn in conn_list {
-        plot_connection(&mut chart_context, &conn, &kp_list);
-        plot_connection_displaced(&mut chart_context, &conn, &kp_list, plot_result_scale);
+        plot_connection(&mut chart_context, conn, kp_list);
+        plot_connection_displaced(&mut chart_context, conn, kp_list, plot_result_scale);
     }
 
     for kp in kp_list {
         // plot_keypoint(&mut chart_context, &kp, plot_feature_size);
-        plot_keypoint_displaced(&mut chart_context, &kp, plot_feature_size, plot_result_scale, plot_result_decimals);
+        plot_keypoint_displaced(&mut chart_context, kp, settings.plot_feature_size, plot_result_scale, settings.plot_result_decimals);
     }
 
     let plot_reaction:bool = true;
     for bc in bc_list {
-        plot_boundary_condition(&mut chart_context, &bc, &kp_list, plot_feature_size,plot_reaction,plot_result_decimals);
+        plot_boundary_condition(&mut chart_context, bc, kp_list, settings.plot_feature_size,plot_reaction,settings.plot_result_decimals);
     }
 
     for pl in pl_list {
-        plot_pointload(&mut chart_context, &pl, &kp_list, plot_feature_size);
+        plot_pointload(&mut chart_context, pl, kp_list, settings.plot_feature_size);
     }
     
     Ok(())
 }
 
+// Plotting the member force diagrams of all connections.
+// diagram_type: "N" = normal force, "V" = shear force, "M" = bending moment.
+pub fn member_force_plot(kp_list:&[Keypoint], 
+                         conn_list:&[Connection], 
+                         bc_list:&[BoundaryCondition], 
+                         settings:&PlotSettings,
+                         diagram_type:&str) -> Result<(), Box<dyn std::error::Error>> {
 
+    // Creating the plotting canvas, returning the struct "chart_context"
+    let mut chart_context = plot_canvas(kp_list, settings.output_path, settings.dimension, settings.chart_title);
+
+    for conn in conn_list {
+        plot_connection(&mut chart_context, conn, kp_list);
+    }
+
+    let plot_reaction:bool = false;
+    for bc in bc_list {
+        plot_boundary_condition(&mut chart_context, bc, kp_list, settings.plot_feature_size,plot_reaction,settings.plot_result_decimals);
+    }
+
+    // Scaling the largest diagram value to 15% of the model size.
+    let max_value:f64 = conn_list.iter()
+        .flat_map(|conn| conn.diagram.iter())
+        .map(|p| match diagram_type {"N" => p.n.abs(), "V" => p.v.abs(), _ => p.m.abs()})
+        .fold(0.0, f64::max);
+    let diagram_scale:f32 = if max_value > 0.0 { (0.15 * model_size(kp_list) / max_value) as f32 } else { 0.0 };
+
+    for conn in conn_list {
+        plot_member_diagram(&mut chart_context, conn, kp_list, diagram_type, diagram_scale, settings.plot_feature_size, settings.plot_result_decimals);
+    }
+
+    Ok(())
+}
+
+// Plotting the geometry with connections coloured by their utilization ratio from the member design checks.
+pub fn utilization_plot(kp_list:&[Keypoint], 
+                        conn_list:&[Connection], 
+                        bc_list:&[BoundaryCondition], 
+                        settings:&PlotSettings) -> Result<(), Box<dyn std::error::Error>> {
+
+    // Creating the plotting canvas, returning the struct "chart_context"
+    let mut chart_context = plot_canvas(kp_list, settings.output_path, settings.dimension, settings.chart_title);
+
+    for conn in conn_list {
+        plot_connection_utilization(&mut chart_context, conn, kp_list, settings.plot_feature_size, settings.plot_result_decimals);
+    }
+
+    let plot_reaction:bool = false;
+    for bc in bc_list {
+        plot_boundary_condition(&mut chart_context, bc, kp_list, settings.plot_feature_size,plot_reaction,settings.plot_result_decimals);
+    }
+
+    Ok(())
+}
+
+// Plotting the optimised truss layout. Line widths are scaled by the bar areas.
+// The undeformed keypoints of the ground structure are shown for reference.
+pub fn topology_plot(kp_list:&[Keypoint], 
+                     topology_result:&TopologyResult, 
+                     bc_list:&[BoundaryCondition], 
+                     pl_list:&[Pointload], 
+                     settings:&PlotSettings) -> Result<(), Box<dyn std::error::Error>> {
+
+    // Creating the plotting canvas, returning the struct "chart_context"
+    let mut chart_context = plot_canvas(kp_list, settings.output_path, settings.dimension, settings.chart_title);
+
+    let max_area:f64 = topology_result.members.iter().map(|member| member.area).fold(0.0, f64::max);
+    for member in &topology_result.members {
+        plot_topology_member(&mut chart_context, member, kp_list, max_area, settings.plot_feature_size);
+    }
+
+    for kp in kp_list {
+        plot_keypoint(&mut chart_context, kp, settings.plot_feature_size);
+    }
+
+    let plot_reaction:bool = false;
+    let plot_result_decimals:usize = 0;
+    for bc in bc_list {
+        plot_boundary_condition(&mut chart_context, bc, kp_list, settings.plot_feature_size,plot_reaction,plot_result_decimals);
+    }
+
+    for pl in pl_list {
+        plot_pointload(&mut chart_context, pl, kp_list, settings.plot_feature_size);
+    }
+
+    Ok(())
+}
+
+// Plotting a deformed shape, e.g. a mode shape, on top of the undeformed geometry.
+// The keypoint displacements are scaled, so the largest displacement is 10% of the model size.
+pub fn deformed_shape_plot(displaced_kp_list:&[Keypoint], 
+                           conn_list:&[Connection], 
+                           bc_list:&[BoundaryCondition], 
+                           settings:&PlotSettings) -> Result<(), Box<dyn std::error::Error>> {
+
+    // Creating the plotting canvas, returning the struct "chart_context"
+    let mut chart_context = plot_canvas(displaced_kp_list, settings.output_path, settings.dimension, settings.chart_title);
+
+    let max_displacement:f64 = displaced_kp_list.iter()
+        .map(|kp| (kp.ux * kp.ux + kp.uy * kp.uy).sqrt())
+        .fold(0.0, f64::max);
+    let plot_result_scale:f32 = if max_displacement > 0.0 { (0.1 * model_size(displaced_kp_list) / max_displacement) as f32 } else { 0.0 };
+
+    for conn in conn_list {
+        plot_connection(&mut chart_context, conn, displaced_kp_list);
+        plot_connection_displaced(&mut chart_context, conn, displaced_kp_list, plot_result_scale);
+    }
+
+    let plot_reaction:bool = false;
+    let plot_result_decimals:usize = 0;
+    for bc in bc_list {
+        plot_boundary_condition(&mut chart_context, bc, displaced_kp_list, settings.plot_feature_size,plot_reaction,plot_result_decimals);
+    }
+
+    Ok(())
+}
+
+// Plotting the displacement amplitudes of a keypoint versus frequency.
+pub fn frequency_response_plot(keypoint_response:&KeypointFrequencyResponse,
+                               output_path:&str,
+                               dimension:(u32, u32),
+                               chart_title:&str) -> Result<(), Box<dyn std::error::Error>> {
+
+    let drawing_area = BitMapBackend::new(output_path, dimension).into_drawing_area();
+    drawing_area.fill(&WHITE)?;
+
+    let min_frequency = keypoint_response.frequency.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max_frequency = keypoint_response.frequency.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let max_amplitude = keypoint_response.ux_amplitude.iter()
+        .chain(keypoint_response.uy_amplitude.iter())
+        .cloned()
+        .fold(0.0, f64::max);
+
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .caption(chart_title, ("sans-serif", 40).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(80)
+        .build_cartesian_2d(min_frequency..max_frequency, 0.0..max_amplitude * 1.1)?;
+
+    chart_context
+        .configure_mesh()
+        .x_desc("Frequency [Hz]")
+        .y_desc("Amplitude")
+        .draw()?;
+
+    chart_context
+        .draw_series(LineSeries::new(keypoint_response.frequency.iter().cloned().zip(keypoint_response.ux_amplitude.iter().cloned()), &BLUE))?
+        .label("ux")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], ShapeStyle::from(&BLUE)));
+
+    chart_context
+        .draw_series(LineSeries::new(keypoint_response.frequency.iter().cloned().zip(keypoint_response.uy_amplitude.iter().cloned()), &RED))?
+        .label("uy")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], ShapeStyle::from(&RED)));
+
+    chart_context
+        .configure_series_labels()
+        .background_style(WHITE)
+        .border_style(BLACK)
+        .draw()?;
+
+    drawing_area.present()?;
+
+    Ok(())
+}
+
+// Plotting an influence line versus distance along the deck path.
+pub fn influence_line_plot(influence_line_result:&InfluenceLineResult,
+                           output_path:&str,
+                           dimension:(u32, u32),
+                           chart_title:&str) -> Result<(), Box<dyn std::error::Error>> {
+
+    let drawing_area = BitMapBackend::new(output_path, dimension).into_drawing_area();
+    drawing_area.fill(&WHITE)?;
+
+    let max_distance = influence_line_result.distance.iter().cloned().fold(0.0, f64::max);
+    let min_value = influence_line_result.value.iter().cloned().fold(0.0, f64::min);
+    let max_value = influence_line_result.value.iter().cloned().fold(0.0, f64::max);
+    let margin = 0.1 * (max_value - min_value).max(f64::EPSILON);
+
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .caption(chart_title, ("sans-serif", 40).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(80)
+        .build_cartesian_2d(0.0..max_distance, (min_value - margin)..(max_value + margin))?;
+
+    chart_context
+        .configure_mesh()
+        .x_desc("Distance along deck")
+        .y_desc(if influence_line_result.quantity == "bar_force" {
+            format!("{} {}", influence_line_result.quantity, influence_line_result.target)
+        } else {
+            format!("{} {} {}", influence_line_result.quantity, influence_line_result.target, influence_line_result.direction)
+        })
+        .draw()?;
+
+    // Zero line and influence line.
+    chart_context.draw_series(LineSeries::new(vec![(0.0, 0.0), (max_distance, 0.0)], &BLACK))?;
+    chart_context.draw_series(LineSeries::new(
+        influence_line_result.distance.iter().cloned().zip(influence_line_result.value.iter().cloned()), &BLUE))?;
+
+    drawing_area.present()?;
+
+    Ok(())
+}
+
+// Plotting the histogram of a response from the reliability analysis.
+// The exceedance threshold is drawn as a red line where it is inside the sample range.
+pub fn histogram_plot(output_result:&ReliabilityOutputResult,
+                      output_path:&str,
+                      dimension:(u32, u32),
+                      chart_title:&str) -> Result<(), Box<dyn std::error::Error>> {
+
+    let drawing_area = BitMapBackend::new(output_path, dimension).into_drawing_area();
+    drawing_area.fill(&WHITE)?;
+
+    let min_value = output_result.histogram.first().map_or(0.0, |bin| bin.lower);
+    let max_value = output_result.histogram.last().map_or(1.0, |bin| bin.upper);
+    let margin = 0.05 * (max_value - min_value).max(f64::EPSILON);
+    let max_count = output_result.histogram.iter().map(|bin| bin.count).max().unwrap_or(0) as f64;
+
+    let mut chart_context = ChartBuilder::on(&drawing_area)
+        .caption(chart_title, ("sans-serif", 40).into_font())
+        .margin(10)
+        .x_label_area_size(40)
+        .y_label_area_size(80)
+        .build_cartesian_2d((min_value - margin)..(max_value + margin), 0.0..max_count * 1.1)?;
+
+    chart_context
+        .configure_mesh()
+        .x_desc(if output_result.target == "-" {
+            output_result.quantity.clone()
+        } else {
+            format!("{} {}", output_result.quantity, output_result.target)
+        })
+        .y_desc("Number of samples")
+        .draw()?;
+
+    chart_context.draw_series(output_result.histogram.iter().map(|bin| {
+        Rectangle::new([(bin.lower, 0.0), (bin.upper, bin.count as f64)], BLUE.mix(0.5).filled())
+    }))?;
+
+    for threshold in [output_result.threshold, -output_result.threshold] {
+        if threshold > min_value - margin && threshold < max_value + margin {
+            chart_context.draw_series(LineSeries::new(vec![(threshold, 0.0), (threshold, max_count * 1.1)], RED.stroke_width(2)))?;
+        }
+    }
+
+    drawing_area.present()?;
+
+    Ok(())
+}
+
+// Returning the largest extent of the model in x or y-direction.
+fn model_size(kp_list:&[Keypoint]) -> f64 {
+    let min_x = kp_list.iter().map(|kp| kp.x).fold(f64::INFINITY, f64::min);
+    let max_x = kp_list.iter().map(|kp| kp.x).fold(f64::NEG_INFINITY, f64::max);
+    let min_y = kp_list.iter().map(|kp| kp.y).fold(f64::INFINITY, f64::min);
+    let max_y = kp_list.iter().map(|kp| kp.y).fold(f64::NEG_INFINITY, f64::max);
+
+    (max_x - min_x).max(max_y - min_y)
+}
+
+// Plotting the geometry of a 3D model in an axonometric projection.
+pub fn space_geometry_plot(kp_list:&[SpaceKeypoint],
+                           conn_list:&[Connection],
+                           bc_list:&[BoundaryCondition],
+                           pl_list:&[SpacePointload],
+                           settings:&PlotSettings) -> Result<(), Box<dyn std::error::Error>> {
+
+    geometry_plot(&project_space_keypoints(kp_list),
+                  conn_list,
+                  &project_space_boundary_conditions(bc_list),
+                  &project_space_pointloads(pl_list),
+                  settings)
+}
+
+// Plotting the deformed shape of a 3D model in an axonometric projection.
+// The displacements are projected in the same way as the coordinates, before scaling.
+pub fn space_deformed_shape_plot(kp_list:&[SpaceKeypoint],
+                                 conn_list:&[Connection],
+                                 bc_list:&[BoundaryCondition],
+                                 settings:&PlotSettings) -> Result<(), Box<dyn std::error::Error>> {
+
+    deformed_shape_plot(&project_space_keypoints(kp_list),
+                        conn_list,
+                        &project_space_boundary_conditions(bc_list),
+                        settings)
+}
+
+// Plotting the geometry of a plane model. Keypoint labels are left out, as meshes have many keypoints.
+pub fn surface_geometry_plot(kp_list:&[Keypoint], 
+                             conn_list:&[Connection], 
+                             surface_list:&[SurfaceElement], 
+                             bc_list:&[BoundaryCondition], 
+                             pl_list:&[Pointload], 
+                             settings:&PlotSettings) -> Result<(), Box<dyn std::error::Error>> {
+
+    let mut chart_context = plot_canvas(kp_list, settings.output_path, settings.dimension, settings.chart_title);
+
+    for surface in surface_list {
+        plot_surface_element(&mut chart_context, surface, kp_list);
+    }
+
+    for conn in conn_list {
+        plot_connection(&mut chart_context, conn, kp_list);
+        plot_connection_releases(&mut chart_context, conn, kp_list, settings.plot_feature_size);
+    }
+
+    let plot_reaction:bool = false;
+    let plot_result_decimals:usize = 0;
+    for bc in bc_list {
+        plot_boundary_condition(&mut chart_context, bc, kp_list, settings.plot_feature_size,plot_reaction,plot_result_decimals);
+    }
+
+    for pl in pl_list {
+        plot_pointload(&mut chart_context, pl, kp_list, settings.plot_feature_size);
+    }
+
+    Ok(())
+}
+
+// Plotting the contour of a keypoint stress over the surface elements.
+// The quantity is "sx", "sy", "txy" or "von_mises".
+pub fn contour_plot(kp_list:&[Keypoint], 
+                    conn_list:&[Connection], 
+                    surface_list:&[SurfaceElement], 
+                    nodal_stresses:&[NodalStress], 
+                    quantity:&str,
+                    bc_list:&[BoundaryCondition], 
+                    settings:&PlotSettings) -> Result<(), Box<dyn std::error::Error>> {
+
+    let keypoint_values:HashMap<String, f64> = nodal_stresses.iter().map(|nodal_stress| {
+        let value = match quantity {
+            "sx" => nodal_stress.sx,
+            "sy" => nodal_stress.sy,
+            "txy" => nodal_stress.txy,
+            "von_mises" => nodal_stress.von_mises,
+            _ => panic!("Unknown contour quantity {}", quantity),
+        };
+        (nodal_stress.keypoint.clone(), value)
+    }).collect();
+
+    let min_value:f64 = keypoint_values.values().cloned().fold(f64::INFINITY, f64::min);
+    let max_value:f64 = keypoint_values.values().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    let mut chart_context = plot_canvas(kp_list, settings.output_path, settings.dimension, settings.chart_title);
+
+    let subdivisions:usize = 6;
+    for surface in surface_list {
+        plot_surface_contour(&mut chart_context, surface, kp_list, &keypoint_values, min_value, max_value, subdivisions);
+    }
+
+    for conn in conn_list {
+        plot_connection(&mut chart_context, conn, kp_list);
+    }
+
+    let plot_reaction:bool = false;
+    for bc in bc_list {
+        plot_boundary_condition(&mut chart_context, bc, kp_list, settings.plot_feature_size,plot_reaction,settings.plot_result_decimals);
+    }
+
+    plot_contour_legend(&mut chart_context, min_value, max_value, settings.plot_feature_size, settings.plot_result_decimals);
+
+    Ok(())
+}
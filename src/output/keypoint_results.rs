@@ -1,7 +1,6 @@
-use serde::Serialize;
 use crate::input::keypoint::Keypoint;
 use std::fs::File;
-use std::io::{Write, Result};
+use std::io::Write;
 
 pub fn eksport_keypoint_structs(kp_list: &[Keypoint],
 								output_path: &str,) {
@@ -0,0 +1,49 @@
+use crate::input::keypoint::Keypoint;
+use crate::input::connection::Connection;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::input::pointload::Pointload;
+use std::fs::{self, File};
+use std::io::Write;
+
+// Writing a model as input files for the solver.
+// The files are named as in the inputs folder, so they can be copied there and analysed.
+pub fn write_model_input_files(kp_list: &[Keypoint],
+							   conn_list: &[Connection],
+							   bc_list: &[BoundaryCondition],
+							   pl_list: &[Pointload],
+							   output_directory: &str,) {
+	fs::create_dir_all(output_directory).unwrap();
+
+	let mut keypoint_lines:Vec<String> = vec!["### keypoints ###".to_string(), "### name, x, y ###".to_string()];
+	for kp in kp_list {
+		keypoint_lines.push(format!("{}, {}, {}", kp.name, kp.x, kp.y));
+	}
+
+	let mut connection_lines:Vec<String> = vec!["### connections ###".to_string(),
+		"### name, connection start, connection end, material, element (optional: bar/frame, default bar), section (optional, from sections.csv) ###".to_string()];
+	for conn in conn_list {
+		if conn.section.is_empty() {
+			connection_lines.push(format!("{}, {}, {}, {}, {}", conn.name, conn.kp_1, conn.kp_2, conn.material, conn.element));
+		} else {
+			connection_lines.push(format!("{}, {}, {}, {}, {}, {}", conn.name, conn.kp_1, conn.kp_2, conn.material, conn.element, conn.section));
+		}
+	}
+
+	let mut bc_lines:Vec<String> = vec!["### bcs ###".to_string(),
+		"### name, keypoint, direction (0=x, 1=y, 2=x+y, 3=rotation, 4=x+y+rotation), value (-1 = fixed. Other values = spring stiffness) ###".to_string()];
+	for bc in bc_list {
+		bc_lines.push(format!("{}, {}, {}, {}", bc.name, bc.keypoint, bc.fixture, bc.spring_stiffness));
+	}
+
+	let mut pointload_lines:Vec<String> = vec!["### pointloads ###".to_string(),
+		"### name, keypoint, loadsize in x-direction, loadsize in y-direction, moment (optional, frame models only) ###".to_string()];
+	for pl in pl_list {
+		pointload_lines.push(format!("{}, {}, {}, {}, {}", pl.name, pl.keypoint, pl.load_x, pl.load_y, pl.moment));
+	}
+
+	for (file_name, lines) in [("keypoints.txt", keypoint_lines), ("connections.txt", connection_lines),
+							   ("bcs.txt", bc_lines), ("pointloads.txt", pointload_lines)] {
+		let mut file = File::create(format!("{}/{}", output_directory, file_name)).unwrap();
+		file.write_all(lines.join("\n").as_bytes()).unwrap();
+	}
+}
@@ -0,0 +1,11 @@
+use crate::data_formatting::generate_result_structs::MovingLoadResult;
+use std::fs::File;
+use std::io::Write;
+
+pub fn eksport_moving_load_results(moving_load_results: &[MovingLoadResult],
+								   output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(moving_load_results).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
@@ -0,0 +1,62 @@
+// This file projects 3D models onto the 2D plotting plane, so the 2D plotting routines can be reused.
+// Axonometric projection with the z-axis vertical, viewed from the azimuth and elevation angles below.
+// An azimuth of 45 degrees and an elevation of 35.26 degrees gives the isometric view. That view hides the
+// diagonals of square plans behind each other, so a rotated view is used by default.
+// The projection is linear, so displacements and loads are projected in the same way as the coordinates.
+
+use crate::input::keypoint::Keypoint;
+use crate::input::space_keypoint::SpaceKeypoint;
+use crate::input::pointload::Pointload;
+use crate::input::space_pointload::SpacePointload;
+use crate::input::boundary_condition::BoundaryCondition;
+use crate::input::release::direction_dofs;
+
+const VIEW_AZIMUTH_DEGREES: f64 = 30.0; // Rotation of the x-axis from the viewing plane, about the z-axis.
+const VIEW_ELEVATION_DEGREES: f64 = 25.0; // Angle of the viewing direction above the xy-plane.
+
+// Returning the plotting coordinates of a 3D point or vector.
+pub fn axonometric_projection(x:f64, y:f64, z:f64) -> (f64, f64) {
+	let (sin_a, cos_a) = VIEW_AZIMUTH_DEGREES.to_radians().sin_cos();
+	let (sin_e, cos_e) = VIEW_ELEVATION_DEGREES.to_radians().sin_cos();
+
+	// Horizontal plot axis in the xy-plane, and the depth axis pointing away from the viewer.
+	let horizontal:f64 = x * cos_a - y * sin_a;
+	let depth:f64 = x * sin_a + y * cos_a;
+
+	(horizontal, z * cos_e + depth * sin_e)
+}
+
+// Returning 2D keypoints at the projected coordinates, with the projected displacements and forces.
+pub fn project_space_keypoints(kp_list:&[SpaceKeypoint]) -> Vec<Keypoint> {
+	kp_list.iter().map(|kp| {
+		let (x, y) = axonometric_projection(kp.x, kp.y, kp.z);
+		let (ux, uy) = axonometric_projection(kp.ux, kp.uy, kp.uz);
+		let (fx, fy) = axonometric_projection(kp.fx, kp.fy, kp.fz);
+		Keypoint {name: kp.name.clone(), x, y, fx, fy, mz: 0.0, ux, uy, rz: 0.0}
+	}).collect()
+}
+
+pub fn project_space_pointloads(pl_list:&[SpacePointload]) -> Vec<Pointload> {
+	pl_list.iter().map(|pl| {
+		let (load_x, load_y) = axonometric_projection(pl.load_x, pl.load_y, pl.load_z);
+		Pointload {name: pl.name.clone(), keypoint: pl.keypoint.clone(), load_x, load_y, moment: 0.0}
+	}).collect()
+}
+
+// Returning the boundary conditions with the 2D fixture code used for the plotting symbol.
+// Keypoints fixed in all dofs are drawn as fixed (4), keypoints fixed in all translations as pinned (2),
+// and other supports as rollers (0).
+pub fn project_space_boundary_conditions(bc_list:&[BoundaryCondition]) -> Vec<BoundaryCondition> {
+	bc_list.iter().map(|bc| {
+		let fixture_dofs = direction_dofs(&bc.fixture);
+		let fixture:&str = if (0..6).all(|dof| fixture_dofs.contains(&dof)) {
+			"4"
+		} else if (0..3).all(|dof| fixture_dofs.contains(&dof)) {
+			"2"
+		} else {
+			"0"
+		};
+		BoundaryCondition {name: bc.name.clone(), keypoint: bc.keypoint.clone(), fixture: fixture.to_string(),
+						   spring_stiffness: bc.spring_stiffness}
+	}).collect()
+}
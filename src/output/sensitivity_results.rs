@@ -0,0 +1,26 @@
+use crate::data_formatting::generate_result_structs::SensitivityResult;
+use std::fs::File;
+use std::io::Write;
+
+pub fn eksport_sensitivity_results(sensitivity_results: &[SensitivityResult],
+								   output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(sensitivity_results).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
+
+// Writing one sensitivity table per response, to <output_path>_<response name>.csv.
+pub fn eksport_sensitivity_tables_csv(sensitivity_results: &[SensitivityResult],
+									  output_path: &str,) {
+	for sensitivity_result in sensitivity_results {
+		let mut csv_string = String::from("parameter,target,value,direct,adjoint\n");
+		for entry in &sensitivity_result.sensitivities {
+			csv_string.push_str(&format!("{},{},{},{},{}\n", entry.parameter, entry.target, entry.value,
+										 entry.direct, entry.adjoint));
+		}
+
+		let mut file = File::create(format!("{}_{}.csv", output_path, sensitivity_result.name)).unwrap();
+		file.write_all(csv_string.as_bytes()).unwrap();
+	}
+}
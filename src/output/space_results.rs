@@ -0,0 +1,20 @@
+use crate::input::space_keypoint::SpaceKeypoint;
+use crate::data_formatting::generate_result_structs::SpaceMemberEndForces;
+use std::fs::File;
+use std::io::Write;
+
+pub fn eksport_space_keypoint_structs(kp_list: &[SpaceKeypoint],
+									  output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(kp_list).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
+
+pub fn eksport_space_member_end_forces(member_end_forces: &[SpaceMemberEndForces],
+									   output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(member_end_forces).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
@@ -0,0 +1,20 @@
+use crate::input::surface_element::SurfaceElement;
+use crate::data_formatting::generate_result_structs::NodalStress;
+use std::fs::File;
+use std::io::Write;
+
+pub fn eksport_surface_element_structs(surface_list: &[SurfaceElement],
+									   output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(surface_list).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
+
+pub fn eksport_nodal_stresses(nodal_stresses: &[NodalStress],
+							  output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(nodal_stresses).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
@@ -0,0 +1,25 @@
+use crate::data_formatting::generate_result_structs::ParameterSweepResult;
+use std::fs::File;
+use std::io::Write;
+
+// Writing the summary table of the parameter sweep, with one row per variant.
+pub fn eksport_parameter_sweep_csv(sweep_result: &ParameterSweepResult,
+								   output_path: &str,) {
+	let mut csv_string = String::from("variant");
+	for name in sweep_result.parameters.iter().chain(sweep_result.outputs.iter()) {
+		csv_string.push_str(&format!(",{}", name));
+	}
+	csv_string.push('\n');
+
+	for variant in &sweep_result.variants {
+		csv_string.push_str(&format!("{}", variant.variant));
+		for value in variant.parameter_values.iter().chain(variant.output_values.iter()) {
+			csv_string.push_str(&format!(",{}", value));
+		}
+		csv_string.push('\n');
+	}
+
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(csv_string.as_bytes()).unwrap();
+}
@@ -0,0 +1,46 @@
+use crate::data_formatting::generate_result_structs::TopologyResult;
+use crate::input::keypoint::Keypoint;
+use crate::input::boundary_condition::BoundaryCondition;
+use std::fs::File;
+use std::io::Write;
+
+pub fn eksport_topology_results(topology_result: &TopologyResult,
+								output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(topology_result).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
+
+// Writing the optimised truss as input files for the solver.
+// Only keypoints connected to the optimised truss, and their boundary conditions, are written.
+pub fn write_topology_input_files(topology_result: &TopologyResult,
+								  kp_list: &[Keypoint],
+								  bc_list: &[BoundaryCondition],
+								  keypoints_path: &str,
+								  connections_path: &str,
+								  bcs_path: &str,) {
+	let is_connected = |name:&String| topology_result.members.iter().any(|member| &member.kp_1 == name || &member.kp_2 == name);
+
+	let mut keypoint_lines:Vec<String> = vec!["### keypoints ###".to_string(), "### name, x, y ###".to_string()];
+	for kp in kp_list.iter().filter(|kp| is_connected(&kp.name)) {
+		keypoint_lines.push(format!("{}, {}, {}", kp.name, kp.x, kp.y));
+	}
+
+	let mut connection_lines:Vec<String> = vec!["### connections ###".to_string(),
+		"### name, connection start, connection end, material, element (optional: bar/frame, default bar), section (optional, from sections.csv) ###".to_string()];
+	for member in &topology_result.members {
+		connection_lines.push(format!("{}, {}, {}, {}, bar, {}", member.name, member.kp_1, member.kp_2, member.material, member.section));
+	}
+
+	let mut bc_lines:Vec<String> = vec!["### bcs ###".to_string(),
+		"### name, keypoint, direction (0=x, 1=y, 2=x+y, 3=rotation, 4=x+y+rotation), value (-1 = fixed. Other values = spring stiffness) ###".to_string()];
+	for bc in bc_list.iter().filter(|bc| is_connected(&bc.keypoint)) {
+		bc_lines.push(format!("{}, {}, {}, {}", bc.name, bc.keypoint, bc.fixture, bc.spring_stiffness));
+	}
+
+	for (path, lines) in [(keypoints_path, keypoint_lines), (connections_path, connection_lines), (bcs_path, bc_lines)] {
+		let mut file = File::create(path).unwrap();
+		file.write_all(lines.join("\n").as_bytes()).unwrap();
+	}
+}
@@ -0,0 +1,39 @@
+use crate::data_formatting::generate_result_structs::KeypointHistory;
+use std::fs::File;
+use std::io::Write;
+
+pub fn eksport_keypoint_histories(histories: &[KeypointHistory],
+								  output_path: &str,) {
+	let json_string = serde_json::to_string_pretty(histories).unwrap();
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(json_string.as_bytes()).unwrap();
+}
+
+// Writing the keypoint histories as a table. One row per time step.
+// Columns: time, followed by ux, uy, rz, fx, fy, mz for each keypoint.
+pub fn eksport_keypoint_histories_csv(histories: &[KeypointHistory],
+									  output_path: &str,) {
+	let mut csv_string = String::from("time");
+	for history in histories {
+		for component in ["ux", "uy", "rz", "fx", "fy", "mz"] {
+			csv_string.push_str(&format!(",{}_{}", history.name, component));
+		}
+	}
+	csv_string.push('\n');
+
+	let number_of_steps:usize = histories.first().map_or(0, |history| history.time.len());
+	for i in 0..number_of_steps {
+		csv_string.push_str(&format!("{}", histories[0].time[i]));
+		for history in histories {
+			for component in [&history.ux, &history.uy, &history.rz, &history.fx, &history.fy, &history.mz] {
+				csv_string.push_str(&format!(",{}", component[i]));
+			}
+		}
+		csv_string.push('\n');
+	}
+
+    // Create and write to the file
+    let mut file = File::create(output_path).unwrap();
+    file.write_all(csv_string.as_bytes()).unwrap();
+}